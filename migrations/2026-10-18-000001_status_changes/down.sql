@@ -0,0 +1,3 @@
+-- Drop the status history table.
+
+DROP TABLE status_changes;
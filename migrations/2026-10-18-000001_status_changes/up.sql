@@ -0,0 +1,16 @@
+-- This table holds the status history for every job application. A row is written whenever a job
+-- is created or its status changes.
+CREATE TABLE status_changes (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    job_id INTEGER NOT NULL,
+    old_status_id INTEGER,
+    new_status_id INTEGER NOT NULL,
+    changed TEXT NOT NULL,
+    FOREIGN KEY (job_id) REFERENCES jobs (id),
+    FOREIGN KEY (old_status_id) REFERENCES statuses (id),
+    FOREIGN KEY (new_status_id) REFERENCES statuses (id)
+);
+
+-- Backfill the initial status of every existing job application using its created timestamp.
+INSERT INTO status_changes (job_id, old_status_id, new_status_id, changed)
+SELECT id, NULL, status_id, created FROM jobs;
@@ -0,0 +1,3 @@
+-- Drop the interviews table.
+
+DROP TABLE interviews;
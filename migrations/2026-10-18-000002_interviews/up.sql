@@ -0,0 +1,12 @@
+-- This table holds all interview rounds for job applications.
+CREATE TABLE interviews (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    job_id INTEGER NOT NULL,
+    round TEXT NOT NULL,
+    interview_type TEXT NOT NULL,
+    scheduled TEXT,
+    interviewers TEXT,
+    outcome TEXT NOT NULL,
+    notes TEXT,
+    FOREIGN KEY (job_id) REFERENCES jobs (id)
+);
@@ -0,0 +1,4 @@
+-- Drop the contacts tables.
+
+DROP TABLE job_contacts;
+DROP TABLE contacts;
@@ -0,0 +1,20 @@
+-- This table holds all contacts (ie. recruiters, referrers, or hiring managers).
+CREATE TABLE contacts (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    email TEXT,
+    phone TEXT,
+    role TEXT,
+    company TEXT,
+    linkedin_url TEXT,
+    notes TEXT
+);
+
+-- This table links contacts to job applications.
+CREATE TABLE job_contacts (
+    job_id INTEGER NOT NULL,
+    contact_id INTEGER NOT NULL,
+    PRIMARY KEY (job_id, contact_id),
+    FOREIGN KEY (job_id) REFERENCES jobs (id),
+    FOREIGN KEY (contact_id) REFERENCES contacts (id)
+);
@@ -0,0 +1,3 @@
+-- Drop the reminders table.
+
+DROP TABLE reminders;
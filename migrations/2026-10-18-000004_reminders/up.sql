@@ -0,0 +1,11 @@
+-- This table holds all follow-up reminders for job applications. Automatic reminders are created
+-- from the reminder rules in the config file.
+CREATE TABLE reminders (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    job_id INTEGER NOT NULL,
+    due TEXT NOT NULL,
+    message TEXT NOT NULL,
+    done BOOLEAN NOT NULL DEFAULT 0,
+    automatic BOOLEAN NOT NULL DEFAULT 0,
+    FOREIGN KEY (job_id) REFERENCES jobs (id)
+);
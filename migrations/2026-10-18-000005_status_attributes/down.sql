@@ -0,0 +1,5 @@
+-- Remove the status customization columns.
+
+ALTER TABLE statuses DROP COLUMN is_terminal;
+ALTER TABLE statuses DROP COLUMN sort_order;
+ALTER TABLE statuses DROP COLUMN color;
@@ -0,0 +1,14 @@
+-- Statuses can now be customized with a display color, a sort order, and whether job
+-- applications in that status are finished.
+ALTER TABLE statuses ADD COLUMN color TEXT;
+ALTER TABLE statuses ADD COLUMN sort_order INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE statuses ADD COLUMN is_terminal BOOLEAN NOT NULL DEFAULT 0;
+
+-- Carry over the colors and ordering of the default statuses.
+UPDATE statuses SET color = 'bright blue', sort_order = 1, is_terminal = 0 WHERE name = 'PENDING';
+UPDATE statuses SET color = 'bright yellow', sort_order = 2, is_terminal = 0 WHERE name = 'IN PROGRESS';
+UPDATE statuses SET color = 'bright magenta', sort_order = 3, is_terminal = 0 WHERE name = 'OFFER RECEIVED';
+UPDATE statuses SET color = 'bright green', sort_order = 4, is_terminal = 1 WHERE name = 'HIRED';
+UPDATE statuses SET color = 'bright red', sort_order = 5, is_terminal = 1 WHERE name = 'REJECTED';
+UPDATE statuses SET color = '#858585', sort_order = 6, is_terminal = 1 WHERE name = 'GHOSTED';
+UPDATE statuses SET color = '#757575', sort_order = 7, is_terminal = 1 WHERE name = 'NOT HIRING ANYMORE';
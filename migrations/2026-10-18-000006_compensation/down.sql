@@ -0,0 +1,3 @@
+-- Drop the compensation table.
+
+DROP TABLE compensation;
@@ -0,0 +1,15 @@
+-- This table holds the compensation details of job applications. All amounts are yearly and
+-- stored in whole units of the currency.
+CREATE TABLE compensation (
+    job_id INTEGER NOT NULL PRIMARY KEY,
+    posted_min BIGINT,
+    posted_max BIGINT,
+    expected BIGINT,
+    offered_base BIGINT,
+    offered_bonus BIGINT,
+    offered_equity BIGINT,
+    currency TEXT,
+    offer_deadline TEXT,
+    benefits TEXT,
+    FOREIGN KEY (job_id) REFERENCES jobs (id)
+);
@@ -0,0 +1,4 @@
+-- Drop the tags tables.
+
+DROP TABLE job_tags;
+DROP TABLE tags;
@@ -0,0 +1,14 @@
+-- This table holds all tags (ie. remote, referral, or startup). Tag names are stored in lowercase.
+CREATE TABLE tags (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE
+);
+
+-- This table links tags to job applications.
+CREATE TABLE job_tags (
+    job_id INTEGER NOT NULL,
+    tag_id INTEGER NOT NULL,
+    PRIMARY KEY (job_id, tag_id),
+    FOREIGN KEY (job_id) REFERENCES jobs (id),
+    FOREIGN KEY (tag_id) REFERENCES tags (id)
+);
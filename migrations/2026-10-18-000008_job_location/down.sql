@@ -0,0 +1,5 @@
+-- Remove the location and work arrangement columns.
+
+ALTER TABLE jobs DROP COLUMN work_mode;
+ALTER TABLE jobs DROP COLUMN country;
+ALTER TABLE jobs DROP COLUMN location;
@@ -0,0 +1,4 @@
+-- Add the location and work arrangement of job applications.
+ALTER TABLE jobs ADD COLUMN location TEXT;
+ALTER TABLE jobs ADD COLUMN country TEXT;
+ALTER TABLE jobs ADD COLUMN work_mode TEXT;
@@ -0,0 +1,4 @@
+-- Remove the application sources.
+
+ALTER TABLE jobs DROP COLUMN source_id;
+DROP TABLE sources;
@@ -0,0 +1,9 @@
+-- This table holds all application sources (ie. job boards or referrals). The default sources are
+-- seeded on the initial run.
+CREATE TABLE sources (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE
+);
+
+-- Track the source of each job application.
+ALTER TABLE jobs ADD COLUMN source_id INTEGER;
@@ -0,0 +1,4 @@
+-- Remove the application goal from sprints.
+
+ALTER TABLE sprints DROP COLUMN goal_period;
+ALTER TABLE sprints DROP COLUMN goal;
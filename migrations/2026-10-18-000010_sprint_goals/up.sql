@@ -0,0 +1,3 @@
+-- Add an optional application goal to sprints. The goal period is either `SPRINT` or `WEEK`.
+ALTER TABLE sprints ADD COLUMN goal INTEGER;
+ALTER TABLE sprints ADD COLUMN goal_period TEXT;
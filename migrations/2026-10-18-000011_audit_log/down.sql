@@ -0,0 +1,4 @@
+-- Remove the audit log.
+
+DROP INDEX audit_log_operation_id;
+DROP TABLE audit_log;
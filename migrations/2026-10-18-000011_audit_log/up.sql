@@ -0,0 +1,18 @@
+-- This table holds a snapshot of every change made to job applications, sprints, and titles so
+-- that changes can be reviewed and undone. Entries written by the same invocation of `fetters`
+-- share an operation ID. Snapshots are stored as JSON.
+CREATE TABLE audit_log (
+    id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    operation_id INTEGER NOT NULL,
+    created TEXT NOT NULL,
+    command TEXT NOT NULL,
+    action TEXT NOT NULL,
+    table_name TEXT NOT NULL,
+    record_id INTEGER NOT NULL,
+    label TEXT NOT NULL,
+    before TEXT,
+    after TEXT,
+    undone BOOLEAN NOT NULL DEFAULT 0
+);
+
+CREATE INDEX audit_log_operation_id ON audit_log (operation_id);
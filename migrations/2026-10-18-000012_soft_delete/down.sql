@@ -0,0 +1,10 @@
+-- Permanently delete job applications in the trash and remove the deletion timestamp.
+
+DELETE FROM status_changes WHERE job_id IN (SELECT id FROM jobs WHERE deleted_at IS NOT NULL);
+DELETE FROM interviews WHERE job_id IN (SELECT id FROM jobs WHERE deleted_at IS NOT NULL);
+DELETE FROM job_contacts WHERE job_id IN (SELECT id FROM jobs WHERE deleted_at IS NOT NULL);
+DELETE FROM reminders WHERE job_id IN (SELECT id FROM jobs WHERE deleted_at IS NOT NULL);
+DELETE FROM job_tags WHERE job_id IN (SELECT id FROM jobs WHERE deleted_at IS NOT NULL);
+DELETE FROM compensation WHERE job_id IN (SELECT id FROM jobs WHERE deleted_at IS NOT NULL);
+DELETE FROM jobs WHERE deleted_at IS NOT NULL;
+ALTER TABLE jobs DROP COLUMN deleted_at;
@@ -0,0 +1,3 @@
+-- Deleted job applications are moved to the trash by setting the timestamp at which they were
+-- deleted. Job applications in the trash are permanently deleted when the trash is purged.
+ALTER TABLE jobs ADD COLUMN deleted_at TEXT;
@@ -0,0 +1,8 @@
+-- Remove the full-text search index and the triggers keeping it in sync.
+
+DROP TRIGGER jobs_fts_title_update;
+DROP TRIGGER jobs_fts_title_insert;
+DROP TRIGGER jobs_fts_delete;
+DROP TRIGGER jobs_fts_update;
+DROP TRIGGER jobs_fts_insert;
+DROP TABLE jobs_fts;
@@ -0,0 +1,60 @@
+-- This full-text index holds the searchable text of every job application. The row ID of each
+-- entry is the ID of its job application. The index is kept in sync with the `jobs` and `titles`
+-- tables by the triggers below.
+CREATE VIRTUAL TABLE jobs_fts USING fts5(
+    company_name,
+    title,
+    notes,
+    link,
+    tokenize = 'unicode61 remove_diacritics 2',
+    prefix = '2 3'
+);
+
+INSERT INTO jobs_fts (rowid, company_name, title, notes, link)
+SELECT jobs.id, jobs.company_name, titles.name, jobs.notes, jobs.link
+FROM jobs
+LEFT JOIN titles ON titles.id = jobs.title_id;
+
+-- Existing entries are removed first because `REPLACE` does not fire the delete trigger.
+CREATE TRIGGER jobs_fts_insert AFTER INSERT ON jobs
+BEGIN
+    DELETE FROM jobs_fts WHERE rowid = new.id;
+    INSERT INTO jobs_fts (rowid, company_name, title, notes, link)
+    VALUES (
+        new.id,
+        new.company_name,
+        (SELECT name FROM titles WHERE id = new.title_id),
+        new.notes,
+        new.link
+    );
+END;
+
+CREATE TRIGGER jobs_fts_update AFTER UPDATE OF id, company_name, title_id, notes, link ON jobs
+BEGIN
+    DELETE FROM jobs_fts WHERE rowid = old.id;
+    INSERT INTO jobs_fts (rowid, company_name, title, notes, link)
+    VALUES (
+        new.id,
+        new.company_name,
+        (SELECT name FROM titles WHERE id = new.title_id),
+        new.notes,
+        new.link
+    );
+END;
+
+CREATE TRIGGER jobs_fts_delete AFTER DELETE ON jobs
+BEGIN
+    DELETE FROM jobs_fts WHERE rowid = old.id;
+END;
+
+CREATE TRIGGER jobs_fts_title_insert AFTER INSERT ON titles
+BEGIN
+    UPDATE jobs_fts SET title = new.name
+    WHERE rowid IN (SELECT id FROM jobs WHERE title_id = new.id);
+END;
+
+CREATE TRIGGER jobs_fts_title_update AFTER UPDATE OF name ON titles
+BEGIN
+    UPDATE jobs_fts SET title = new.name
+    WHERE rowid IN (SELECT id FROM jobs WHERE title_id = new.id);
+END;
@@ -0,0 +1,3 @@
+-- Remove the timestamps at which contacts were linked to job applications.
+
+ALTER TABLE job_contacts DROP COLUMN linked;
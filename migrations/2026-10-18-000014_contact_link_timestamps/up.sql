@@ -0,0 +1,3 @@
+-- Contacts record the timestamp at which they were linked to a job application, which counts as
+-- activity when sweeping inactive job applications. Existing links have no timestamp.
+ALTER TABLE job_contacts ADD COLUMN linked TEXT;
@@ -0,0 +1,3 @@
+-- Empty optional job fields stay NULL, since the rows that stored empty strings are not known.
+
+SELECT 1;
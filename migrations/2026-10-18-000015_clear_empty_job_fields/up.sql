@@ -0,0 +1,7 @@
+-- Empty optional job fields were stored as empty strings when they were cleared. Store them as
+-- NULL instead so they are not counted as a separate value in filters and insights.
+UPDATE jobs SET link = NULL WHERE TRIM(link) = '';
+UPDATE jobs SET notes = NULL WHERE TRIM(notes) = '';
+UPDATE jobs SET location = NULL WHERE TRIM(location) = '';
+UPDATE jobs SET country = NULL WHERE TRIM(country) = '';
+UPDATE jobs SET work_mode = NULL WHERE TRIM(work_mode) = '';
@@ -1,6 +1,8 @@
 //! Contains all CLI options.
 
-use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand, ValueEnum};
 
 /// Contains all CLI options for `fetters`.
 #[derive(Debug, Parser)]
@@ -10,38 +12,202 @@ pub struct Cli {
     /// Run a subcommand.
     #[command(subcommand)]
     pub command: Command,
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        default_value_t = OutputFormat::Table,
+        help = "Set the output format for commands that list records: list, insights, search, due, log, contact list, interview list, offers compare, sprint show-all, status list, and trash list."
+    )]
+    pub format: OutputFormat,
+}
+
+/// Contains all output formats for displaying records.
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    /// A colored table.
+    Table,
+    /// A JSON array.
+    Json,
+    /// Comma-separated values with a header row.
+    Csv,
+    /// Tab-separated values with a header row.
+    Tsv,
+    /// A Markdown table.
+    Markdown,
 }
 
 /// Contains all subcommands for `fetters`.
 #[derive(Debug, Subcommand)]
 pub enum Command {
     /// Track a new job application.
-    Add {
-        /// The name of the company.
-        company: String,
-    },
+    Add(AddArgs),
     /// Display the ASCII art.
     Banner,
+    /// Update or delete multiple tracked job applications at once.
+    #[command(subcommand)]
+    Bulk(BulkOption),
     /// Configure `fetters` by opening its config file.
     #[command(subcommand)]
     Config(ConfigOption),
-    /// Delete a tracked job application.
-    Delete(QueryArgs),
+    /// Manage contacts (ie. recruiters or hiring managers) and link them to job applications.
+    #[command(subcommand)]
+    Contact(ContactOption),
+    /// Move a tracked job application to the trash.
+    Delete(DeleteArgs),
+    /// Check the database for integrity problems and repair them.
+    Doctor(DoctorArgs),
+    /// List overdue and upcoming follow-up reminders.
+    Due(DueArgs),
+    /// Export the database to a portable JSON archive.
+    Export(ExportArgs),
+    /// Show the status history timeline of a tracked job application.
+    History(QueryArgs),
+    /// Import job applications from a file.
+    #[command(subcommand)]
+    Import(ImportOption),
     /// Show job application inslghts.
-    Insights,
+    Insights(InsightsArgs),
+    /// Track interview rounds for job applications.
+    #[command(subcommand)]
+    Interview(InterviewOption),
     /// List job applications.
     List(QueryArgs),
+    /// Show the most recent changes recorded in the audit log.
+    Log(LogArgs),
+    /// Track compensation details and compare offers.
+    #[command(subcommand)]
+    Offers(OffersOption),
     /// Open the web link in your default browser or the local file associated with a job application.
     Open(QueryArgs),
+    /// Set a follow-up reminder for a job application.
+    Remind(RemindArgs),
+    /// Replace all data in the database with the contents of a JSON archive created by `export`.
+    Restore(RestoreArgs),
+    /// Search the company name, title, notes, and link of all job applications.
+    Search(SearchArgs),
     /// Configuration options for job sprints.
     #[command(subcommand)]
     Sprint(SprintOption),
+    /// Manage application statuses.
+    #[command(subcommand)]
+    Status(StatusOption),
+    /// Mark `PENDING` job applications without recent activity as `GHOSTED`.
+    Sweep(SweepArgs),
+    /// List, restore, or permanently delete job applications in the trash.
+    #[command(subcommand)]
+    Trash(TrashOption),
+    /// Revert the most recent changes recorded in the audit log.
+    Undo(UndoArgs),
     /// Update a tracked job application.
-    Update(QueryArgs),
+    Update(Box<UpdateArgs>),
 }
 
-/// All flags you can use to query jobs.
+/// All arguments you can use to track a new job application. Fields that are not provided with a
+/// flag will be prompted for.
+#[derive(Debug, Parser)]
+pub struct AddArgs {
+    /// The name of the company.
+    pub company: String,
+    #[arg(
+        short,
+        long,
+        help = "Set the job title. A new title is created if it does not already exist."
+    )]
+    pub title: Option<String>,
+    #[arg(
+        short,
+        long,
+        help = "Set the application status. Must match an existing status."
+    )]
+    pub status: Option<String>,
+    #[arg(short, long, help = "Set a link to the job application.")]
+    pub link: Option<String>,
+    #[arg(short, long, help = "Set notes for the job application.")]
+    pub notes: Option<String>,
+    #[arg(
+        long,
+        help = "Add the job application to an existing sprint instead of the current sprint."
+    )]
+    pub sprint: Option<String>,
+    #[arg(
+        long,
+        help = "Override the created timestamp (YYYY-MM-DD or YYYY-MM-DD HH:MM:SS)."
+    )]
+    pub created: Option<String>,
+    #[arg(long, help = "Set the city or region of the job.")]
+    pub location: Option<String>,
+    #[arg(long, help = "Set the country of the job.")]
+    pub country: Option<String>,
+    #[arg(long, help = "Set the work arrangement (remote, hybrid, or on-site).")]
+    pub work_mode: Option<String>,
+    #[arg(
+        long,
+        help = "Set the application source (ie. referral). A new source is created if it does not already exist."
+    )]
+    pub source: Option<String>,
+    #[arg(
+        long,
+        value_delimiter = ',',
+        help = "Add a tag to the job application. May be repeated or comma-separated."
+    )]
+    pub tag: Vec<String>,
+    #[arg(
+        long,
+        help = "Never prompt. Missing required fields raise an error and the entry is saved without confirmation."
+    )]
+    pub no_input: bool,
+}
+
+/// All arguments for displaying job application insights.
 #[derive(Debug, Parser)]
+pub struct InsightsArgs {
+    #[arg(
+        long,
+        help = "Only show the number of job applications, response rate, and offer rate per application source."
+    )]
+    pub sources: bool,
+    #[arg(
+        long,
+        conflicts_with = "sources",
+        help = "Only show the application funnel for the current sprint and across all sprints."
+    )]
+    pub funnel: bool,
+    #[arg(
+        long,
+        conflicts_with_all = ["sources", "funnel"],
+        help = "Only show the number of days between applying, the first response, and each status change across all sprints, as well as slow applications."
+    )]
+    pub durations: bool,
+    #[arg(
+        long,
+        conflicts_with_all = ["sources", "funnel", "durations"],
+        help = "Only show the number of job applications created over time across all sprints and a calendar heatmap of the last year."
+    )]
+    pub timeline: bool,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = TimelinePeriod::Week,
+        requires = "timeline",
+        help = "Set the period each bar in the timeline covers."
+    )]
+    pub by: TimelinePeriod,
+}
+
+/// Contains all periods job applications can be grouped by in the timeline.
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum TimelinePeriod {
+    /// Group job applications by the day they were created.
+    Day,
+    /// Group job applications by the week they were created. Weeks start on Monday.
+    Week,
+    /// Group job applications by the month they were created.
+    Month,
+}
+
+/// All flags you can use to query jobs.
+#[derive(Debug, Default, Parser)]
 pub struct QueryArgs {
     #[arg(
         short,
@@ -49,18 +215,38 @@ pub struct QueryArgs {
         help = "Filter results by company name. Supports searching with partial text."
     )]
     pub company: Option<String>,
+    #[arg(
+        long,
+        help = "Filter results by country. Supports searching with partial text."
+    )]
+    pub country: Option<String>,
+    #[arg(
+        long,
+        help = "Target a job application by its ID. Searches all sprints unless --sprint is specified."
+    )]
+    pub id: Option<i32>,
     #[arg(
         short,
         long,
         help = "Filter results by links. Supports searching with partial text."
     )]
     pub link: Option<String>,
+    #[arg(
+        long,
+        help = "Filter results by location. Supports searching with partial text."
+    )]
+    pub location: Option<String>,
     #[arg(
         short,
         long,
         help = "Filter results by notes. Supports searching with partial text."
     )]
     pub notes: Option<String>,
+    #[arg(
+        long,
+        help = "Filter results by application source. Supports searching with partial text."
+    )]
+    pub source: Option<String>,
     #[arg(
         long,
         help = "Filter results by sprint name. Supports searching with partial text."
@@ -78,6 +264,143 @@ pub struct QueryArgs {
         help = "Filter results by job title. Supports searching with partial text."
     )]
     pub title: Option<String>,
+    #[arg(
+        long,
+        value_delimiter = ',',
+        help = "Filter results by tag. May be repeated or comma-separated. Job applications must have all tags unless --any-tag is specified."
+    )]
+    pub tag: Vec<String>,
+    #[arg(
+        long,
+        requires = "tag",
+        help = "Match job applications that have any of the --tag values instead of all of them."
+    )]
+    pub any_tag: bool,
+    #[arg(
+        long,
+        help = "Filter results by work arrangement (remote, hybrid, or on-site)."
+    )]
+    pub work_mode: Option<String>,
+}
+
+/// All arguments you can use to update a job application. If any `--set-*` flag is provided, the
+/// job application is updated with those values without prompting.
+#[derive(Debug, Parser)]
+pub struct UpdateArgs {
+    /// Query the job application to update.
+    #[command(flatten)]
+    pub query_args: QueryArgs,
+    #[arg(long, help = "Set a new company name.")]
+    pub set_company: Option<String>,
+    #[arg(
+        long,
+        help = "Set a new job title. A new title is created if it does not already exist."
+    )]
+    pub set_title: Option<String>,
+    #[arg(
+        long,
+        help = "Set a new application status. Must match an existing status."
+    )]
+    pub set_status: Option<String>,
+    #[arg(long, help = "Set a new link. Pass an empty string to clear it.")]
+    pub set_link: Option<String>,
+    #[arg(long, help = "Set new notes. Pass an empty string to clear them.")]
+    pub set_notes: Option<String>,
+    #[arg(long, help = "Move the job application to an existing sprint.")]
+    pub set_sprint: Option<String>,
+    #[arg(long, help = "Set a new location. Pass an empty string to clear it.")]
+    pub set_location: Option<String>,
+    #[arg(long, help = "Set a new country. Pass an empty string to clear it.")]
+    pub set_country: Option<String>,
+    #[arg(
+        long,
+        help = "Set a new work arrangement (remote, hybrid, or on-site). Pass an empty string to clear it."
+    )]
+    pub set_work_mode: Option<String>,
+    #[arg(
+        long,
+        help = "Set a new application source. A new source is created if it does not already exist."
+    )]
+    pub set_source: Option<String>,
+    #[arg(
+        long,
+        value_delimiter = ',',
+        help = "Add a tag to the job application. May be repeated or comma-separated."
+    )]
+    pub add_tag: Vec<String>,
+    #[arg(
+        long,
+        value_delimiter = ',',
+        help = "Remove a tag from the job application. May be repeated or comma-separated."
+    )]
+    pub remove_tag: Vec<String>,
+}
+
+impl UpdateArgs {
+    /// Returns whether any of the `--set-*` or tag flags were provided.
+    pub fn has_updates(&self) -> bool {
+        self.set_company.is_some()
+            || self.set_title.is_some()
+            || self.set_status.is_some()
+            || self.set_link.is_some()
+            || self.set_notes.is_some()
+            || self.set_sprint.is_some()
+            || self.set_location.is_some()
+            || self.set_country.is_some()
+            || self.set_work_mode.is_some()
+            || self.set_source.is_some()
+            || !self.add_tag.is_empty()
+            || !self.remove_tag.is_empty()
+    }
+}
+
+/// All arguments you can use to delete a job application.
+#[derive(Debug, Parser)]
+pub struct DeleteArgs {
+    /// Query the job application to delete.
+    #[command(flatten)]
+    pub query_args: QueryArgs,
+    #[arg(short, long, help = "Skip the confirmation prompt.")]
+    pub yes: bool,
+}
+
+/// All subcommands for updating or deleting multiple job applications at once.
+#[derive(Debug, Subcommand)]
+pub enum BulkOption {
+    /// Apply the same changes to every selected job application.
+    Update(Box<BulkUpdateArgs>),
+    /// Move every selected job application to the trash.
+    Delete(Box<BulkDeleteArgs>),
+}
+
+/// All arguments you can use to update multiple job applications at once.
+#[derive(Debug, Parser)]
+pub struct BulkUpdateArgs {
+    /// Query the job applications and set the changes to apply.
+    #[command(flatten)]
+    pub update_args: UpdateArgs,
+    #[arg(
+        long,
+        help = "Select every matched job application instead of choosing from a list."
+    )]
+    pub all: bool,
+    #[arg(short, long, help = "Skip the confirmation prompt.")]
+    pub yes: bool,
+}
+
+/// All arguments you can use to delete multiple job applications at once.
+#[derive(Debug, Parser)]
+pub struct BulkDeleteArgs {
+    /// Query the job applications to delete.
+    #[command(flatten)]
+    pub query_args: QueryArgs,
+    #[arg(
+        long,
+        help = "Select every matched job application instead of choosing from a list."
+    )]
+    pub all: bool,
+    #[arg(short, long, help = "Skip the confirmation prompt.")]
+    pub yes: bool,
 }
 
 /// All subcommands for interacting with the configuration file for `fetters`.
@@ -91,6 +414,455 @@ pub enum ConfigOption {
     Show,
 }
 
+/// All arguments for exporting the database.
+#[derive(Debug, Parser)]
+pub struct ExportArgs {
+    #[arg(
+        long,
+        value_name = "FILE",
+        required = true,
+        help = "Export every table in the database to a JSON archive."
+    )]
+    pub all: PathBuf,
+}
+
+/// All arguments for checking and repairing the integrity of the database.
+#[derive(Debug, Parser)]
+pub struct DoctorArgs {
+    #[arg(long, help = "Only report integrity problems without repairing them.")]
+    pub dry_run: bool,
+    #[arg(short, long, help = "Skip the confirmation prompt.")]
+    pub yes: bool,
+}
+
+/// All arguments for marking inactive job applications as ghosted.
+#[derive(Debug, Default, Parser)]
+pub struct SweepArgs {
+    #[arg(
+        long,
+        value_parser = clap::value_parser!(i64).range(1..),
+        help = "Override the number of days without activity after which a job application is considered ghosted."
+    )]
+    pub days: Option<i64>,
+    #[arg(
+        long,
+        help = "Only list inactive job applications without marking them as ghosted."
+    )]
+    pub dry_run: bool,
+    #[arg(short, long, help = "Skip the confirmation prompt.")]
+    pub yes: bool,
+}
+
+/// All arguments for showing the audit log.
+#[derive(Debug, Parser)]
+pub struct LogArgs {
+    #[arg(
+        short = 'n',
+        long,
+        default_value_t = 20,
+        value_parser = clap::value_parser!(i64).range(1..),
+        help = "The number of operations to show."
+    )]
+    pub limit: i64,
+}
+
+/// All arguments for searching job applications.
+#[derive(Debug, Parser)]
+pub struct SearchArgs {
+    /// The search query. Supports phrases ("senior engineer"), prefixes (eng*), and the AND, OR,
+    /// and NOT operators.
+    pub query: String,
+    #[arg(
+        long,
+        help = "Only search job applications in sprints matching this name."
+    )]
+    pub sprint: Option<String>,
+    #[arg(
+        short = 'n',
+        long,
+        default_value_t = 20,
+        value_parser = clap::value_parser!(i64).range(1..),
+        help = "The maximum number of job applications to show."
+    )]
+    pub limit: i64,
+}
+
+/// All arguments for undoing changes recorded in the audit log.
+#[derive(Debug, Parser)]
+pub struct UndoArgs {
+    /// The number of operations to undo.
+    #[arg(default_value_t = 1, value_parser = clap::value_parser!(i64).range(1..))]
+    pub count: i64,
+    #[arg(
+        long,
+        help = "Also undo changes made by the sweep on startup, which are skipped by default."
+    )]
+    pub include_sweeps: bool,
+    #[arg(short, long, help = "Skip the confirmation prompt.")]
+    pub yes: bool,
+}
+
+/// All subcommands for managing deleted job applications.
+#[derive(Debug, Subcommand)]
+pub enum TrashOption {
+    /// List the job applications in the trash.
+    List {
+        #[arg(
+            long,
+            help = "Only list job applications deleted more than this long ago (ie. 30d, 2w, or 12h)."
+        )]
+        older_than: Option<String>,
+    },
+    /// Restore job applications from the trash. Prompts for the job applications to restore if no
+    /// flag is provided.
+    Restore {
+        #[arg(
+            long,
+            help = "The ID of a job application to restore. Can be repeated."
+        )]
+        id: Vec<i32>,
+        #[arg(long, help = "Restore every job application in the trash.")]
+        all: bool,
+        #[arg(short, long, help = "Skip the confirmation prompt.")]
+        yes: bool,
+    },
+    /// Permanently delete job applications in the trash.
+    Purge {
+        #[arg(
+            long,
+            help = "Only purge job applications deleted more than this long ago (ie. 30d, 2w, or 12h)."
+        )]
+        older_than: Option<String>,
+        #[arg(short, long, help = "Skip the confirmation prompt.")]
+        yes: bool,
+    },
+}
+
+/// All arguments for restoring the database from a JSON archive.
+#[derive(Debug, Parser)]
+pub struct RestoreArgs {
+    /// The path to the JSON archive.
+    pub path: PathBuf,
+    #[arg(short, long, help = "Skip the confirmation prompt.")]
+    pub yes: bool,
+}
+
+/// All subcommands for importing job applications.
+#[derive(Debug, Subcommand)]
+pub enum ImportOption {
+    /// Import job applications from a CSV file with a header row.
+    Csv(CsvImportArgs),
+}
+
+/// All arguments for importing job applications from a CSV file. Each `--*-column` flag maps a CSV
+/// header (matched case-insensitively) to a job application field.
+#[derive(Debug, Parser)]
+pub struct CsvImportArgs {
+    /// The path to the CSV file.
+    pub path: PathBuf,
+    #[arg(
+        long,
+        default_value = "company",
+        help = "The column containing the company name."
+    )]
+    pub company_column: String,
+    #[arg(
+        long,
+        default_value = "title",
+        help = "The column containing the job title."
+    )]
+    pub title_column: String,
+    #[arg(
+        long,
+        default_value = "status",
+        help = "The column containing the application status."
+    )]
+    pub status_column: String,
+    #[arg(long, default_value = "link", help = "The column containing the link.")]
+    pub link_column: String,
+    #[arg(
+        long,
+        default_value = "notes",
+        help = "The column containing the notes."
+    )]
+    pub notes_column: String,
+    #[arg(
+        long,
+        default_value = "created",
+        help = "The column containing the created timestamp (YYYY-MM-DD or YYYY-MM-DD HH:MM:SS)."
+    )]
+    pub created_column: String,
+    #[arg(
+        long,
+        default_value = "sprint",
+        help = "The column containing the sprint name. Missing sprints are created."
+    )]
+    pub sprint_column: String,
+    #[arg(
+        long,
+        default_value = "location",
+        help = "The column containing the location."
+    )]
+    pub location_column: String,
+    #[arg(
+        long,
+        default_value = "country",
+        help = "The column containing the country."
+    )]
+    pub country_column: String,
+    #[arg(
+        long,
+        default_value = "work_mode",
+        help = "The column containing the work arrangement (remote, hybrid, or on-site)."
+    )]
+    pub work_mode_column: String,
+    #[arg(
+        long,
+        default_value = "source",
+        help = "The column containing the application source. Missing sources are created."
+    )]
+    pub source_column: String,
+    #[arg(
+        long,
+        default_value = "PENDING",
+        help = "The status to use for rows without a status."
+    )]
+    pub default_status: String,
+    #[arg(
+        long,
+        help = "Validate the file and report what would be imported without writing anything."
+    )]
+    pub dry_run: bool,
+}
+
+/// All subcommands for managing contacts.
+#[derive(Debug, Subcommand)]
+pub enum ContactOption {
+    /// Add a new contact.
+    Add(ContactArgs),
+    /// List all contacts.
+    List {
+        #[arg(
+            short,
+            long,
+            help = "Filter contacts by company. Supports searching with partial text."
+        )]
+        company: Option<String>,
+    },
+    /// Link contacts to a job application.
+    Link(LinkArgs),
+    /// Unlink contacts from a job application.
+    Unlink(LinkArgs),
+}
+
+/// All arguments you can use to add a new contact. Fields that are not provided with a flag will
+/// be prompted for.
+#[derive(Debug, Parser)]
+pub struct ContactArgs {
+    /// The name of the contact.
+    pub name: String,
+    #[arg(short, long, help = "Set the email address of the contact.")]
+    pub email: Option<String>,
+    #[arg(short, long, help = "Set the phone number of the contact.")]
+    pub phone: Option<String>,
+    #[arg(
+        short,
+        long,
+        help = "Set the role of the contact (ie. recruiter or hiring manager)."
+    )]
+    pub role: Option<String>,
+    #[arg(short, long, help = "Set the company the contact works for.")]
+    pub company: Option<String>,
+    #[arg(long, help = "Set the LinkedIn profile URL of the contact.")]
+    pub linkedin: Option<String>,
+    #[arg(short, long, help = "Set notes for the contact.")]
+    pub notes: Option<String>,
+    #[arg(
+        long,
+        help = "Never prompt. Fields that are not provided with a flag are left empty."
+    )]
+    pub no_input: bool,
+}
+
+/// All arguments you can use to link or unlink contacts.
+#[derive(Debug, Parser)]
+pub struct LinkArgs {
+    /// Query the job application to link or unlink contacts.
+    #[command(flatten)]
+    pub query_args: QueryArgs,
+    #[arg(
+        long = "contact-id",
+        help = "Target a contact by its ID instead of selecting contacts from a menu."
+    )]
+    pub contact_id: Option<i32>,
+}
+
+/// All arguments you can use to set a follow-up reminder.
+#[derive(Debug, Parser)]
+pub struct RemindArgs {
+    /// The reminder message.
+    pub message: String,
+    /// Query the job application to set a reminder for.
+    #[command(flatten)]
+    pub query_args: QueryArgs,
+    #[arg(
+        long = "in",
+        default_value = "7d",
+        help = "Set when the reminder is due from now. Use a number followed by h, d, or w (ie. 7d)."
+    )]
+    pub due_in: String,
+}
+
+/// All arguments you can use to list or complete follow-up reminders.
+#[derive(Debug, Parser)]
+pub struct DueArgs {
+    #[arg(
+        long,
+        default_value = "7d",
+        help = "Show upcoming reminders due within this duration from now. Use a number followed by h, d, or w (ie. 7d)."
+    )]
+    pub within: String,
+    #[arg(
+        long,
+        value_name = "ID",
+        help = "Mark the reminder with this ID as done. May be repeated."
+    )]
+    pub done: Vec<i32>,
+}
+
+/// All subcommands for tracking compensation and comparing offers.
+#[derive(Debug, Subcommand)]
+pub enum OffersOption {
+    /// Compare all job applications with an offered base salary side by side.
+    Compare,
+    /// Set the compensation details of a job application.
+    Set(Box<CompensationArgs>),
+}
+
+/// All arguments you can use to set the compensation details of a job application. Amounts are
+/// yearly and accept a k or m suffix (ie. 120k). If any value is provided with a flag, the
+/// compensation details are updated with those values without prompting.
+#[derive(Debug, Parser)]
+pub struct CompensationArgs {
+    /// Query the job application to set compensation details for.
+    #[command(flatten)]
+    pub query_args: QueryArgs,
+    #[arg(long, help = "Set the lower end of the posted salary range.")]
+    pub posted_min: Option<String>,
+    #[arg(long, help = "Set the upper end of the posted salary range.")]
+    pub posted_max: Option<String>,
+    #[arg(long, help = "Set the salary you asked for.")]
+    pub expected: Option<String>,
+    #[arg(long, help = "Set the offered base salary.")]
+    pub base: Option<String>,
+    #[arg(long, help = "Set the offered yearly bonus.")]
+    pub bonus: Option<String>,
+    #[arg(long, help = "Set the yearly value of the offered equity.")]
+    pub equity: Option<String>,
+    #[arg(long, help = "Set the currency of all amounts (ie. USD).")]
+    pub currency: Option<String>,
+    #[arg(
+        long,
+        help = "Set the date by which the offer must be accepted (YYYY-MM-DD)."
+    )]
+    pub deadline: Option<String>,
+    #[arg(long, help = "Set notes about the offered benefits.")]
+    pub benefits: Option<String>,
+}
+
+impl CompensationArgs {
+    /// Returns whether any compensation value was provided with a flag.
+    pub fn has_updates(&self) -> bool {
+        self.posted_min.is_some()
+            || self.posted_max.is_some()
+            || self.expected.is_some()
+            || self.base.is_some()
+            || self.bonus.is_some()
+            || self.equity.is_some()
+            || self.currency.is_some()
+            || self.deadline.is_some()
+            || self.benefits.is_some()
+    }
+}
+
+/// All subcommands for managing interviews. Each subcommand supports the same query options as
+/// the `list` subcommand for selecting the job application.
+#[derive(Debug, Subcommand)]
+pub enum InterviewOption {
+    /// Add an interview round to a job application.
+    Add(QueryArgs),
+    /// List all interviews for the job applications matching the query.
+    List(QueryArgs),
+    /// Update an interview round.
+    Update(QueryArgs),
+    /// Delete an interview round.
+    Delete(QueryArgs),
+}
+
+/// All subcommands for managing application statuses.
+#[derive(Debug, Subcommand)]
+pub enum StatusOption {
+    /// Add a new status to the end of the status order.
+    Add {
+        /// The name of the status.
+        name: String,
+        #[arg(
+            long,
+            help = "Set the display color. Use an ANSI color name (ie. red or bright-green) or a hex color (ie. #ff8800)."
+        )]
+        color: Option<String>,
+        #[arg(
+            long,
+            help = "Mark job applications in this status as finished (ie. rejected or hired)."
+        )]
+        terminal: bool,
+    },
+    /// Set the display color of a status.
+    Color {
+        /// The name of the status.
+        name: String,
+        /// An ANSI color name (ie. red or bright-green), a hex color (ie. #ff8800), or `none` to
+        /// remove the color.
+        color: String,
+    },
+    /// List all statuses in their sort order.
+    List,
+    /// Remove a status.
+    Remove {
+        /// The name of the status.
+        name: String,
+        #[arg(
+            long,
+            help = "Move job applications and status history referencing this status to another status."
+        )]
+        replace_with: Option<String>,
+        #[arg(short, long, help = "Skip the confirmation prompt.")]
+        yes: bool,
+    },
+    /// Rename a status.
+    Rename {
+        /// The current name of the status.
+        name: String,
+        /// The new name of the status.
+        new_name: String,
+    },
+    /// Move a status to a new position in the status order.
+    Reorder {
+        /// The name of the status.
+        name: String,
+        /// The new position of the status, starting at 1.
+        position: usize,
+    },
+    /// Set whether job applications in a status are finished.
+    Terminal {
+        /// The name of the status.
+        name: String,
+        /// Whether the status is terminal.
+        #[arg(action = clap::ArgAction::Set)]
+        is_terminal: bool,
+    },
+}
+
 /// All subcommands for managing job sprints.
 #[derive(Debug, Subcommand)]
 pub enum SprintOption {
@@ -100,9 +872,79 @@ pub enum SprintOption {
     New {
         #[arg(short, long, help = "Override the default sprint name (YYYY-MM-DD).")]
         name: Option<String>,
+        #[arg(
+            short,
+            long,
+            help = "Set a goal for the number of job applications in this sprint (ie. 50 or 10/week)."
+        )]
+        goal: Option<String>,
+    },
+    /// Edit the dates or goal of a job sprint. Prompts for the goal if no flag is provided.
+    Edit(SprintEditArgs),
+    /// Rename a job sprint.
+    Rename {
+        /// The current name of the sprint.
+        name: String,
+        /// The new name of the sprint.
+        new_name: String,
+    },
+    /// Delete a job sprint.
+    Delete {
+        /// The name of the sprint.
+        name: String,
+        #[arg(
+            long,
+            help = "Move the job applications in this sprint to another sprint before deleting it."
+        )]
+        reassign_to: Option<String>,
+        #[arg(short, long, help = "Skip the confirmation prompt.")]
+        yes: bool,
+    },
+    /// Merge a job sprint into another one. All job applications are moved and the merged sprint
+    /// is deleted.
+    Merge {
+        /// The name of the sprint to merge and delete.
+        from: String,
+        /// The name of the sprint to merge into.
+        into: String,
+        #[arg(short, long, help = "Skip the confirmation prompt.")]
+        yes: bool,
     },
     /// Show all job sprints tracked by `fetters`.
     ShowAll,
     /// Set the current job sprint.
     Set,
 }
+
+/// All arguments for editing a job sprint.
+#[derive(Debug, Parser)]
+pub struct SprintEditArgs {
+    #[arg(
+        short,
+        long,
+        help = "The name of the sprint to edit. Defaults to the current sprint."
+    )]
+    pub sprint: Option<String>,
+    #[arg(
+        short,
+        long,
+        help = "Set a goal for the number of job applications in this sprint (ie. 50 or 10/week)."
+    )]
+    pub goal: Option<String>,
+    #[arg(
+        long,
+        conflicts_with = "goal",
+        help = "Remove the goal of this sprint."
+    )]
+    pub clear_goal: bool,
+    #[arg(long, help = "Set the start date of this sprint (YYYY-MM-DD).")]
+    pub start_date: Option<String>,
+    #[arg(long, help = "Set the end date of this sprint (YYYY-MM-DD).")]
+    pub end_date: Option<String>,
+    #[arg(
+        long,
+        conflicts_with = "end_date",
+        help = "Remove the end date of this sprint."
+    )]
+    pub clear_end_date: bool,
+}
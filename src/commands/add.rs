@@ -1,15 +1,20 @@
 //! Contains a function called by the CLI when adding a new job.
 
-use chrono::Local;
 use diesel::sqlite::SqliteConnection;
 use inquire::{Confirm, Select, Text};
 use owo_colors::OwoColorize;
+use strum::IntoEnumIterator;
 
+use crate::cli::AddArgs;
+use crate::repositories::sprint::SprintRepository;
+use crate::utils::dates::{normalize_timestamp, now_timestamp};
 use crate::utils::prompt::get_inquire_config;
+use crate::utils::tags::normalize_tags;
+use crate::utils::titles::use_title_by_name;
 use crate::{errors::FettersError, utils::display::display_single_job};
 use crate::{
     models::{
-        job::{NewJob, TabledJob},
+        job::{NewJob, TabledJob, WorkMode},
         sprint::QueriedSprint,
         status::QueriedStatus,
         title::NewTitle,
@@ -17,86 +22,180 @@ use crate::{
     utils::titles::create_or_use_title,
 };
 use crate::{
-    repositories::{job::JobRepository, statuses::StatusRepository, title::TitleRepository},
+    repositories::{
+        job::JobRepository, source::SourceRepository, statuses::StatusRepository,
+        title::TitleRepository,
+    },
     utils::titles::TitleType,
 };
 
-/// Run the inquire menu to track a new job application.
+/// Track a new job application. Any field that was not provided through `add_args` is set with
+/// an inquire prompt unless `--no-input` was specified.
 pub fn add_job(
     connection: &mut SqliteConnection,
-    company_name: &str,
+    add_args: &AddArgs,
     current_sprint: &QueriedSprint,
 ) -> Result<(), FettersError> {
-    let title_type = create_or_use_title(connection)?;
-    let status = select_status(connection)?;
-    let link = input_link()?;
-    let notes = input_notes()?;
+    let title_type = match &add_args.title {
+        Some(title) => use_title_by_name(connection, title)?,
+        None if add_args.no_input => {
+            return Err(FettersError::MissingInput("title".to_string()));
+        }
+        None => create_or_use_title(connection)?,
+    };
+    let status = match &add_args.status {
+        Some(status_name) => {
+            let mut status_repo = StatusRepository { connection };
+            status_repo
+                .get_status_by_name(status_name)?
+                .ok_or(FettersError::StatusNotFound(status_name.to_string()))?
+        }
+        None if add_args.no_input => {
+            return Err(FettersError::MissingInput("status".to_string()));
+        }
+        None => select_status(connection)?,
+    };
+    // Empty flag values are treated the same as leaving the field out.
+    let link = match &add_args.link {
+        Some(link) if link.trim().is_empty() => None,
+        Some(link) => Some(link.to_string()),
+        None if add_args.no_input => None,
+        None => input_link()?,
+    };
+    let notes = match &add_args.notes {
+        Some(notes) if notes.trim().is_empty() => None,
+        Some(notes) => Some(notes.to_string()),
+        None if add_args.no_input => None,
+        None => input_notes()?,
+    };
+    let work_mode = match &add_args.work_mode {
+        Some(work_mode) => Some(WorkMode::parse(work_mode)?),
+        None if add_args.no_input => None,
+        None => select_work_mode()?,
+    }
+    .map(|work_mode| work_mode.to_string());
+    let location = match &add_args.location {
+        Some(location) if location.trim().is_empty() => None,
+        Some(location) => Some(location.to_string()),
+        None if add_args.no_input => None,
+        None => input_location()?,
+    };
+    let country = match &add_args.country {
+        Some(country) if country.trim().is_empty() => None,
+        Some(country) => Some(country.to_string()),
+        None if add_args.no_input => None,
+        None => input_country()?,
+    };
+    let source_name = match &add_args.source {
+        Some(source_name) => Some(source_name.trim().to_uppercase()),
+        None if add_args.no_input => None,
+        None => select_source(connection)?,
+    };
+    let tag_names = if add_args.tag.is_empty() && !add_args.no_input {
+        input_tags()?
+    } else {
+        normalize_tags(&add_args.tag)
+    };
+    let (sprint_id, sprint_name) = match &add_args.sprint {
+        Some(sprint_name) => {
+            let mut sprint_repo = SprintRepository { connection };
+            let queried_sprint = sprint_repo
+                .get_sprint_by_name(sprint_name)?
+                .ok_or(FettersError::SprintNotFound(sprint_name.to_string()))?;
 
-    let created = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+            (queried_sprint.id, queried_sprint.name)
+        }
+        None => (current_sprint.id, current_sprint.name.clone()),
+    };
+
+    let created = match &add_args.created {
+        Some(created) => normalize_timestamp(created)?,
+        None => now_timestamp(),
+    };
 
     let tabled_job = TabledJob {
         // NOTE: The ID is set to an arbitrary value to satisfy struct requirements.
         id: 0,
         created: created.clone(),
-        company_name: company_name.to_string(),
+        company_name: add_args.company.to_string(),
         title: Some(match title_type {
             TitleType::NewTitle(ref title) => title.to_string(),
             TitleType::QueriedTitle(ref queried_title) => queried_title.name.to_string(),
         }),
         status: Some(status.name),
+        status_color: status.color,
         link: link.clone(),
         notes: notes.clone(),
+        work_mode: work_mode.clone(),
+        location: location.clone(),
+        country: country.clone(),
+        source: source_name.clone(),
+        tags: (!tag_names.is_empty()).then(|| tag_names.join(", ")),
     };
 
-    display_single_job(&tabled_job);
+    display_single_job(&tabled_job, &[]);
+
+    if !add_args.no_input && !confirm_new_entry()? {
+        println!("{}", "Cancelled.".red().bold());
+        return Ok(());
+    }
+
+    let title_id = match title_type {
+        TitleType::NewTitle(new_title) => {
+            let mut title_repo = TitleRepository { connection };
+            title_repo.add_title(NewTitle { name: &new_title })?.id
+        }
+        TitleType::QueriedTitle(queried_title) => queried_title.id,
+    };
+    let source_id = match source_name {
+        Some(source_name) => {
+            let mut source_repo = SourceRepository { connection };
+            Some(source_repo.add_source(&source_name)?.id)
+        }
+        None => None,
+    };
+    let new_job = NewJob {
+        company_name: &add_args.company,
+        created,
+        title_id,
+        status_id: status.id,
+        link: link.as_deref(),
+        notes: notes.as_deref(),
+        sprint_id,
+        location: location.as_deref(),
+        country: country.as_deref(),
+        work_mode: work_mode.as_deref(),
+        source_id,
+        deleted_at: None,
+    };
 
+    let mut job_repo = JobRepository { connection };
+    job_repo.add_job(new_job, &tag_names)?;
+
+    println!(
+        "{}",
+        format!("\nCreated new entry for sprint [{sprint_name}]!\n")
+            .green()
+            .bold()
+    );
+
+    Ok(())
+}
+
+/// Confirm whether the new entry should be created.
+fn confirm_new_entry() -> Result<bool, FettersError> {
     loop {
         match Confirm::new("Confirm new entry?")
             .with_default(true)
             .with_render_config(get_inquire_config())
             .prompt_skippable()?
         {
-            Some(true) => {
-                let title_id = match title_type {
-                    TitleType::NewTitle(new_title) => {
-                        let mut title_repo = TitleRepository { connection };
-                        title_repo.add_title(NewTitle { name: &new_title })?.id
-                    }
-                    TitleType::QueriedTitle(queried_title) => queried_title.id,
-                };
-                let new_job = NewJob {
-                    company_name,
-                    created,
-                    title_id,
-                    status_id: status.id,
-                    link: link.as_deref(),
-                    notes: notes.as_deref(),
-                    sprint_id: current_sprint.id,
-                };
-
-                let mut job_repo = JobRepository { connection };
-                job_repo.add_job(new_job)?;
-
-                println!(
-                    "{}",
-                    format!(
-                        "\nCreated new entry for sprint [{}]!\n",
-                        current_sprint.name
-                    )
-                    .green()
-                    .bold()
-                );
-
-                return Ok(());
-            }
-            Some(false) => {
-                println!("{}", "Cancelled.".red().bold());
-                return Ok(());
-            }
+            Some(confirmed) => return Ok(confirmed),
             None => println!("{}", "Invalid input, try again".red().bold()),
         }
     }
 }
+
 /// Select a job application status.
 fn select_status(connection: &mut SqliteConnection) -> Result<QueriedStatus, FettersError> {
     let mut status_repo = StatusRepository { connection };
@@ -124,6 +223,60 @@ fn input_link() -> Result<Option<String>, FettersError> {
     )
 }
 
+/// Select an optional source for the job application.
+fn select_source(connection: &mut SqliteConnection) -> Result<Option<String>, FettersError> {
+    let mut source_repo = SourceRepository { connection };
+    let all_sources = source_repo.get_all_sources()?;
+
+    Ok(Select::new(
+        "[OPTIONAL] Select where you found this application:",
+        all_sources,
+    )
+    .with_render_config(get_inquire_config())
+    .prompt_skippable()?
+    .map(|source| source.name))
+}
+
+/// Select an optional work arrangement for the job application.
+fn select_work_mode() -> Result<Option<WorkMode>, FettersError> {
+    Ok(Select::new(
+        "[OPTIONAL] Select the work arrangement for this application:",
+        WorkMode::iter().collect(),
+    )
+    .with_render_config(get_inquire_config())
+    .prompt_skippable()?)
+}
+
+/// Input an optional location for the job application.
+fn input_location() -> Result<Option<String>, FettersError> {
+    Ok(
+        Text::new("[OPTIONAL] Enter the city or region of this job:")
+            .with_render_config(get_inquire_config())
+            .prompt_skippable()?
+            .filter(|location| !location.trim().is_empty()),
+    )
+}
+
+/// Input an optional country for the job application.
+fn input_country() -> Result<Option<String>, FettersError> {
+    Ok(Text::new("[OPTIONAL] Enter the country of this job:")
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?
+        .filter(|country| !country.trim().is_empty()))
+}
+
+/// Input optional comma-separated tags for the job application.
+fn input_tags() -> Result<Vec<String>, FettersError> {
+    let tags = Text::new("[OPTIONAL] Enter comma-separated tags for this job application:")
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?
+        .unwrap_or_default();
+
+    Ok(normalize_tags(
+        &tags.split(',').map(str::to_string).collect::<Vec<String>>(),
+    ))
+}
+
 /// Input optional notes for the job application.
 fn input_notes() -> Result<Option<String>, FettersError> {
     Ok(
@@ -0,0 +1,161 @@
+//! Contains functions called by the CLI when updating or deleting multiple jobs at once.
+
+use diesel::SqliteConnection;
+use inquire::MultiSelect;
+use owo_colors::OwoColorize;
+
+use crate::{
+    cli::{BulkDeleteArgs, BulkUpdateArgs, QueryArgs},
+    commands::update::ResolvedUpdates,
+    errors::FettersError,
+    models::{job::TabledJob, sprint::QueriedSprint},
+    repositories::job::JobRepository,
+    utils::{
+        display::display_jobs,
+        prompt::{confirm, get_inquire_config},
+        tags::normalize_tags,
+    },
+};
+
+/// Apply the same changes to multiple tracked job applications.
+pub fn bulk_update_jobs(
+    connection: &mut SqliteConnection,
+    bulk_update_args: &mut BulkUpdateArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let update_args = &mut bulk_update_args.update_args;
+
+    if !update_args.has_updates() {
+        return Err(FettersError::NoUpdatesProvided);
+    }
+
+    let Some(selected_jobs) = select_jobs(
+        connection,
+        &mut update_args.query_args,
+        bulk_update_args.all,
+        "Select the jobs you want to modify:",
+        current_sprint,
+    )?
+    else {
+        return Ok(());
+    };
+
+    // Resolve the `--set-*` flags before confirming so invalid values are reported early.
+    let resolved_updates = ResolvedUpdates::resolve(connection, update_args)?;
+
+    if confirm(
+        &format!(
+            "Apply updates to {} job application(s)?",
+            selected_jobs.len()
+        ),
+        bulk_update_args.yes,
+    )? {
+        let job_ids: Vec<i32> = selected_jobs.iter().map(|job| job.id).collect();
+
+        let mut job_repo = JobRepository { connection };
+        job_repo.update_jobs(
+            &job_ids,
+            &resolved_updates.job_update(update_args),
+            &normalize_tags(&update_args.add_tag),
+            &normalize_tags(&update_args.remove_tag),
+        )?;
+
+        println!(
+            "{}",
+            format!("\nUpdated {} job application(s)!\n", job_ids.len())
+                .green()
+                .bold()
+        );
+    }
+
+    Ok(())
+}
+
+/// Move multiple tracked job applications to the trash.
+pub fn bulk_delete_jobs(
+    connection: &mut SqliteConnection,
+    bulk_delete_args: &mut BulkDeleteArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let Some(selected_jobs) = select_jobs(
+        connection,
+        &mut bulk_delete_args.query_args,
+        bulk_delete_args.all,
+        "Select the jobs you want to delete:",
+        current_sprint,
+    )?
+    else {
+        return Ok(());
+    };
+
+    if confirm(
+        &format!(
+            "Confirm deletion of {} job application(s)?",
+            selected_jobs.len()
+        ),
+        bulk_delete_args.yes,
+    )? {
+        let job_ids: Vec<i32> = selected_jobs.iter().map(|job| job.id).collect();
+
+        let mut job_repo = JobRepository { connection };
+        job_repo.delete_jobs(&job_ids)?;
+
+        println!(
+            "{}",
+            format!(
+                "\nMoved {} job application(s) to the trash!\n",
+                job_ids.len()
+            )
+            .green()
+            .bold()
+        );
+    }
+
+    Ok(())
+}
+
+/// Display the job applications matching the query and let the user select a subset of them.
+/// Every matched job application is selected if `all` is set or a job was targeted by its ID.
+/// Returns `None` if the selection was skipped or nothing was selected.
+fn select_jobs(
+    connection: &mut SqliteConnection,
+    query_args: &mut QueryArgs,
+    all: bool,
+    message: &str,
+    current_sprint: &QueriedSprint,
+) -> Result<Option<Vec<TabledJob>>, FettersError> {
+    // Search the default sprint if no sprint filter or job ID was specified.
+    if query_args.sprint.is_none() && query_args.id.is_none() {
+        query_args.sprint = Some(current_sprint.name.clone());
+    }
+
+    let mut job_repo = JobRepository { connection };
+    let matched_jobs = job_repo.list_jobs(query_args, current_sprint)?;
+
+    if matched_jobs.is_empty() {
+        if let Some(job_id) = query_args.id {
+            return Err(FettersError::JobNotFound(job_id));
+        }
+
+        return Err(FettersError::NoJobsAvailable(
+            query_args
+                .sprint
+                .clone()
+                .unwrap_or(current_sprint.name.clone()),
+        ));
+    }
+
+    display_jobs(
+        &matched_jobs,
+        query_args.sprint.as_ref().unwrap_or(&current_sprint.name),
+    );
+
+    if all || query_args.id.is_some() {
+        return Ok(Some(matched_jobs));
+    }
+
+    Ok(MultiSelect::new(message, matched_jobs)
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?
+        .filter(|selected_jobs| !selected_jobs.is_empty()))
+}
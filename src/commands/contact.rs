@@ -0,0 +1,258 @@
+//! Contains functions called by the CLI when managing contacts.
+
+use diesel::SqliteConnection;
+use inquire::MultiSelect;
+use owo_colors::OwoColorize;
+
+use crate::{
+    cli::{ContactArgs, LinkArgs, OutputFormat},
+    errors::FettersError,
+    models::{
+        contact::{NewContact, QueriedContact},
+        sprint::QueriedSprint,
+    },
+    repositories::contact::ContactRepository,
+    utils::{
+        display::{display_contacts, display_single_job},
+        format::print_records,
+        prompt::{get_inquire_config, input_optional_text},
+        selection::select_job,
+    },
+};
+
+/// Add a new contact. Any field that was not provided through `contact_args` is set with an
+/// inquire prompt unless `--no-input` was specified.
+pub fn add_contact(
+    connection: &mut SqliteConnection,
+    contact_args: &ContactArgs,
+) -> Result<(), FettersError> {
+    let input_field = |value: &Option<String>, message: &str| match value {
+        Some(value) => Ok(Some(value.to_string())),
+        None if contact_args.no_input => Ok(None),
+        None => input_optional_text(message, ""),
+    };
+
+    let email = input_field(
+        &contact_args.email,
+        "[OPTIONAL] Enter the email address of this contact:",
+    )?;
+    let phone = input_field(
+        &contact_args.phone,
+        "[OPTIONAL] Enter the phone number of this contact:",
+    )?;
+    let role = input_field(
+        &contact_args.role,
+        "[OPTIONAL] Enter the role of this contact (ie. recruiter or hiring manager):",
+    )?;
+    let company = input_field(
+        &contact_args.company,
+        "[OPTIONAL] Enter the company this contact works for:",
+    )?;
+    let linkedin_url = input_field(
+        &contact_args.linkedin,
+        "[OPTIONAL] Enter the LinkedIn profile URL of this contact:",
+    )?;
+    let notes = input_field(
+        &contact_args.notes,
+        "[OPTIONAL] Enter any notes for this contact:",
+    )?;
+
+    let mut contact_repo = ContactRepository { connection };
+    let contact = contact_repo.add_contact(NewContact {
+        name: &contact_args.name,
+        email: email.as_deref(),
+        phone: phone.as_deref(),
+        role: role.as_deref(),
+        company: company.as_deref(),
+        linkedin_url: linkedin_url.as_deref(),
+        notes: notes.as_deref(),
+    })?;
+
+    println!(
+        "{}",
+        format!(
+            "\nAdded contact {} with ID [{}]!\n",
+            contact.name, contact.id
+        )
+        .green()
+        .bold()
+    );
+
+    Ok(())
+}
+
+/// List all contacts, optionally filtered by company.
+pub fn list_contacts(
+    connection: &mut SqliteConnection,
+    company: Option<&str>,
+    output_format: OutputFormat,
+) -> Result<(), FettersError> {
+    let mut contact_repo = ContactRepository { connection };
+    let contacts = contact_repo.list_contacts(company)?;
+
+    if output_format != OutputFormat::Table {
+        return print_records(&contacts, output_format);
+    }
+
+    if contacts.is_empty() {
+        println!("{}", "No contacts found.".yellow().bold());
+        return Ok(());
+    }
+
+    display_contacts(&contacts, &format!("CONTACTS [{} LISTED]", contacts.len()));
+
+    Ok(())
+}
+
+/// Link contacts to a job application.
+pub fn link_contacts(
+    connection: &mut SqliteConnection,
+    link_args: &mut LinkArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let Some(job) = select_job(
+        connection,
+        &mut link_args.query_args,
+        current_sprint,
+        "Select the job application to link contacts to:",
+    )?
+    else {
+        return Ok(());
+    };
+
+    let mut contact_repo = ContactRepository { connection };
+
+    let selected_contacts = match link_args.contact_id {
+        Some(contact_id) => vec![
+            contact_repo
+                .get_contact(contact_id)?
+                .ok_or(FettersError::ContactNotFound(contact_id))?,
+        ],
+        None => {
+            let linked_ids: Vec<i32> = contact_repo
+                .get_job_contacts(job.id)?
+                .iter()
+                .map(|contact| contact.id)
+                .collect();
+            let available_contacts: Vec<QueriedContact> = contact_repo
+                .list_contacts(None)?
+                .into_iter()
+                .filter(|contact| !linked_ids.contains(&contact.id))
+                .collect();
+
+            if available_contacts.is_empty() {
+                println!(
+                    "{}",
+                    format!("No contacts are available to link to {}.", job.company_name)
+                        .yellow()
+                        .bold()
+                );
+                return Ok(());
+            }
+
+            match MultiSelect::new("Select the contacts to link:", available_contacts)
+                .with_render_config(get_inquire_config())
+                .prompt_skippable()?
+            {
+                Some(selections) => selections,
+                None => return Ok(()),
+            }
+        }
+    };
+
+    for contact in selected_contacts.iter() {
+        contact_repo.link_contact(job.id, contact.id)?;
+    }
+
+    println!(
+        "{}",
+        format!(
+            "\nLinked {} contact(s) to {}!",
+            selected_contacts.len(),
+            job.company_name
+        )
+        .green()
+        .bold()
+    );
+
+    let job_contacts = contact_repo.get_job_contacts(job.id)?;
+    display_single_job(&job, &job_contacts);
+
+    Ok(())
+}
+
+/// Unlink contacts from a job application.
+pub fn unlink_contacts(
+    connection: &mut SqliteConnection,
+    link_args: &mut LinkArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let Some(job) = select_job(
+        connection,
+        &mut link_args.query_args,
+        current_sprint,
+        "Select the job application to unlink contacts from:",
+    )?
+    else {
+        return Ok(());
+    };
+
+    let mut contact_repo = ContactRepository { connection };
+    let linked_contacts = contact_repo.get_job_contacts(job.id)?;
+
+    let selected_contacts = match link_args.contact_id {
+        Some(contact_id) => {
+            let contact = contact_repo
+                .get_contact(contact_id)?
+                .ok_or(FettersError::ContactNotFound(contact_id))?;
+
+            if !linked_contacts.iter().any(|linked| linked.id == contact.id) {
+                println!(
+                    "{}",
+                    format!("{} is not linked to {}.", contact.name, job.company_name)
+                        .yellow()
+                        .bold()
+                );
+                return Ok(());
+            }
+
+            vec![contact]
+        }
+        None if linked_contacts.is_empty() => {
+            println!(
+                "{}",
+                format!("No contacts are linked to {}.", job.company_name)
+                    .yellow()
+                    .bold()
+            );
+            return Ok(());
+        }
+        None => match MultiSelect::new("Select the contacts to unlink:", linked_contacts)
+            .with_render_config(get_inquire_config())
+            .prompt_skippable()?
+        {
+            Some(selections) => selections,
+            None => return Ok(()),
+        },
+    };
+
+    for contact in selected_contacts.iter() {
+        contact_repo.unlink_contact(job.id, contact.id)?;
+    }
+
+    println!(
+        "{}",
+        format!(
+            "\nUnlinked {} contact(s) from {}!",
+            selected_contacts.len(),
+            job.company_name
+        )
+        .green()
+        .bold()
+    );
+
+    let job_contacts = contact_repo.get_job_contacts(job.id)?;
+    display_single_job(&job, &job_contacts);
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+//! Contains a function called by the CLI when checking the integrity of the database.
+
+use diesel::SqliteConnection;
+use inquire::Confirm;
+use owo_colors::OwoColorize;
+
+use crate::{
+    cli::DoctorArgs,
+    errors::FettersError,
+    models::sprint::QueriedSprint,
+    repositories::{integrity::IntegrityRepository, statuses::StatusRepository},
+    utils::{display::display_integrity_issues, prompt::get_inquire_config},
+};
+
+/// Check the database for integrity problems and repair them after confirmation. Job applications
+/// referencing missing sprints are moved to the current sprint and job applications referencing
+/// missing statuses are set to `PENDING`, or to the first status if `PENDING` does not exist.
+pub fn run_doctor(
+    connection: &mut SqliteConnection,
+    doctor_args: &DoctorArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let mut status_repo = StatusRepository { connection };
+    let fallback_status = match status_repo.get_status_by_name("PENDING")? {
+        Some(status) => status,
+        None => status_repo
+            .get_all_statuses()?
+            .into_iter()
+            .next()
+            .ok_or(FettersError::StatusNotFound("PENDING".to_string()))?,
+    };
+
+    let mut integrity_repo = IntegrityRepository { connection };
+    let report = integrity_repo.check()?;
+
+    if report.is_empty() {
+        println!("{}", "No integrity problems found!".green().bold());
+        return Ok(());
+    }
+
+    let issues = report.issues(&current_sprint.name, &fallback_status.name);
+    let num_issues = issues.len();
+    display_integrity_issues(issues);
+
+    if doctor_args.dry_run {
+        return Ok(());
+    }
+
+    let confirmed = if doctor_args.yes {
+        Some(true)
+    } else {
+        Confirm::new(&format!("Repair {num_issues} problem(s)?"))
+            .with_default(true)
+            .with_render_config(get_inquire_config())
+            .prompt_skippable()?
+    };
+
+    match confirmed {
+        Some(true) => {
+            integrity_repo.repair(&report, current_sprint.id, fallback_status.id)?;
+
+            println!(
+                "{}",
+                format!("\nRepaired {num_issues} problem(s)!\n")
+                    .green()
+                    .bold()
+            );
+        }
+        Some(false) => println!("{}", "Cancelled.".red().bold()),
+        None => println!("{}", "Invalid input, try again".red().bold()),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+//! Contains a function called by the CLI when exporting the database.
+
+use std::fs::File;
+use std::io::Write;
+
+use diesel::SqliteConnection;
+use owo_colors::OwoColorize;
+
+use crate::{cli::ExportArgs, errors::FettersError, repositories::archive::ArchiveRepository};
+
+/// Export every table in the database to a portable JSON archive.
+pub fn export_all(
+    connection: &mut SqliteConnection,
+    export_args: &ExportArgs,
+) -> Result<(), FettersError> {
+    let mut archive_repo = ArchiveRepository { connection };
+    let archive = archive_repo.export_archive()?;
+
+    let mut file = File::create(&export_args.all)?;
+    file.write_all(serde_json::to_string_pretty(&archive)?.as_bytes())?;
+
+    println!(
+        "{}",
+        format!(
+            "\nExported {} job application(s) and {} sprint(s) to {:?}!\n",
+            archive.jobs.len(),
+            archive.sprints.len(),
+            export_args.all
+        )
+        .green()
+        .bold()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+//! Contains a function called by the CLI when displaying the status history of a job.
+
+use std::collections::HashMap;
+
+use chrono::{Local, TimeDelta};
+use diesel::SqliteConnection;
+
+use crate::{
+    cli::QueryArgs,
+    errors::FettersError,
+    models::{sprint::QueriedSprint, status_change::TabledStatusChange},
+    repositories::{status_change::StatusChangeRepository, statuses::StatusRepository},
+    utils::{dates::parse_timestamp, display::display_status_history, selection::select_job},
+};
+
+/// Display the status history timeline for a tracked job application.
+pub fn show_history(
+    connection: &mut SqliteConnection,
+    query_args: &mut QueryArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    if let Some(job) = select_job(
+        connection,
+        query_args,
+        current_sprint,
+        "Select the job to show its history:",
+    )? {
+        let mut status_repo = StatusRepository { connection };
+        let all_statuses = status_repo.get_all_statuses()?;
+        let status_names: HashMap<i32, String> = all_statuses
+            .iter()
+            .map(|status| (status.id, status.name.clone()))
+            .collect();
+
+        let mut status_change_repo = StatusChangeRepository { connection };
+        let status_changes = status_change_repo.get_status_changes(job.id)?;
+
+        let now = Local::now().naive_local();
+        let mut timeline: Vec<TabledStatusChange> = Vec::new();
+        for (index, status_change) in status_changes.iter().enumerate() {
+            let next_changed = status_changes
+                .get(index + 1)
+                .and_then(|next| parse_timestamp(&next.changed))
+                .unwrap_or(now);
+
+            timeline.push(TabledStatusChange {
+                changed: status_change.changed.clone(),
+                old_status: status_change
+                    .old_status_id
+                    .and_then(|status_id| status_names.get(&status_id).cloned())
+                    .unwrap_or("N/A".to_string()),
+                new_status: status_names
+                    .get(&status_change.new_status_id)
+                    .cloned()
+                    .unwrap_or("N/A".to_string()),
+                duration: parse_timestamp(&status_change.changed)
+                    .map(|changed| format_duration(next_changed - changed))
+                    .unwrap_or("N/A".to_string()),
+            });
+        }
+
+        display_status_history(
+            &timeline,
+            &all_statuses,
+            &format!("STATUS HISTORY FOR {} [{}]", job.company_name, job.id),
+        );
+    }
+
+    Ok(())
+}
+
+/// Format a duration as days and hours.
+fn format_duration(duration: TimeDelta) -> String {
+    let days = duration.num_days();
+    let hours = duration.num_hours() - days * 24;
+
+    format!("{days}d {hours}h")
+}
@@ -0,0 +1,376 @@
+//! Contains functions called by the CLI when importing job applications.
+
+use csv::{ReaderBuilder, StringRecord};
+use diesel::{Connection, SqliteConnection};
+use owo_colors::OwoColorize;
+
+use crate::{
+    cli::CsvImportArgs,
+    errors::FettersError,
+    models::{
+        job::{NewJob, WorkMode},
+        sprint::{NewSprint, QueriedSprint},
+        title::NewTitle,
+    },
+    repositories::{
+        job::JobRepository, source::SourceRepository, sprint::SprintRepository,
+        statuses::StatusRepository, title::TitleRepository,
+    },
+    utils::dates::{normalize_timestamp, now_timestamp},
+};
+
+/// Contains the results of an import.
+#[derive(Debug, Default)]
+struct ImportSummary {
+    /// The number of job applications that were imported.
+    imported: usize,
+    /// The names of all job titles that were created.
+    created_titles: Vec<String>,
+    /// The names of all sprints that were created.
+    created_sprints: Vec<String>,
+    /// The line number and error message for each row that could not be imported.
+    failed_rows: Vec<(usize, String)>,
+}
+
+/// Contains the names of the job title and sprint that were created while importing a row.
+struct ImportedRow {
+    created_title: Option<String>,
+    created_sprint: Option<String>,
+}
+
+/// Contains the index of each mapped column in the CSV header.
+struct ColumnIndices {
+    company: Option<usize>,
+    title: Option<usize>,
+    status: Option<usize>,
+    link: Option<usize>,
+    notes: Option<usize>,
+    created: Option<usize>,
+    sprint: Option<usize>,
+    location: Option<usize>,
+    country: Option<usize>,
+    work_mode: Option<usize>,
+    source: Option<usize>,
+}
+
+/// Import job applications from a CSV file. Rows that fail to import are reported without
+/// aborting the rest of the import.
+pub fn import_csv(
+    connection: &mut SqliteConnection,
+    import_args: &CsvImportArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let mut reader = ReaderBuilder::new()
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_path(&import_args.path)?;
+
+    let headers = reader.headers()?.clone();
+    let find_column = |column_name: &str| {
+        headers
+            .iter()
+            .position(|header| header.eq_ignore_ascii_case(column_name))
+    };
+    let columns = ColumnIndices {
+        company: find_column(&import_args.company_column),
+        title: find_column(&import_args.title_column),
+        status: find_column(&import_args.status_column),
+        link: find_column(&import_args.link_column),
+        notes: find_column(&import_args.notes_column),
+        created: find_column(&import_args.created_column),
+        sprint: find_column(&import_args.sprint_column),
+        location: find_column(&import_args.location_column),
+        country: find_column(&import_args.country_column),
+        work_mode: find_column(&import_args.work_mode_column),
+        source: find_column(&import_args.source_column),
+    };
+
+    if columns.company.is_none() {
+        return Err(FettersError::MissingColumn(
+            import_args.company_column.clone(),
+        ));
+    }
+    if columns.title.is_none() {
+        return Err(FettersError::MissingColumn(
+            import_args.title_column.clone(),
+        ));
+    }
+
+    let mut summary = ImportSummary::default();
+
+    // Everything is imported inside a single transaction so that a dry run can roll back all
+    // changes. Each row is imported inside a nested transaction (savepoint) so that a failing row
+    // does not affect the others.
+    let result = connection.transaction::<_, FettersError, _>(|connection| {
+        for (index, record) in reader.records().enumerate() {
+            // Line 1 contains the header row.
+            let line_number = index + 2;
+
+            let imported = record.map_err(FettersError::from).and_then(|record| {
+                connection.transaction::<_, FettersError, _>(|connection| {
+                    import_row(connection, &record, &columns, import_args, current_sprint)
+                })
+            });
+
+            match imported {
+                Ok(imported_row) => {
+                    summary.imported += 1;
+                    summary.created_titles.extend(imported_row.created_title);
+                    summary.created_sprints.extend(imported_row.created_sprint);
+                }
+                Err(error) => summary.failed_rows.push((line_number, error.to_string())),
+            }
+        }
+
+        if import_args.dry_run {
+            return Err(diesel::result::Error::RollbackTransaction.into());
+        }
+
+        Ok(())
+    });
+
+    match result {
+        Ok(()) => {}
+        Err(FettersError::DieselResultError(diesel::result::Error::RollbackTransaction))
+            if import_args.dry_run => {}
+        Err(error) => return Err(error),
+    }
+
+    print_summary(&summary, import_args.dry_run);
+
+    Ok(())
+}
+
+/// Import a single CSV row as a new job application.
+fn import_row(
+    connection: &mut SqliteConnection,
+    record: &StringRecord,
+    columns: &ColumnIndices,
+    import_args: &CsvImportArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<ImportedRow, FettersError> {
+    let get_field = |column: Option<usize>| {
+        column
+            .and_then(|index| record.get(index))
+            .filter(|value| !value.is_empty())
+    };
+
+    let company_name = get_field(columns.company).ok_or(FettersError::MissingImportField(
+        import_args.company_column.clone(),
+    ))?;
+    let title_name = get_field(columns.title).ok_or(FettersError::MissingImportField(
+        import_args.title_column.clone(),
+    ))?;
+    let status_name = get_field(columns.status).unwrap_or(&import_args.default_status);
+    let created = match get_field(columns.created) {
+        Some(created) => normalize_timestamp(created)?,
+        None => now_timestamp(),
+    };
+
+    let work_mode = get_field(columns.work_mode)
+        .map(WorkMode::parse)
+        .transpose()?
+        .map(|work_mode| work_mode.to_string());
+
+    let mut status_repo = StatusRepository { connection };
+    let status = status_repo
+        .get_status_by_name(status_name)?
+        .ok_or(FettersError::StatusNotFound(status_name.to_string()))?;
+
+    let mut imported_row = ImportedRow {
+        created_title: None,
+        created_sprint: None,
+    };
+
+    let mut title_repo = TitleRepository { connection };
+    let title_id = match title_repo.get_title_by_name(title_name)? {
+        Some(queried_title) => queried_title.id,
+        None => {
+            imported_row.created_title = Some(title_name.to_string());
+            title_repo.add_title(NewTitle { name: title_name })?.id
+        }
+    };
+
+    let sprint_id = match get_field(columns.sprint) {
+        Some(sprint_name) => {
+            let mut sprint_repo = SprintRepository { connection };
+            match sprint_repo.get_sprint_by_name(sprint_name)? {
+                Some(queried_sprint) => queried_sprint.id,
+                None => {
+                    imported_row.created_sprint = Some(sprint_name.to_string());
+                    sprint_repo
+                        .add_job_sprint(NewSprint {
+                            name: sprint_name,
+                            start_date: created.split(' ').next().unwrap_or(&created),
+                            end_date: None,
+                            num_jobs: &0,
+                            goal: None,
+                            goal_period: None,
+                        })?
+                        .id
+                }
+            }
+        }
+        None => current_sprint.id,
+    };
+
+    let source_id = match get_field(columns.source) {
+        Some(source_name) => {
+            let mut source_repo = SourceRepository { connection };
+            Some(source_repo.add_source(source_name)?.id)
+        }
+        None => None,
+    };
+
+    let mut job_repo = JobRepository { connection };
+    job_repo.add_job(
+        NewJob {
+            company_name,
+            created,
+            title_id,
+            status_id: status.id,
+            link: get_field(columns.link),
+            notes: get_field(columns.notes),
+            sprint_id,
+            location: get_field(columns.location),
+            country: get_field(columns.country),
+            work_mode: work_mode.as_deref(),
+            source_id,
+            deleted_at: None,
+        },
+        &[],
+    )?;
+
+    Ok(imported_row)
+}
+
+/// Print the results of an import.
+fn print_summary(summary: &ImportSummary, dry_run: bool) {
+    for (line_number, error) in summary.failed_rows.iter() {
+        println!("{}", format!("Row {line_number}: {error}").red().bold());
+    }
+
+    let verb = if dry_run { "Would import" } else { "Imported" };
+    println!(
+        "{}",
+        format!(
+            "\n{verb} {} job application(s), creating {} title(s) and {} sprint(s). {} row(s) failed.\n",
+            summary.imported,
+            summary.created_titles.len(),
+            summary.created_sprints.len(),
+            summary.failed_rows.len()
+        )
+        .green()
+        .bold()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use clap::Parser;
+    use diesel::dsl::count;
+    use diesel::prelude::*;
+
+    use super::*;
+    use crate::schema::{jobs, sprints, titles};
+    use crate::utils::testing::TestDatabase;
+
+    /// Write a CSV file with the given contents to the temporary directory.
+    fn write_csv(file_name: &str, contents: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("fetters-{}-{file_name}", std::process::id()));
+        fs::write(&path, contents).expect("the temporary directory is writable");
+
+        path
+    }
+
+    /// Import the CSV file at the given path with the default column mapping and extra flags.
+    fn import(
+        database: &mut TestDatabase,
+        path: &Path,
+        flags: &[&str],
+    ) -> Result<(), FettersError> {
+        let mut args = vec!["csv", path.to_str().expect("the path is valid UTF-8")];
+        args.extend(flags);
+        let import_args = CsvImportArgs::try_parse_from(args).expect("the arguments are valid");
+
+        import_csv(&mut database.connection, &import_args, &database.sprint)
+    }
+
+    #[test]
+    fn failing_rows_are_rolled_back_without_affecting_other_rows() -> Result<(), FettersError> {
+        let mut database = TestDatabase::new()?;
+        // Fail the job insert after the row has already created its title.
+        diesel::sql_query(
+            "CREATE TRIGGER reject_broken BEFORE INSERT ON jobs WHEN NEW.company_name = 'Broken' \
+             BEGIN SELECT RAISE(ABORT, 'rejected'); END;",
+        )
+        .execute(&mut database.connection)?;
+
+        let path = write_csv(
+            "savepoints.csv",
+            "company,title,status,sprint\n\
+             Acme,Engineer,PENDING,2025-fall\n\
+             Broken,Designer,PENDING,\n\
+             Initech,Engineer,UNKNOWN,\n\
+             Globex,Engineer,REJECTED,\n",
+        );
+        let result = import(&mut database, &path, &[]);
+        fs::remove_file(&path).ok();
+        result?;
+
+        let companies: Vec<String> = jobs::table
+            .order(jobs::id)
+            .select(jobs::company_name)
+            .load(&mut database.connection)?;
+        assert_eq!(companies, vec!["Acme", "Globex"]);
+
+        let designer_titles: i64 = titles::table
+            .filter(titles::name.eq("Designer"))
+            .select(count(titles::id))
+            .first(&mut database.connection)?;
+        assert_eq!(designer_titles, 0);
+
+        let sprint_jobs: i32 = sprints::table
+            .filter(sprints::name.eq("2025-fall"))
+            .select(sprints::num_jobs)
+            .first(&mut database.connection)?;
+        assert_eq!(sprint_jobs, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn dry_runs_roll_back_every_row() -> Result<(), FettersError> {
+        let mut database = TestDatabase::new()?;
+
+        let path = write_csv(
+            "dry-run.csv",
+            "company,title,sprint\nAcme,Engineer,2025-fall\nGlobex,Designer,\n",
+        );
+        let result = import(&mut database, &path, &["--dry-run"]);
+        fs::remove_file(&path).ok();
+        result?;
+
+        let total_jobs: i64 = jobs::table
+            .select(count(jobs::id))
+            .first(&mut database.connection)?;
+        assert_eq!(total_jobs, 0);
+
+        let total_titles: i64 = titles::table
+            .select(count(titles::id))
+            .first(&mut database.connection)?;
+        assert_eq!(total_titles, 0);
+
+        let imported_sprints: i64 = sprints::table
+            .filter(sprints::name.eq("2025-fall"))
+            .select(count(sprints::id))
+            .first(&mut database.connection)?;
+        assert_eq!(imported_sprints, 0);
+
+        Ok(())
+    }
+}
@@ -1,27 +1,243 @@
 //! Contains a function called by the CLI when displaying application insights.
 
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Duration, Local, Months, NaiveDate};
 use diesel::SqliteConnection;
+use owo_colors::OwoColorize;
 
 use crate::{
-    errors::FettersError, models::sprint::QueriedSprint, repositories::job::JobRepository,
-    utils::display::display_insights,
+    cli::{InsightsArgs, OutputFormat, TimelinePeriod},
+    errors::FettersError,
+    models::{
+        insight::{DurationInsights, TimelineCount},
+        sprint::QueriedSprint,
+    },
+    repositories::job::JobRepository,
+    utils::{
+        dates::{DATE_FORMAT, parse_timestamp},
+        display::{
+            display_activity_heatmap, display_funnel, display_insights, display_source_insights,
+            display_sprint_progress, display_stage_durations, display_timeline,
+        },
+        format::print_records,
+    },
 };
 
 /// Display all job insights.
 pub fn show_insights(
     connection: &mut SqliteConnection,
+    insights_args: &InsightsArgs,
     current_sprint: &QueriedSprint,
+    output_format: OutputFormat,
 ) -> Result<(), FettersError> {
     let mut job_repo = JobRepository { connection };
+
+    if insights_args.sources {
+        let jobs_per_source = job_repo.count_jobs_per_source(current_sprint)?;
+
+        if jobs_per_source.is_empty() {
+            return Err(FettersError::NoJobsAvailable(current_sprint.name.clone()));
+        }
+
+        if output_format != OutputFormat::Table {
+            return print_records(&jobs_per_source, output_format);
+        }
+
+        display_source_insights(jobs_per_source, "JOBS PER SOURCE");
+
+        return Ok(());
+    }
+
+    if insights_args.timeline {
+        return show_timeline(&mut job_repo, insights_args.by, output_format);
+    }
+
+    if insights_args.durations {
+        let (duration_stats, duration_outliers) = job_repo.get_stage_durations()?;
+
+        // JSON output keeps both record sets in one document, while the other formats print the
+        // slow applications as a second record set.
+        match output_format {
+            OutputFormat::Table => {}
+            OutputFormat::Json => {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&DurationInsights {
+                        durations: &duration_stats,
+                        outliers: &duration_outliers,
+                    })?
+                );
+                return Ok(());
+            }
+            _ => {
+                print_records(&duration_stats, output_format)?;
+                println!();
+                return print_records(&duration_outliers, output_format);
+            }
+        }
+
+        if duration_stats.is_empty() {
+            println!(
+                "{}",
+                "No job applications have received a response or changed status yet."
+                    .yellow()
+                    .bold()
+            );
+            return Ok(());
+        }
+
+        display_stage_durations(duration_stats, duration_outliers);
+
+        return Ok(());
+    }
+
+    if insights_args.funnel {
+        let sprint_funnel = job_repo.get_funnel(Some(current_sprint), "current sprint")?;
+        let overall_funnel = job_repo.get_funnel(None, "overall")?;
+
+        if output_format != OutputFormat::Table {
+            let all_stages: Vec<_> = sprint_funnel.into_iter().chain(overall_funnel).collect();
+            return print_records(&all_stages, output_format);
+        }
+
+        display_funnel(&sprint_funnel, &format!("FUNNEL [{}]", current_sprint.name));
+        display_funnel(&overall_funnel, "FUNNEL [OVERALL]");
+
+        return Ok(());
+    }
+
     let jobs_per_status = job_repo.count_jobs_per_status(current_sprint)?;
     let jobs_per_sprint = job_repo.count_jobs_per_sprint(current_sprint)?;
+    let jobs_per_work_mode = job_repo.count_jobs_per_work_mode(current_sprint)?;
+    let jobs_per_location = job_repo.count_jobs_per_location(current_sprint)?;
 
-    if !jobs_per_status.is_empty() && !jobs_per_sprint.is_empty() {
-        display_insights(jobs_per_status, "JOBS PER STATUS", false);
-        display_insights(jobs_per_sprint, "JOBS PER SPRINT", true);
-    } else {
+    if jobs_per_status.is_empty() || jobs_per_sprint.is_empty() {
         return Err(FettersError::NoJobsAvailable(current_sprint.name.clone()));
     }
 
+    if output_format != OutputFormat::Table {
+        let all_insights: Vec<_> = jobs_per_status
+            .into_iter()
+            .chain(jobs_per_sprint)
+            .chain(jobs_per_work_mode)
+            .chain(jobs_per_location)
+            .collect();
+        return print_records(&all_insights, output_format);
+    }
+
+    display_insights(jobs_per_status, "JOBS PER STATUS", false);
+    display_insights(jobs_per_sprint, "JOBS PER SPRINT", true);
+    if let Some(progress) = current_sprint.progress(Local::now().date_naive()) {
+        display_sprint_progress(&progress);
+    }
+    display_insights(jobs_per_work_mode, "JOBS PER WORK MODE", false);
+    display_insights(jobs_per_location, "JOBS PER LOCATION", false);
+    display_source_insights(
+        job_repo.count_jobs_per_source(current_sprint)?,
+        "JOBS PER SOURCE",
+    );
+    display_funnel(
+        &job_repo.get_funnel(Some(current_sprint), "current sprint")?,
+        &format!("FUNNEL [{}]", current_sprint.name),
+    );
+    display_funnel(&job_repo.get_funnel(None, "overall")?, "FUNNEL [OVERALL]");
+
+    let (duration_stats, duration_outliers) = job_repo.get_stage_durations()?;
+    if !duration_stats.is_empty() {
+        display_stage_durations(duration_stats, duration_outliers);
+    }
+
+    Ok(())
+}
+
+/// Display the number of job applications created per period across all sprints, followed by a
+/// calendar heatmap of the daily application activity over the last year.
+fn show_timeline(
+    job_repo: &mut JobRepository,
+    timeline_period: TimelinePeriod,
+    output_format: OutputFormat,
+) -> Result<(), FettersError> {
+    let created_dates: Vec<NaiveDate> = job_repo
+        .get_created_timestamps()?
+        .iter()
+        .filter_map(|created| parse_timestamp(created))
+        .map(|created| created.date())
+        .collect();
+
+    let timeline = build_timeline(&created_dates, timeline_period);
+
+    if output_format != OutputFormat::Table {
+        return print_records(&timeline, output_format);
+    }
+
+    if timeline.is_empty() {
+        println!("{}", "No job applications are tracked.".yellow().bold());
+        return Ok(());
+    }
+
+    let mut jobs_per_day: BTreeMap<NaiveDate, i64> = BTreeMap::new();
+    for created_date in created_dates {
+        *jobs_per_day.entry(created_date).or_default() += 1;
+    }
+
+    display_timeline(
+        &timeline,
+        match timeline_period {
+            TimelinePeriod::Day => "JOBS PER DAY",
+            TimelinePeriod::Week => "JOBS PER WEEK",
+            TimelinePeriod::Month => "JOBS PER MONTH",
+        },
+    );
+    display_activity_heatmap(&jobs_per_day, Local::now().date_naive());
+
     Ok(())
 }
+
+/// Count the job applications created within each period, from the period of the earliest job
+/// application to the period of the latest one. Periods without job applications are included
+/// with a count of zero.
+fn build_timeline(
+    created_dates: &[NaiveDate],
+    timeline_period: TimelinePeriod,
+) -> Vec<TimelineCount> {
+    let period_start = |date: NaiveDate| match timeline_period {
+        TimelinePeriod::Day => date,
+        TimelinePeriod::Week => date - Duration::days(date.weekday().num_days_from_monday() as i64),
+        TimelinePeriod::Month => date.with_day(1).unwrap_or(date),
+    };
+    let next_period = |date: NaiveDate| match timeline_period {
+        TimelinePeriod::Day => date + Duration::days(1),
+        TimelinePeriod::Week => date + Duration::weeks(1),
+        TimelinePeriod::Month => date
+            .checked_add_months(Months::new(1))
+            .unwrap_or(NaiveDate::MAX),
+    };
+
+    let mut counts: BTreeMap<NaiveDate, i64> = BTreeMap::new();
+    for created_date in created_dates {
+        *counts.entry(period_start(*created_date)).or_default() += 1;
+    }
+
+    let (Some(first_period), Some(last_period)) = (
+        counts.keys().next().copied(),
+        counts.keys().next_back().copied(),
+    ) else {
+        return vec![];
+    };
+
+    let mut timeline = vec![];
+    let mut period = first_period;
+    while period <= last_period {
+        timeline.push(TimelineCount {
+            period: match timeline_period {
+                TimelinePeriod::Month => period.format("%Y-%m").to_string(),
+                _ => period.format(DATE_FORMAT).to_string(),
+            },
+            count: counts.get(&period).copied().unwrap_or_default(),
+        });
+        period = next_period(period);
+    }
+
+    timeline
+}
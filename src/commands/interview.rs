@@ -0,0 +1,350 @@
+//! Contains functions called by the CLI when managing interviews.
+
+use diesel::SqliteConnection;
+use inquire::{Confirm, MultiSelect, Select};
+use owo_colors::OwoColorize;
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+use crate::{
+    cli::{OutputFormat, QueryArgs},
+    errors::FettersError,
+    models::{
+        interview::{
+            InterviewOutcome, InterviewType, InterviewUpdate, NewInterview, QueriedInterview,
+        },
+        sprint::QueriedSprint,
+    },
+    repositories::interview::InterviewRepository,
+    utils::{
+        dates::normalize_timestamp,
+        display::display_interviews,
+        format::print_records,
+        prompt::{get_inquire_config, input_optional_text},
+        selection::{query_jobs, select_job},
+    },
+};
+
+/// Track a new interview round for a job application.
+pub fn add_interview(
+    connection: &mut SqliteConnection,
+    query_args: &mut QueryArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let Some(job) = select_job(
+        connection,
+        query_args,
+        current_sprint,
+        "Select the job to add an interview for:",
+    )?
+    else {
+        return Ok(());
+    };
+
+    let round = input_required_text("Enter the name of this interview round:", "")?;
+    let interview_type = select_interview_type()?;
+    let scheduled = input_scheduled("")?;
+    let interviewers = input_optional_text("[OPTIONAL] Enter the names of the interviewers:", "")?;
+    let notes = input_optional_text("[OPTIONAL] Enter any notes for this interview:", "")?;
+
+    let mut interview_repo = InterviewRepository { connection };
+    interview_repo.add_interview(NewInterview {
+        job_id: job.id,
+        round: &round,
+        interview_type: &interview_type,
+        scheduled: scheduled.as_deref(),
+        interviewers: interviewers.as_deref(),
+        outcome: &InterviewOutcome::Pending.to_string(),
+        notes: notes.as_deref(),
+    })?;
+
+    println!(
+        "{}",
+        format!("\nAdded interview for {}!\n", job.company_name)
+            .green()
+            .bold()
+    );
+
+    Ok(())
+}
+
+/// List all interviews for the job applications matching the query.
+pub fn list_interviews(
+    connection: &mut SqliteConnection,
+    query_args: &mut QueryArgs,
+    current_sprint: &QueriedSprint,
+    output_format: OutputFormat,
+) -> Result<(), FettersError> {
+    let matched_jobs = query_jobs(connection, query_args, current_sprint)?;
+    let job_ids: Vec<i32> = matched_jobs.iter().map(|job| job.id).collect();
+
+    let mut interview_repo = InterviewRepository { connection };
+    let interviews = interview_repo.list_interviews(&job_ids)?;
+
+    if output_format != OutputFormat::Table {
+        return print_records(&interviews, output_format);
+    }
+
+    if interviews.is_empty() {
+        println!(
+            "{}",
+            "No interviews are tracked for the matching job applications."
+                .yellow()
+                .bold()
+        );
+        return Ok(());
+    }
+
+    display_interviews(
+        &interviews,
+        &format!("INTERVIEWS [{} LISTED]", interviews.len()),
+    );
+
+    Ok(())
+}
+
+/// Update an interview round for a job application.
+pub fn update_interview(
+    connection: &mut SqliteConnection,
+    query_args: &mut QueryArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let Some(interview) = select_interview(
+        connection,
+        query_args,
+        current_sprint,
+        "Select the interview you want to modify:",
+    )?
+    else {
+        return Ok(());
+    };
+
+    if let Some(selections) = MultiSelect::new(
+        "Select the fields you want to update:",
+        UpdatableInterviewField::iter().collect(),
+    )
+    .with_render_config(get_inquire_config())
+    .prompt_skippable()?
+    {
+        if selections.is_empty() {
+            println!("{}", "No changes selected.".yellow().bold());
+            return Ok(());
+        }
+
+        let mut new_round: Option<String> = None;
+        let mut new_interview_type: Option<String> = None;
+        let mut new_scheduled: Option<Option<String>> = None;
+        let mut new_interviewers: Option<Option<String>> = None;
+        let mut new_outcome: Option<String> = None;
+        let mut new_notes: Option<Option<String>> = None;
+
+        for selection in selections {
+            match selection {
+                UpdatableInterviewField::Round => {
+                    new_round = Some(input_required_text(
+                        "Enter a new name for this interview round:",
+                        &interview.round,
+                    )?);
+                }
+                UpdatableInterviewField::InterviewType => {
+                    new_interview_type = Some(select_interview_type()?);
+                }
+                UpdatableInterviewField::Scheduled => {
+                    new_scheduled = Some(input_scheduled(
+                        &interview.scheduled.clone().unwrap_or("".to_string()),
+                    )?);
+                }
+                UpdatableInterviewField::Interviewers => {
+                    new_interviewers = Some(input_optional_text(
+                        "Enter the names of the interviewers:",
+                        &interview.interviewers.clone().unwrap_or("".to_string()),
+                    )?);
+                }
+                UpdatableInterviewField::Outcome => {
+                    new_outcome = Some(select_outcome()?);
+                }
+                UpdatableInterviewField::Notes => {
+                    new_notes = Some(input_optional_text(
+                        "Enter new notes for this interview:",
+                        &interview.notes.clone().unwrap_or("".to_string()),
+                    )?);
+                }
+            }
+        }
+
+        match Confirm::new("Confirm updates?")
+            .with_default(true)
+            .with_render_config(get_inquire_config())
+            .prompt_skippable()?
+        {
+            Some(true) => {
+                let interview_update = InterviewUpdate {
+                    round: new_round.as_deref(),
+                    interview_type: new_interview_type.as_deref(),
+                    scheduled: new_scheduled.as_ref().map(|value| value.as_deref()),
+                    interviewers: new_interviewers.as_ref().map(|value| value.as_deref()),
+                    outcome: new_outcome.as_deref(),
+                    notes: new_notes.as_ref().map(|value| value.as_deref()),
+                };
+
+                let mut interview_repo = InterviewRepository { connection };
+                interview_repo.update_interview(interview.id, interview_update)?;
+
+                println!("{}", "\nUpdated interview!\n".green().bold());
+            }
+            Some(false) => println!("{}", "Cancelled.".red().bold()),
+            None => println!("{}", "Invalid input, try again".red().bold()),
+        }
+    }
+
+    Ok(())
+}
+
+/// Delete an interview round from a job application.
+pub fn delete_interview(
+    connection: &mut SqliteConnection,
+    query_args: &mut QueryArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let Some(interview) = select_interview(
+        connection,
+        query_args,
+        current_sprint,
+        "Select the interview you want to delete:",
+    )?
+    else {
+        return Ok(());
+    };
+
+    match Confirm::new("Confirm deletion?")
+        .with_default(true)
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?
+    {
+        Some(true) => {
+            let mut interview_repo = InterviewRepository { connection };
+            interview_repo.delete_interview(interview.id)?;
+
+            println!("{}", "\nDeleted interview!\n".green().bold());
+        }
+        Some(false) => println!("{}", "Cancelled.".red().bold()),
+        None => println!("{}", "Invalid input, try again".red().bold()),
+    }
+
+    Ok(())
+}
+
+/// This enum contains all updatable interview fields users can choose from.
+#[derive(Debug, Display, EnumIter)]
+enum UpdatableInterviewField {
+    /// Update the name of the interview round.
+    #[strum(to_string = "Round")]
+    Round,
+    /// Update the type of interview.
+    #[strum(to_string = "Type")]
+    InterviewType,
+    /// Update the scheduled timestamp.
+    #[strum(to_string = "Scheduled")]
+    Scheduled,
+    /// Update the names of the interviewers.
+    #[strum(to_string = "Interviewers")]
+    Interviewers,
+    /// Update the outcome of the interview.
+    #[strum(to_string = "Outcome")]
+    Outcome,
+    /// Update notes for this interview.
+    #[strum(to_string = "Notes")]
+    Notes,
+}
+
+/// Select a job application, then select one of its interviews.
+fn select_interview(
+    connection: &mut SqliteConnection,
+    query_args: &mut QueryArgs,
+    current_sprint: &QueriedSprint,
+    message: &str,
+) -> Result<Option<QueriedInterview>, FettersError> {
+    let Some(job) = select_job(
+        connection,
+        query_args,
+        current_sprint,
+        "Select the job application:",
+    )?
+    else {
+        return Ok(None);
+    };
+
+    let mut interview_repo = InterviewRepository { connection };
+    let interviews = interview_repo.get_interviews(job.id)?;
+
+    if interviews.is_empty() {
+        println!(
+            "{}",
+            format!("No interviews are tracked for {}.", job.company_name)
+                .yellow()
+                .bold()
+        );
+        return Ok(None);
+    }
+
+    Ok(Select::new(message, interviews)
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?)
+}
+
+/// Select the type of interview.
+fn select_interview_type() -> Result<String, FettersError> {
+    match Select::new(
+        "Select the type of interview:",
+        InterviewType::iter().collect(),
+    )
+    .with_render_config(get_inquire_config())
+    .prompt_skippable()?
+    {
+        Some(interview_type) => Ok(interview_type.to_string()),
+        None => Err(FettersError::UnknownError(
+            "No selection was provided.".to_string(),
+        )),
+    }
+}
+
+/// Select the outcome of an interview.
+fn select_outcome() -> Result<String, FettersError> {
+    match Select::new(
+        "Select the outcome of this interview:",
+        InterviewOutcome::iter().collect(),
+    )
+    .with_render_config(get_inquire_config())
+    .prompt_skippable()?
+    {
+        Some(outcome) => Ok(outcome.to_string()),
+        None => Err(FettersError::UnknownError(
+            "No selection was provided.".to_string(),
+        )),
+    }
+}
+
+/// Input an optional scheduled timestamp, re-prompting until a valid date is entered.
+fn input_scheduled(previous_value: &str) -> Result<Option<String>, FettersError> {
+    loop {
+        match input_optional_text(
+            "[OPTIONAL] Enter when this interview is scheduled (YYYY-MM-DD or YYYY-MM-DD HH:MM:SS):",
+            previous_value,
+        )? {
+            Some(scheduled) => match normalize_timestamp(&scheduled) {
+                Ok(timestamp) => return Ok(Some(timestamp)),
+                Err(error) => println!("{}", error.red().bold()),
+            },
+            None => return Ok(None),
+        }
+    }
+}
+
+/// Input a required text field, re-prompting until a value is entered.
+fn input_required_text(message: &str, previous_value: &str) -> Result<String, FettersError> {
+    loop {
+        match input_optional_text(message, previous_value)? {
+            Some(input) => return Ok(input),
+            None => println!("{}", "A value is required for this field!".red().bold()),
+        }
+    }
+}
@@ -3,20 +3,32 @@
 use diesel::SqliteConnection;
 
 use crate::{
-    cli::QueryArgs, errors::FettersError, models::sprint::QueriedSprint,
-    repositories::job::JobRepository, utils::display::display_jobs,
+    cli::{OutputFormat, QueryArgs},
+    errors::FettersError,
+    models::sprint::QueriedSprint,
+    repositories::{contact::ContactRepository, job::JobRepository},
+    utils::{
+        display::{display_jobs, display_single_job},
+        format::print_records,
+    },
 };
 
-/// List all job applications stored in the `jobs` SQLite table.
+/// List all job applications stored in the `jobs` SQLite table. A job application targeted by its
+/// ID is displayed along with its linked contacts.
 pub fn list_jobs(
     connection: &mut SqliteConnection,
     query_args: &QueryArgs,
     current_sprint: &QueriedSprint,
+    output_format: OutputFormat,
 ) -> Result<(), FettersError> {
     let mut job_repo = JobRepository { connection };
     let all_jobs = job_repo.list_jobs(query_args, current_sprint)?;
 
     if all_jobs.is_empty() {
+        if let Some(job_id) = query_args.id {
+            return Err(FettersError::JobNotFound(job_id));
+        }
+
         return Err(FettersError::NoJobsAvailable(
             query_args
                 .sprint
@@ -27,6 +39,20 @@ pub fn list_jobs(
         ));
     }
 
+    if output_format != OutputFormat::Table {
+        return print_records(&all_jobs, output_format);
+    }
+
+    if query_args.id.is_some() {
+        let mut contact_repo = ContactRepository { connection };
+        for job in all_jobs {
+            let contacts = contact_repo.get_job_contacts(job.id)?;
+            display_single_job(&job, &contacts);
+        }
+
+        return Ok(());
+    }
+
     display_jobs(
         &all_jobs,
         query_args.sprint.as_ref().unwrap_or(&current_sprint.name),
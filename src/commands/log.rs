@@ -0,0 +1,37 @@
+//! Contains a function called by the CLI when showing the audit log.
+
+use diesel::SqliteConnection;
+use owo_colors::OwoColorize;
+
+use crate::{
+    cli::{LogArgs, OutputFormat},
+    errors::FettersError,
+    repositories::audit::AuditRepository,
+    utils::{display::display_audit_log, format::print_records},
+};
+
+/// Show the most recent operations recorded in the audit log, newest first.
+pub fn show_log(
+    connection: &mut SqliteConnection,
+    log_args: &LogArgs,
+    output_format: OutputFormat,
+) -> Result<(), FettersError> {
+    let mut audit_repo = AuditRepository { connection };
+    let operations = audit_repo.list_operations(log_args.limit)?;
+
+    if output_format != OutputFormat::Table {
+        return print_records(&operations, output_format);
+    }
+
+    if operations.is_empty() {
+        println!("{}", "No changes have been recorded yet.".green().bold());
+        return Ok(());
+    }
+
+    display_audit_log(
+        &operations,
+        &format!("AUDIT LOG [{} OPERATION(S) LISTED]", operations.len()),
+    );
+
+    Ok(())
+}
@@ -1,10 +1,26 @@
 //! Contains functions for use with the CLI.
 
 pub mod add;
+pub mod bulk;
 pub mod config;
+pub mod contact;
 pub mod delete;
+pub mod doctor;
+pub mod export;
+pub mod history;
+pub mod import;
 pub mod insights;
+pub mod interview;
 pub mod list;
+pub mod log;
+pub mod offers;
 pub mod open;
+pub mod reminder;
+pub mod restore;
+pub mod search;
 pub mod sprint;
+pub mod status;
+pub mod sweep;
+pub mod trash;
+pub mod undo;
 pub mod update;
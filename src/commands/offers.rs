@@ -0,0 +1,327 @@
+//! Contains functions called by the CLI when tracking compensation and comparing offers.
+
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
+use diesel::SqliteConnection;
+use inquire::{Confirm, MultiSelect};
+use owo_colors::OwoColorize;
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+use crate::{
+    cli::{CompensationArgs, OutputFormat},
+    errors::FettersError,
+    models::{
+        compensation::{Compensation, OfferComparison, format_amount},
+        sprint::QueriedSprint,
+    },
+    repositories::compensation::CompensationRepository,
+    utils::{
+        dates::normalize_date,
+        display::display_offers,
+        format::print_records,
+        prompt::{get_inquire_config, input_optional_text},
+        selection::select_job,
+    },
+};
+
+/// Set the compensation details of a job application. Values provided with flags are applied
+/// without prompting. Otherwise, the fields to update are selected in a `MultiSelect` menu.
+pub fn set_compensation(
+    connection: &mut SqliteConnection,
+    compensation_args: &mut CompensationArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let Some(job) = select_job(
+        connection,
+        &mut compensation_args.query_args,
+        current_sprint,
+        "Select the job to set compensation details for:",
+    )?
+    else {
+        return Ok(());
+    };
+
+    let mut compensation_repo = CompensationRepository { connection };
+    let mut job_compensation =
+        compensation_repo
+            .get_compensation(job.id)?
+            .unwrap_or(Compensation {
+                job_id: job.id,
+                ..Default::default()
+            });
+
+    if compensation_args.has_updates() {
+        let parse_flag = |value: &Option<String>| value.as_deref().map(parse_amount).transpose();
+
+        if let Some(posted_min) = parse_flag(&compensation_args.posted_min)? {
+            job_compensation.posted_min = Some(posted_min);
+        }
+        if let Some(posted_max) = parse_flag(&compensation_args.posted_max)? {
+            job_compensation.posted_max = Some(posted_max);
+        }
+        if let Some(expected) = parse_flag(&compensation_args.expected)? {
+            job_compensation.expected = Some(expected);
+        }
+        if let Some(base) = parse_flag(&compensation_args.base)? {
+            job_compensation.offered_base = Some(base);
+        }
+        if let Some(bonus) = parse_flag(&compensation_args.bonus)? {
+            job_compensation.offered_bonus = Some(bonus);
+        }
+        if let Some(equity) = parse_flag(&compensation_args.equity)? {
+            job_compensation.offered_equity = Some(equity);
+        }
+        if let Some(currency) = &compensation_args.currency {
+            job_compensation.currency = Some(currency.trim().to_uppercase());
+        }
+        if let Some(deadline) = &compensation_args.deadline {
+            job_compensation.offer_deadline = Some(normalize_date(deadline)?);
+        }
+        if let Some(benefits) = &compensation_args.benefits {
+            job_compensation.benefits = Some(benefits.to_string());
+        }
+    } else {
+        let Some(selections) = MultiSelect::new(
+            "Select the fields you want to update:",
+            CompensationField::iter().collect(),
+        )
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?
+        else {
+            return Ok(());
+        };
+
+        for selection in selections {
+            match selection {
+                CompensationField::PostedMin => {
+                    job_compensation.posted_min =
+                        input_amount(&selection, job_compensation.posted_min)?;
+                }
+                CompensationField::PostedMax => {
+                    job_compensation.posted_max =
+                        input_amount(&selection, job_compensation.posted_max)?;
+                }
+                CompensationField::Expected => {
+                    job_compensation.expected =
+                        input_amount(&selection, job_compensation.expected)?;
+                }
+                CompensationField::OfferedBase => {
+                    job_compensation.offered_base =
+                        input_amount(&selection, job_compensation.offered_base)?;
+                }
+                CompensationField::OfferedBonus => {
+                    job_compensation.offered_bonus =
+                        input_amount(&selection, job_compensation.offered_bonus)?;
+                }
+                CompensationField::OfferedEquity => {
+                    job_compensation.offered_equity =
+                        input_amount(&selection, job_compensation.offered_equity)?;
+                }
+                CompensationField::Currency => {
+                    job_compensation.currency = input_optional_text(
+                        "Enter the currency of all amounts (ie. USD):",
+                        job_compensation.currency.as_deref().unwrap_or(""),
+                    )?
+                    .map(|currency| currency.to_uppercase());
+                }
+                CompensationField::OfferDeadline => {
+                    job_compensation.offer_deadline =
+                        input_deadline(job_compensation.offer_deadline.as_deref())?;
+                }
+                CompensationField::Benefits => {
+                    job_compensation.benefits = input_optional_text(
+                        "Enter notes about the offered benefits:",
+                        job_compensation.benefits.as_deref().unwrap_or(""),
+                    )?;
+                }
+            }
+        }
+
+        match Confirm::new("Confirm updates?")
+            .with_default(true)
+            .with_render_config(get_inquire_config())
+            .prompt_skippable()?
+        {
+            Some(true) => {}
+            Some(false) => {
+                println!("{}", "Cancelled.".red().bold());
+                return Ok(());
+            }
+            None => {
+                println!("{}", "Invalid input, try again".red().bold());
+                return Ok(());
+            }
+        }
+    }
+
+    let mut compensation_repo = CompensationRepository { connection };
+    compensation_repo.save_compensation(&job_compensation)?;
+
+    println!(
+        "{}",
+        format!("\nSaved compensation details for {}!\n", job.company_name)
+            .green()
+            .bold()
+    );
+
+    Ok(())
+}
+
+/// Compare all job applications with an offered base salary side by side, ordered by their total
+/// yearly compensation.
+pub fn compare_offers(
+    connection: &mut SqliteConnection,
+    output_format: OutputFormat,
+) -> Result<(), FettersError> {
+    let mut compensation_repo = CompensationRepository { connection };
+    let mut offers: Vec<OfferComparison> = compensation_repo
+        .list_offers()?
+        .into_iter()
+        .map(|(job_compensation, company_name, title, status)| {
+            let posted_range = match (job_compensation.posted_min, job_compensation.posted_max) {
+                (Some(min), Some(max)) => {
+                    Some(format!("{} - {}", format_amount(min), format_amount(max)))
+                }
+                (Some(min), None) => Some(format!("{}+", format_amount(min))),
+                (None, Some(max)) => Some(format!("Up to {}", format_amount(max))),
+                (None, None) => None,
+            };
+
+            OfferComparison {
+                company_name,
+                job_id: job_compensation.job_id,
+                title,
+                status,
+                total: job_compensation.total_offered(),
+                currency: job_compensation.currency,
+                offered_base: job_compensation.offered_base,
+                offered_bonus: job_compensation.offered_bonus,
+                offered_equity: job_compensation.offered_equity,
+                posted_range,
+                expected: job_compensation.expected,
+                offer_deadline: job_compensation.offer_deadline,
+                benefits: job_compensation.benefits,
+            }
+        })
+        .collect();
+
+    offers.sort_by_key(|offer| Reverse(offer.total));
+
+    if output_format != OutputFormat::Table {
+        return print_records(&offers, output_format);
+    }
+
+    if offers.is_empty() {
+        println!(
+            "{}",
+            "No offers are tracked. Set an offered base salary with `fetters offers set --base`."
+                .yellow()
+                .bold()
+        );
+        return Ok(());
+    }
+
+    display_offers(&offers, &format!("OFFERS [{} COMPARED]", offers.len()));
+
+    let currencies: HashSet<Option<&String>> =
+        offers.iter().map(|offer| offer.currency.as_ref()).collect();
+    if currencies.len() > 1 {
+        println!(
+            "{}",
+            "The offers use different currencies. Totals are not converted between currencies."
+                .yellow()
+                .bold()
+        );
+    }
+
+    Ok(())
+}
+
+/// This enum contains all compensation fields users can choose from.
+#[derive(Debug, Display, EnumIter)]
+enum CompensationField {
+    /// Update the lower end of the posted salary range.
+    #[strum(to_string = "Posted Range (Minimum)")]
+    PostedMin,
+    /// Update the upper end of the posted salary range.
+    #[strum(to_string = "Posted Range (Maximum)")]
+    PostedMax,
+    /// Update the salary you asked for.
+    #[strum(to_string = "Expected Salary")]
+    Expected,
+    /// Update the offered base salary.
+    #[strum(to_string = "Offered Base Salary")]
+    OfferedBase,
+    /// Update the offered yearly bonus.
+    #[strum(to_string = "Offered Bonus")]
+    OfferedBonus,
+    /// Update the yearly value of the offered equity.
+    #[strum(to_string = "Offered Equity")]
+    OfferedEquity,
+    /// Update the currency.
+    #[strum(to_string = "Currency")]
+    Currency,
+    /// Update the offer deadline.
+    #[strum(to_string = "Offer Deadline")]
+    OfferDeadline,
+    /// Update notes about the offered benefits.
+    #[strum(to_string = "Benefits")]
+    Benefits,
+}
+
+/// Parse a user-provided amount of money. Commas and underscores are ignored and a `k` or `m`
+/// suffix multiplies the amount by a thousand or a million (ie. `120k` or `1.5m`).
+fn parse_amount(input: &str) -> Result<i64, FettersError> {
+    let cleaned = input.trim().to_lowercase().replace([',', '_', ' '], "");
+    let (number, multiplier) = match cleaned.chars().last() {
+        Some('k') => (&cleaned[..cleaned.len() - 1], 1_000.0),
+        Some('m') => (&cleaned[..cleaned.len() - 1], 1_000_000.0),
+        _ => (cleaned.as_str(), 1.0),
+    };
+
+    match number.parse::<f64>() {
+        Ok(amount) if amount.is_finite() && amount >= 0.0 => {
+            Ok((amount * multiplier).round() as i64)
+        }
+        _ => Err(FettersError::InvalidAmount(input.to_string())),
+    }
+}
+
+/// Input an optional amount, re-prompting until a valid amount is entered.
+fn input_amount(
+    compensation_field: &CompensationField,
+    previous_value: Option<i64>,
+) -> Result<Option<i64>, FettersError> {
+    let message = format!(
+        "Enter the {}:",
+        compensation_field.to_string().to_lowercase()
+    );
+    let previous_value = previous_value.map(|amount| amount.to_string());
+
+    loop {
+        match input_optional_text(&message, previous_value.as_deref().unwrap_or(""))? {
+            Some(amount) => match parse_amount(&amount) {
+                Ok(amount) => return Ok(Some(amount)),
+                Err(error) => println!("{}", error.red().bold()),
+            },
+            None => return Ok(None),
+        }
+    }
+}
+
+/// Input an optional offer deadline, re-prompting until a valid date is entered.
+fn input_deadline(previous_value: Option<&str>) -> Result<Option<String>, FettersError> {
+    loop {
+        match input_optional_text(
+            "Enter the date by which the offer must be accepted (YYYY-MM-DD):",
+            previous_value.unwrap_or(""),
+        )? {
+            Some(deadline) => match normalize_date(&deadline) {
+                Ok(deadline) => return Ok(Some(deadline)),
+                Err(error) => println!("{}", error.red().bold()),
+            },
+            None => return Ok(None),
+        }
+    }
+}
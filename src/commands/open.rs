@@ -20,8 +20,8 @@ pub fn open_application(
 ) -> Result<(), FettersError> {
     let default_sprint = Some(current_sprint.name.clone());
 
-    // Search the default sprint if no sprint filter was specified.
-    if query_args.sprint.is_none() {
+    // Search the default sprint if no sprint filter or job ID was specified.
+    if query_args.sprint.is_none() && query_args.id.is_none() {
         query_args.sprint = default_sprint;
     }
 
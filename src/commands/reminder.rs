@@ -0,0 +1,136 @@
+//! Contains functions called by the CLI when managing follow-up reminders.
+
+use chrono::Local;
+use diesel::SqliteConnection;
+use owo_colors::OwoColorize;
+
+use crate::{
+    cli::{DueArgs, OutputFormat, RemindArgs},
+    config::configuration::ReminderRule,
+    errors::FettersError,
+    models::{
+        reminder::{NewReminder, TabledReminder},
+        sprint::QueriedSprint,
+    },
+    repositories::reminder::ReminderRepository,
+    utils::{
+        dates::{DATE_FORMAT, TIMESTAMP_FORMAT, parse_duration},
+        display::display_reminders,
+        format::print_records,
+        selection::select_job,
+    },
+};
+
+/// Set a follow-up reminder for a job application.
+pub fn add_reminder(
+    connection: &mut SqliteConnection,
+    remind_args: &mut RemindArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let due = (Local::now().naive_local() + parse_duration(&remind_args.due_in)?)
+        .format(TIMESTAMP_FORMAT)
+        .to_string();
+
+    let Some(job) = select_job(
+        connection,
+        &mut remind_args.query_args,
+        current_sprint,
+        "Select the job to set a reminder for:",
+    )?
+    else {
+        return Ok(());
+    };
+
+    let mut reminder_repo = ReminderRepository { connection };
+    reminder_repo.add_reminder(NewReminder {
+        job_id: job.id,
+        due: &due,
+        message: &remind_args.message,
+        done: false,
+        automatic: false,
+    })?;
+
+    println!(
+        "{}",
+        format!("\nReminder for {} set for {due}!\n", job.company_name)
+            .green()
+            .bold()
+    );
+
+    Ok(())
+}
+
+/// List overdue reminders and reminders that are due soon, or mark reminders as done. Automatic
+/// reminders are created from the reminder rules before listing.
+pub fn show_due_reminders(
+    connection: &mut SqliteConnection,
+    due_args: &DueArgs,
+    reminder_rules: &[ReminderRule],
+    output_format: OutputFormat,
+) -> Result<(), FettersError> {
+    let mut reminder_repo = ReminderRepository { connection };
+
+    if !due_args.done.is_empty() {
+        for reminder_id in due_args.done.iter() {
+            reminder_repo
+                .complete_reminder(*reminder_id)?
+                .ok_or(FettersError::ReminderNotFound(*reminder_id))?;
+        }
+
+        println!(
+            "{}",
+            format!("\nMarked {} reminder(s) as done!\n", due_args.done.len())
+                .green()
+                .bold()
+        );
+
+        return Ok(());
+    }
+
+    reminder_repo.sync_automatic_reminders(reminder_rules)?;
+
+    let now = Local::now().naive_local();
+    let due_before = (now + parse_duration(&due_args.within)?)
+        .format(TIMESTAMP_FORMAT)
+        .to_string();
+    let now_timestamp = now.format(TIMESTAMP_FORMAT).to_string();
+    let today = now.format(DATE_FORMAT).to_string();
+
+    let reminders: Vec<TabledReminder> = reminder_repo
+        .list_open_reminders(&due_before)?
+        .into_iter()
+        .map(|(reminder, company_name, status)| TabledReminder {
+            id: reminder.id,
+            state: if reminder.due < now_timestamp {
+                "OVERDUE".to_string()
+            } else if reminder.due.starts_with(&today) {
+                "DUE TODAY".to_string()
+            } else {
+                "UPCOMING".to_string()
+            },
+            due: reminder.due,
+            job_id: reminder.job_id,
+            company_name,
+            status,
+            message: reminder.message,
+        })
+        .collect();
+
+    if output_format != OutputFormat::Table {
+        return print_records(&reminders, output_format);
+    }
+
+    if reminders.is_empty() {
+        println!(
+            "{}",
+            format!("No reminders are due within {}.", due_args.within)
+                .green()
+                .bold()
+        );
+        return Ok(());
+    }
+
+    display_reminders(&reminders, &format!("REMINDERS [{} DUE]", reminders.len()));
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+//! Contains a function called by the CLI when restoring the database from an archive.
+
+use std::fs::read_to_string;
+
+use diesel::SqliteConnection;
+use inquire::Confirm;
+use owo_colors::OwoColorize;
+
+use crate::{
+    cli::RestoreArgs, commands::sprint::fall_back_to_latest_sprint, config::configuration::Config,
+    errors::FettersError, models::archive::Archive, repositories::archive::ArchiveRepository,
+    utils::prompt::get_inquire_config,
+};
+
+/// Replace all data in the database with the contents of a JSON archive. If the current sprint is
+/// not in the archive, the latest open sprint becomes the current sprint.
+pub fn restore_database(
+    connection: &mut SqliteConnection,
+    restore_args: &RestoreArgs,
+    config: Config,
+) -> Result<(), FettersError> {
+    let archive: Archive = serde_json::from_str(&read_to_string(&restore_args.path)?)?;
+
+    println!(
+        "{}",
+        format!(
+            "Archive created at {} contains {} job application(s) and {} sprint(s).",
+            archive.exported,
+            archive.jobs.len(),
+            archive.sprints.len()
+        )
+        .cyan()
+    );
+
+    let confirmed = if restore_args.yes {
+        Some(true)
+    } else {
+        Confirm::new("This will replace ALL existing data. Confirm restore?")
+            .with_default(false)
+            .with_render_config(get_inquire_config())
+            .prompt_skippable()?
+    };
+
+    if confirmed != Some(true) {
+        println!("{}", "Cancelled.".red().bold());
+        return Ok(());
+    }
+
+    let mut archive_repo = ArchiveRepository { connection };
+    archive_repo.restore_archive(archive)?;
+
+    println!(
+        "{}",
+        format!("\nRestored database from {:?}!\n", restore_args.path)
+            .green()
+            .bold()
+    );
+
+    fall_back_to_latest_sprint(connection, config)
+}
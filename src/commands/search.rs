@@ -0,0 +1,59 @@
+//! Contains a function called by the CLI when searching job applications.
+
+use diesel::SqliteConnection;
+use owo_colors::OwoColorize;
+
+use crate::{
+    cli::{OutputFormat, SearchArgs},
+    errors::FettersError,
+    repositories::search::SearchRepository,
+    utils::{display::display_search_results, format::print_records},
+};
+
+/// Search the company name, title, notes, and link of all job applications and show the matches,
+/// most relevant first. Matched terms are highlighted in tables.
+pub fn search_jobs(
+    connection: &mut SqliteConnection,
+    search_args: &SearchArgs,
+    output_format: OutputFormat,
+) -> Result<(), FettersError> {
+    let mut search_repo = SearchRepository { connection };
+    let mut search_results = search_repo.search_jobs(
+        &search_args.query,
+        search_args.sprint.as_deref(),
+        search_args.limit,
+    )?;
+
+    if output_format != OutputFormat::Table {
+        for search_result in search_results.iter_mut() {
+            search_result.apply_highlights(|matched| matched.to_string());
+        }
+
+        return print_records(&search_results, output_format);
+    }
+
+    if search_results.is_empty() {
+        println!(
+            "{}",
+            format!("No job applications match [{}].", search_args.query)
+                .green()
+                .bold()
+        );
+        return Ok(());
+    }
+
+    for search_result in search_results.iter_mut() {
+        search_result.apply_highlights(|matched| matched.yellow().bold().to_string());
+    }
+
+    display_search_results(
+        &search_results,
+        &format!(
+            "SEARCH RESULTS FOR [{}] [{} JOBS LISTED]",
+            search_args.query,
+            search_results.len()
+        ),
+    );
+
+    Ok(())
+}
@@ -6,27 +6,42 @@ use inquire::Select;
 use owo_colors::OwoColorize;
 
 use crate::{
+    cli::{OutputFormat, SprintEditArgs},
     config::configuration::Config,
     errors::FettersError,
-    models::sprint::{NewSprint, QueriedSprint, SprintUpdate},
+    models::sprint::{GoalPeriod, NewSprint, QueriedSprint, SprintGoal, SprintUpdate},
     repositories::sprint::SprintRepository,
-    utils::{display::display_sprint, prompt::get_inquire_config},
+    utils::{
+        dates::normalize_date,
+        display::{display_sprint, display_sprint_progress},
+        format::print_records,
+        prompt::{confirm, get_inquire_config, input_optional_text},
+    },
 };
 
-/// Display the current sprint and its metadata in a table.
+/// Display the current sprint and its metadata in a table, followed by its progress towards its
+/// goal if one is set.
 pub fn show_current_sprint(queried_sprint: QueriedSprint) {
+    let progress = queried_sprint.progress(Local::now().date_naive());
+
     display_sprint(&vec![queried_sprint], "CURRENT SPRINT");
+
+    if let Some(progress) = progress {
+        display_sprint_progress(&progress);
+    }
 }
 
 /// Create a new sprint.
 pub fn create_new_sprint(
     connection: &mut SqliteConnection,
     name: &Option<String>,
+    goal: Option<&str>,
     config: Config,
     current_sprint: &QueriedSprint,
 ) -> Result<(), FettersError> {
     let today = Local::now().format("%Y-%m-%d").to_string();
     let new_sprint_name = name.clone().unwrap_or(today.clone());
+    let goal = goal.map(SprintGoal::parse).transpose()?;
 
     // Throw `SprintNameConflict` if there is a naming collision between today's date and the
     // current sprint's name (defaults to date of creation).
@@ -40,9 +55,8 @@ pub fn create_new_sprint(
     sprint_repo.update_sprint(
         current_sprint.id,
         SprintUpdate {
-            name: None,
-            start_date: None,
             end_date: Some(Some(&today)),
+            ..Default::default()
         },
     )?;
 
@@ -52,6 +66,8 @@ pub fn create_new_sprint(
         start_date: &today,
         end_date: None,
         num_jobs: &0,
+        goal: goal.map(|goal| goal.target),
+        goal_period: goal.map(|goal| goal.period.to_string()).as_deref(),
     })?;
 
     // Write the new sprint to the configuration file.
@@ -64,11 +80,363 @@ pub fn create_new_sprint(
     Ok(())
 }
 
+/// Edit the dates or goal of a sprint. The goal is prompted for if no flag is provided.
+pub fn edit_sprint(
+    connection: &mut SqliteConnection,
+    edit_args: &SprintEditArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<(), FettersError> {
+    let queried_sprint = find_sprint(
+        connection,
+        edit_args.sprint.as_deref().unwrap_or(&current_sprint.name),
+    )?;
+
+    let start_date = edit_args
+        .start_date
+        .as_deref()
+        .map(normalize_date)
+        .transpose()?;
+    let end_date = match &edit_args.end_date {
+        Some(end_date) => Some(Some(normalize_date(end_date)?)),
+        None if edit_args.clear_end_date => Some(None),
+        None => None,
+    };
+
+    let new_start_date = start_date.as_ref().unwrap_or(&queried_sprint.start_date);
+    if let Some(new_end_date) = end_date
+        .as_ref()
+        .map_or(queried_sprint.end_date.as_ref(), Option::as_ref)
+        && new_start_date > new_end_date
+    {
+        return Err(FettersError::InvalidSprintDates(
+            new_start_date.to_string(),
+            new_end_date.to_string(),
+        ));
+    }
+
+    let has_date_updates = start_date.is_some() || end_date.is_some();
+    let new_goal = match &edit_args.goal {
+        Some(goal) => Some(Some(SprintGoal::parse(goal)?)),
+        None if edit_args.clear_goal => Some(None),
+        None if has_date_updates => None,
+        None => Some(input_goal(queried_sprint.sprint_goal())?),
+    };
+    let goal_period = new_goal.map(|goal| goal.map(|goal| goal.period.to_string()));
+
+    let mut sprint_repo = SprintRepository { connection };
+    let updated_sprint = sprint_repo.update_sprint(
+        queried_sprint.id,
+        SprintUpdate {
+            start_date: start_date.as_deref(),
+            end_date: end_date.as_ref().map(Option::as_deref),
+            goal: new_goal.map(|goal| goal.map(|goal| goal.target)),
+            goal_period: goal_period.as_ref().map(Option::as_deref),
+            ..Default::default()
+        },
+    )?;
+    let progress = updated_sprint.progress(Local::now().date_naive());
+
+    display_sprint(&vec![updated_sprint], "UPDATED SPRINT");
+
+    if let Some(progress) = progress {
+        display_sprint_progress(&progress);
+    }
+
+    Ok(())
+}
+
+/// Rename a sprint. The configuration file is updated if the current sprint is renamed.
+pub fn rename_sprint(
+    connection: &mut SqliteConnection,
+    name: &str,
+    new_name: &str,
+    config: Config,
+) -> Result<(), FettersError> {
+    let queried_sprint = find_sprint(connection, name)?;
+    let new_name = new_name.trim();
+
+    if new_name.is_empty() {
+        return Err(FettersError::UnknownError(
+            "A sprint name cannot be empty.".to_string(),
+        ));
+    }
+
+    let mut sprint_repo = SprintRepository { connection };
+    if new_name != queried_sprint.name && sprint_repo.get_sprint_by_name(new_name)?.is_some() {
+        return Err(FettersError::SprintNameConflict(new_name.to_string()));
+    }
+
+    let renamed_sprint = sprint_repo.update_sprint(
+        queried_sprint.id,
+        SprintUpdate {
+            name: Some(new_name),
+            ..Default::default()
+        },
+    )?;
+
+    if config.current_sprint == queried_sprint.name {
+        let mut new_config = config;
+        new_config.current_sprint = renamed_sprint.name.clone();
+        new_config.save_to_file()?;
+    }
+
+    println!(
+        "{}",
+        format!(
+            "\nRenamed sprint [{}] to [{}]!\n",
+            queried_sprint.name, renamed_sprint.name
+        )
+        .green()
+        .bold()
+    );
+
+    Ok(())
+}
+
+/// Delete a sprint. Sprints containing job applications can only be deleted when another sprint
+/// is provided to move the job applications to. The current sprint can only be deleted when
+/// another sprint is provided, which becomes the new current sprint.
+pub fn delete_sprint(
+    connection: &mut SqliteConnection,
+    name: &str,
+    reassign_to: Option<&str>,
+    yes: bool,
+    config: Config,
+) -> Result<(), FettersError> {
+    let queried_sprint = find_sprint(connection, name)?;
+    let target_sprint = match reassign_to {
+        Some(target_name) => Some(find_sprint(connection, target_name)?),
+        None => None,
+    };
+
+    if let Some(ref target_sprint) = target_sprint
+        && target_sprint.id == queried_sprint.id
+    {
+        return Err(FettersError::UnknownError(
+            "A sprint cannot be reassigned to itself.".to_string(),
+        ));
+    }
+
+    let is_current_sprint = config.current_sprint == queried_sprint.name;
+    if is_current_sprint && target_sprint.is_none() {
+        return Err(FettersError::SprintInUse(queried_sprint.name));
+    }
+
+    let mut sprint_repo = SprintRepository { connection };
+    let (active_jobs, trashed_jobs) = sprint_repo.count_jobs_in_sprint(queried_sprint.id)?;
+
+    // Job applications in the trash still reference the sprint, so they also block deletion.
+    if target_sprint.is_none() {
+        if active_jobs > 0 {
+            return Err(FettersError::SprintNotEmpty(
+                queried_sprint.name,
+                active_jobs,
+            ));
+        }
+        if trashed_jobs > 0 {
+            return Err(FettersError::SprintHasTrashedJobs(
+                queried_sprint.name,
+                trashed_jobs,
+            ));
+        }
+    }
+
+    let message = match target_sprint {
+        Some(ref target_sprint) if active_jobs + trashed_jobs > 0 => format!(
+            "Delete sprint [{}] and move {} to [{}]?",
+            queried_sprint.name,
+            describe_job_counts(active_jobs, trashed_jobs),
+            target_sprint.name
+        ),
+        _ => format!("Delete sprint [{}]?", queried_sprint.name),
+    };
+
+    if !confirm(&message, yes)? {
+        return Ok(());
+    }
+
+    sprint_repo.delete_sprint(
+        queried_sprint.id,
+        target_sprint.as_ref().map(|target_sprint| target_sprint.id),
+    )?;
+
+    if is_current_sprint && let Some(target_sprint) = target_sprint {
+        switch_current_sprint(&mut sprint_repo, config, &target_sprint)?;
+    }
+
+    println!(
+        "{}",
+        format!("\nDeleted sprint [{}]!\n", queried_sprint.name)
+            .green()
+            .bold()
+    );
+
+    Ok(())
+}
+
+/// Merge a sprint into another one. The configuration file is updated if the merged sprint is the
+/// current sprint.
+pub fn merge_sprints(
+    connection: &mut SqliteConnection,
+    from: &str,
+    into: &str,
+    yes: bool,
+    config: Config,
+) -> Result<(), FettersError> {
+    let from_sprint = find_sprint(connection, from)?;
+    let into_sprint = find_sprint(connection, into)?;
+
+    if from_sprint.id == into_sprint.id {
+        return Err(FettersError::UnknownError(
+            "A sprint cannot be merged into itself.".to_string(),
+        ));
+    }
+
+    let mut sprint_repo = SprintRepository { connection };
+    let (active_jobs, trashed_jobs) = sprint_repo.count_jobs_in_sprint(from_sprint.id)?;
+
+    if !confirm(
+        &format!(
+            "Merge sprint [{}] into [{}], moving {}?",
+            from_sprint.name,
+            into_sprint.name,
+            describe_job_counts(active_jobs, trashed_jobs)
+        ),
+        yes,
+    )? {
+        return Ok(());
+    }
+
+    let mut merged_sprint = sprint_repo.merge_sprints(&from_sprint, &into_sprint)?;
+
+    if config.current_sprint == from_sprint.name {
+        merged_sprint = switch_current_sprint(&mut sprint_repo, config, &merged_sprint)?;
+    }
+
+    display_sprint(&vec![merged_sprint], "MERGED SPRINT");
+
+    Ok(())
+}
+
+/// Describe the number of active job applications and job applications in the trash that are
+/// moved between sprints.
+fn describe_job_counts(active_jobs: i64, trashed_jobs: i64) -> String {
+    if trashed_jobs > 0 {
+        format!("{active_jobs} job application(s) and {trashed_jobs} in the trash")
+    } else {
+        format!("{active_jobs} job application(s)")
+    }
+}
+
+/// Make the latest open sprint the current sprint if the current sprint no longer exists, ie. after
+/// an undo or a restore removed it. Closed sprints are only used if no sprint is open.
+pub fn fall_back_to_latest_sprint(
+    connection: &mut SqliteConnection,
+    mut config: Config,
+) -> Result<(), FettersError> {
+    let mut sprint_repo = SprintRepository { connection };
+    if sprint_repo
+        .get_sprint_by_name(&config.current_sprint)?
+        .is_none()
+        && let Some(latest_sprint) =
+            sprint_repo
+                .get_all_sprints()?
+                .into_iter()
+                .max_by_key(|sprint| {
+                    (
+                        sprint.end_date.is_none(),
+                        sprint.start_date.clone(),
+                        sprint.id,
+                    )
+                })
+    {
+        config.current_sprint = latest_sprint.name;
+        config.save_to_file()?;
+
+        println!(
+            "{}",
+            format!("Switched to sprint [{}].", config.current_sprint).cyan()
+        );
+    }
+
+    Ok(())
+}
+
+/// Find a sprint by name, raising an error if it does not exist.
+fn find_sprint(
+    connection: &mut SqliteConnection,
+    name: &str,
+) -> Result<QueriedSprint, FettersError> {
+    let mut sprint_repo = SprintRepository { connection };
+
+    sprint_repo
+        .get_sprint_by_name(name)?
+        .ok_or(FettersError::SprintNotFound(name.to_string()))
+}
+
+/// Make a sprint the current sprint by writing it to the configuration file and removing its end
+/// date.
+fn switch_current_sprint(
+    sprint_repo: &mut SprintRepository,
+    config: Config,
+    queried_sprint: &QueriedSprint,
+) -> Result<QueriedSprint, FettersError> {
+    let mut new_config = config;
+    new_config.current_sprint = queried_sprint.name.clone();
+    new_config.save_to_file()?;
+
+    println!(
+        "{}",
+        format!(
+            "Successfully set the current sprint to [{}]!",
+            &new_config.current_sprint
+        )
+        .green()
+        .bold()
+    );
+
+    sprint_repo.update_sprint(
+        queried_sprint.id,
+        SprintUpdate {
+            end_date: Some(None),
+            ..Default::default()
+        },
+    )
+}
+
+/// Input an optional sprint goal, re-prompting until a valid goal is entered.
+fn input_goal(previous_goal: Option<SprintGoal>) -> Result<Option<SprintGoal>, FettersError> {
+    let previous_value = previous_goal.map_or(String::new(), |goal| match goal.period {
+        GoalPeriod::Sprint => goal.target.to_string(),
+        GoalPeriod::Week => format!("{}/week", goal.target),
+    });
+
+    loop {
+        match input_optional_text(
+            "Enter the number of job applications to track in this sprint (ie. 50 or 10/week):",
+            &previous_value,
+        )? {
+            Some(goal) => match SprintGoal::parse(&goal) {
+                Ok(goal) => return Ok(Some(goal)),
+                Err(error) => println!("{}", error.red().bold()),
+            },
+            None => return Ok(None),
+        }
+    }
+}
+
 /// Display all tracked sprints.
-pub fn show_all_sprints(connection: &mut SqliteConnection) -> Result<(), FettersError> {
+pub fn show_all_sprints(
+    connection: &mut SqliteConnection,
+    output_format: OutputFormat,
+) -> Result<(), FettersError> {
     let mut sprint_repo = SprintRepository { connection };
     let all_sprints = sprint_repo.get_all_sprints()?;
 
+    if output_format != OutputFormat::Table {
+        return print_records(&all_sprints, output_format);
+    }
+
     display_sprint(&all_sprints, "ALL SPRINTS");
 
     Ok(())
@@ -110,9 +478,8 @@ pub fn set_sprint(
                 sprint_repo.update_sprint(
                     current_sprint.id,
                     SprintUpdate {
-                        name: None,
-                        start_date: None,
                         end_date: Some(Some(&today)),
+                        ..Default::default()
                     },
                 )?;
 
@@ -120,9 +487,8 @@ pub fn set_sprint(
                 sprint_repo.update_sprint(
                     queried_sprint.id,
                     SprintUpdate {
-                        name: None,
-                        start_date: None,
                         end_date: Some(None),
+                        ..Default::default()
                     },
                 )?;
 
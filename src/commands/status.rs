@@ -0,0 +1,250 @@
+//! Contains functions called by the CLI when managing application statuses.
+
+use diesel::SqliteConnection;
+use inquire::Confirm;
+use owo_colors::OwoColorize;
+
+use crate::{
+    cli::OutputFormat,
+    errors::FettersError,
+    models::status::{QueriedStatus, StatusUpdate},
+    repositories::statuses::{DEFAULT_STATUSES, StatusRepository},
+    utils::{
+        colors::normalize_color, display::display_statuses, format::print_records,
+        prompt::get_inquire_config,
+    },
+};
+
+/// Add a new status. Status names are stored in uppercase.
+pub fn add_status(
+    connection: &mut SqliteConnection,
+    name: &str,
+    color: Option<&str>,
+    is_terminal: bool,
+) -> Result<(), FettersError> {
+    let color = color.map(normalize_color).transpose()?;
+
+    let mut status_repo = StatusRepository { connection };
+    let status =
+        status_repo.add_status(&name.trim().to_uppercase(), color.as_deref(), is_terminal)?;
+
+    println!(
+        "{}",
+        format!("\nAdded status [{}]!\n", status.name)
+            .green()
+            .bold()
+    );
+
+    Ok(())
+}
+
+/// List all statuses in their sort order.
+pub fn list_statuses(
+    connection: &mut SqliteConnection,
+    output_format: OutputFormat,
+) -> Result<(), FettersError> {
+    let mut status_repo = StatusRepository { connection };
+    let all_statuses = status_repo.get_all_statuses()?;
+
+    if output_format != OutputFormat::Table {
+        return print_records(&all_statuses, output_format);
+    }
+
+    display_statuses(
+        &all_statuses,
+        &format!("STATUSES [{} LISTED]", all_statuses.len()),
+    );
+
+    Ok(())
+}
+
+/// Rename a status. Status names are stored in uppercase.
+pub fn rename_status(
+    connection: &mut SqliteConnection,
+    name: &str,
+    new_name: &str,
+) -> Result<(), FettersError> {
+    let status = find_status(connection, name)?;
+    refuse_built_in_status(&status)?;
+    let new_name = new_name.trim().to_uppercase();
+
+    let mut status_repo = StatusRepository { connection };
+    if let Some(existing_status) = status_repo.get_status_by_name(&new_name)?
+        && existing_status.id != status.id
+    {
+        return Err(FettersError::StatusNameConflict(new_name));
+    }
+
+    status_repo.update_status(
+        status.id,
+        StatusUpdate {
+            name: Some(&new_name),
+            ..Default::default()
+        },
+    )?;
+
+    println!(
+        "{}",
+        format!("\nRenamed status [{}] to [{new_name}]!\n", status.name)
+            .green()
+            .bold()
+    );
+
+    Ok(())
+}
+
+/// Remove a status. A status that is still referenced by job applications or their status
+/// history can only be removed if a replacement status is provided.
+pub fn remove_status(
+    connection: &mut SqliteConnection,
+    name: &str,
+    replace_with: Option<&str>,
+    yes: bool,
+) -> Result<(), FettersError> {
+    let status = find_status(connection, name)?;
+    refuse_built_in_status(&status)?;
+    let replacement = match replace_with {
+        Some(replacement_name) => Some(find_status(connection, replacement_name)?),
+        None => None,
+    };
+
+    if let Some(ref replacement) = replacement
+        && replacement.id == status.id
+    {
+        return Err(FettersError::UnknownError(
+            "A status cannot be replaced with itself.".to_string(),
+        ));
+    }
+
+    let mut status_repo = StatusRepository { connection };
+    let references = status_repo.count_status_references(status.id)?;
+
+    if references > 0 && replacement.is_none() {
+        return Err(FettersError::StatusInUse(status.name, references));
+    }
+
+    let message = match replacement {
+        Some(ref replacement) if references > 0 => format!(
+            "Remove status [{}] and move {references} record(s) to [{}]?",
+            status.name, replacement.name
+        ),
+        _ => format!("Remove status [{}]?", status.name),
+    };
+
+    let confirmed = if yes {
+        Some(true)
+    } else {
+        Confirm::new(&message)
+            .with_default(true)
+            .with_render_config(get_inquire_config())
+            .prompt_skippable()?
+    };
+
+    match confirmed {
+        Some(true) => {
+            status_repo.remove_status(status.id, replacement.map(|replacement| replacement.id))?;
+
+            println!(
+                "{}",
+                format!("\nRemoved status [{}]!\n", status.name)
+                    .green()
+                    .bold()
+            );
+        }
+        Some(false) => println!("{}", "Cancelled.".red().bold()),
+        None => println!("{}", "Invalid input, try again".red().bold()),
+    }
+
+    Ok(())
+}
+
+/// Move a status to a new position in the status order.
+pub fn reorder_status(
+    connection: &mut SqliteConnection,
+    name: &str,
+    position: usize,
+) -> Result<(), FettersError> {
+    let status = find_status(connection, name)?;
+
+    let mut status_repo = StatusRepository { connection };
+    status_repo.reorder_status(status.id, position)?;
+
+    let all_statuses = status_repo.get_all_statuses()?;
+    display_statuses(&all_statuses, "STATUSES");
+
+    Ok(())
+}
+
+/// Set the display color of a status. Passing `none` removes the color.
+pub fn set_status_color(
+    connection: &mut SqliteConnection,
+    name: &str,
+    color: &str,
+) -> Result<(), FettersError> {
+    let status = find_status(connection, name)?;
+    let color = match color.trim().to_lowercase().as_str() {
+        "none" => None,
+        _ => Some(normalize_color(color)?),
+    };
+
+    let mut status_repo = StatusRepository { connection };
+    status_repo.update_status(
+        status.id,
+        StatusUpdate {
+            color: Some(color.as_deref()),
+            ..Default::default()
+        },
+    )?;
+
+    let all_statuses = status_repo.get_all_statuses()?;
+    display_statuses(&all_statuses, "STATUSES");
+
+    Ok(())
+}
+
+/// Set whether job applications in a status are finished.
+pub fn set_status_terminal(
+    connection: &mut SqliteConnection,
+    name: &str,
+    is_terminal: bool,
+) -> Result<(), FettersError> {
+    let status = find_status(connection, name)?;
+
+    let mut status_repo = StatusRepository { connection };
+    status_repo.update_status(
+        status.id,
+        StatusUpdate {
+            is_terminal: Some(is_terminal),
+            ..Default::default()
+        },
+    )?;
+
+    let all_statuses = status_repo.get_all_statuses()?;
+    display_statuses(&all_statuses, "STATUSES");
+
+    Ok(())
+}
+
+/// Refuse changes to a built-in status, which is looked up by name by the analytics, the sweep,
+/// the doctor, and the default reminder rules.
+fn refuse_built_in_status(status: &QueriedStatus) -> Result<(), FettersError> {
+    if DEFAULT_STATUSES
+        .iter()
+        .any(|(default_name, _, _)| *default_name == status.name)
+    {
+        return Err(FettersError::StatusIsBuiltIn(status.name.clone()));
+    }
+
+    Ok(())
+}
+
+/// Find a status by name, raising an error if it does not exist.
+fn find_status(
+    connection: &mut SqliteConnection,
+    name: &str,
+) -> Result<QueriedStatus, FettersError> {
+    let mut status_repo = StatusRepository { connection };
+    status_repo
+        .get_status_by_name(name)?
+        .ok_or(FettersError::StatusNotFound(name.to_string()))
+}
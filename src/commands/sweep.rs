@@ -0,0 +1,107 @@
+//! Contains a function called by the CLI when marking inactive job applications as ghosted.
+
+use diesel::SqliteConnection;
+use inquire::Confirm;
+use owo_colors::OwoColorize;
+
+use crate::{
+    cli::SweepArgs,
+    config::configuration::SweepSettings,
+    errors::FettersError,
+    repositories::{job::JobRepository, statuses::StatusRepository},
+    utils::{display::display_jobs_with_header, prompt::get_inquire_config},
+};
+
+/// Find `PENDING` job applications across all sprints whose last activity is older than the
+/// inactivity threshold and mark them as `GHOSTED` after confirmation. Nothing is printed when
+/// running on startup and no inactive job applications were found, and a hint to run the sweep is
+/// printed instead of marking them when running on startup with `--dry-run`.
+pub fn sweep_inactive_jobs(
+    connection: &mut SqliteConnection,
+    sweep_args: &SweepArgs,
+    sweep_settings: &SweepSettings,
+    on_startup: bool,
+) -> Result<(), FettersError> {
+    let inactivity_days = sweep_args.days.unwrap_or(sweep_settings.inactivity_days);
+
+    let mut status_repo = StatusRepository { connection };
+    let ghosted_status = status_repo
+        .get_status_by_name("GHOSTED")?
+        .ok_or(FettersError::StatusNotFound("GHOSTED".to_string()))?;
+
+    let mut job_repo = JobRepository { connection };
+    let inactive_job_ids = job_repo.find_inactive_jobs("PENDING", inactivity_days)?;
+
+    if inactive_job_ids.is_empty() {
+        if on_startup {
+            return Ok(());
+        }
+
+        println!(
+            "{}",
+            format!("No PENDING job applications have been inactive for more than {inactivity_days} day(s).")
+                .green()
+                .bold()
+        );
+        return Ok(());
+    }
+
+    let inactive_jobs = job_repo.list_jobs_by_ids(&inactive_job_ids)?;
+    display_jobs_with_header(
+        &inactive_jobs,
+        &format!(
+            "INACTIVE FOR MORE THAN {inactivity_days} DAY(S) [{} JOBS LISTED]",
+            inactive_jobs.len()
+        ),
+    );
+
+    if sweep_args.dry_run {
+        if on_startup {
+            println!(
+                "{}",
+                format!(
+                    "Run `fetters sweep` to mark them as [{}].\n",
+                    ghosted_status.name
+                )
+                .yellow()
+                .bold()
+            );
+        }
+
+        return Ok(());
+    }
+
+    let confirmed = if sweep_args.yes {
+        Some(true)
+    } else {
+        Confirm::new(&format!(
+            "Mark {} job application(s) as [{}]?",
+            inactive_jobs.len(),
+            ghosted_status.name
+        ))
+        .with_default(true)
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?
+    };
+
+    match confirmed {
+        Some(true) => {
+            job_repo.set_status_for_jobs(&inactive_job_ids, ghosted_status.id)?;
+
+            println!(
+                "{}",
+                format!(
+                    "\nMarked {} job application(s) as [{}]!\n",
+                    inactive_job_ids.len(),
+                    ghosted_status.name
+                )
+                .green()
+                .bold()
+            );
+        }
+        Some(false) => println!("{}", "Cancelled.".red().bold()),
+        None => println!("{}", "Invalid input, try again".red().bold()),
+    }
+
+    Ok(())
+}
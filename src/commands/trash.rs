@@ -0,0 +1,172 @@
+//! Contains functions called by the CLI when managing job applications in the trash.
+
+use chrono::Local;
+use diesel::SqliteConnection;
+use inquire::MultiSelect;
+use owo_colors::OwoColorize;
+
+use crate::{
+    cli::OutputFormat,
+    errors::FettersError,
+    models::job::TrashedJob,
+    repositories::job::JobRepository,
+    utils::{
+        dates::{TIMESTAMP_FORMAT, parse_duration},
+        display::display_trash,
+        format::print_records,
+        prompt::{confirm, get_inquire_config},
+    },
+};
+
+/// List the job applications in the trash, optionally limited to the ones deleted more than
+/// `older_than` ago.
+pub fn list_trash(
+    connection: &mut SqliteConnection,
+    older_than: Option<&str>,
+    output_format: OutputFormat,
+) -> Result<(), FettersError> {
+    let trashed_jobs = find_trashed_jobs(connection, older_than)?;
+
+    if output_format != OutputFormat::Table {
+        return print_records(&trashed_jobs, output_format);
+    }
+
+    if trashed_jobs.is_empty() {
+        println!("{}", "The trash is empty.".green().bold());
+        return Ok(());
+    }
+
+    display_trash(
+        &trashed_jobs,
+        &format!("TRASH [{} JOBS LISTED]", trashed_jobs.len()),
+    );
+
+    Ok(())
+}
+
+/// Restore job applications from the trash. The job applications are targeted by their IDs,
+/// every job application is restored if `all` is set, and the user selects them from a list
+/// otherwise.
+pub fn restore_trashed_jobs(
+    connection: &mut SqliteConnection,
+    job_ids: &[i32],
+    all: bool,
+    yes: bool,
+) -> Result<(), FettersError> {
+    let trashed_jobs = find_trashed_jobs(connection, None)?;
+
+    if trashed_jobs.is_empty() {
+        println!("{}", "The trash is empty.".green().bold());
+        return Ok(());
+    }
+
+    let selected_jobs: Vec<TrashedJob> = if all {
+        trashed_jobs
+    } else if !job_ids.is_empty() {
+        if let Some(job_id) = job_ids
+            .iter()
+            .find(|job_id| !trashed_jobs.iter().any(|job| job.id == **job_id))
+        {
+            return Err(FettersError::JobNotInTrash(*job_id));
+        }
+
+        trashed_jobs
+            .into_iter()
+            .filter(|job| job_ids.contains(&job.id))
+            .collect()
+    } else {
+        display_trash(
+            &trashed_jobs,
+            &format!("TRASH [{} JOBS LISTED]", trashed_jobs.len()),
+        );
+
+        match MultiSelect::new("Select the jobs you want to restore:", trashed_jobs)
+            .with_render_config(get_inquire_config())
+            .prompt_skippable()?
+        {
+            Some(selected_jobs) if !selected_jobs.is_empty() => selected_jobs,
+            _ => return Ok(()),
+        }
+    };
+
+    if confirm(
+        &format!("Restore {} job application(s)?", selected_jobs.len()),
+        yes,
+    )? {
+        let selected_ids: Vec<i32> = selected_jobs.iter().map(|job| job.id).collect();
+
+        let mut job_repo = JobRepository { connection };
+        job_repo.restore_jobs(&selected_ids)?;
+
+        println!(
+            "{}",
+            format!("\nRestored {} job application(s)!\n", selected_ids.len())
+                .green()
+                .bold()
+        );
+    }
+
+    Ok(())
+}
+
+/// Permanently delete the job applications in the trash, optionally limited to the ones deleted
+/// more than `older_than` ago.
+pub fn purge_trash(
+    connection: &mut SqliteConnection,
+    older_than: Option<&str>,
+    yes: bool,
+) -> Result<(), FettersError> {
+    let trashed_jobs = find_trashed_jobs(connection, older_than)?;
+
+    if trashed_jobs.is_empty() {
+        println!("{}", "Nothing to purge.".green().bold());
+        return Ok(());
+    }
+
+    display_trash(
+        &trashed_jobs,
+        &format!("PURGE [{} JOBS LISTED]", trashed_jobs.len()),
+    );
+
+    if confirm(
+        &format!(
+            "Permanently delete {} job application(s)?",
+            trashed_jobs.len()
+        ),
+        yes,
+    )? {
+        let job_ids: Vec<i32> = trashed_jobs.iter().map(|job| job.id).collect();
+
+        let mut job_repo = JobRepository { connection };
+        job_repo.purge_jobs(&job_ids)?;
+
+        println!(
+            "{}",
+            format!("\nPurged {} job application(s)!\n", job_ids.len())
+                .green()
+                .bold()
+        );
+    }
+
+    Ok(())
+}
+
+/// List the job applications in the trash. Only job applications deleted more than `older_than`
+/// ago (ie. `30d`) are listed if it is provided.
+fn find_trashed_jobs(
+    connection: &mut SqliteConnection,
+    older_than: Option<&str>,
+) -> Result<Vec<TrashedJob>, FettersError> {
+    let deleted_before = older_than
+        .map(|older_than| {
+            parse_duration(older_than).map(|duration| {
+                (Local::now().naive_local() - duration)
+                    .format(TIMESTAMP_FORMAT)
+                    .to_string()
+            })
+        })
+        .transpose()?;
+
+    let mut job_repo = JobRepository { connection };
+    job_repo.list_trashed_jobs(deleted_before.as_deref())
+}
@@ -0,0 +1,55 @@
+//! Contains a function called by the CLI when undoing changes recorded in the audit log.
+
+use diesel::SqliteConnection;
+use owo_colors::OwoColorize;
+
+use crate::{
+    cli::UndoArgs,
+    commands::sprint::fall_back_to_latest_sprint,
+    config::configuration::Config,
+    errors::FettersError,
+    repositories::audit::AuditRepository,
+    utils::{display::display_audit_log, prompt::confirm},
+};
+
+/// Revert the most recent operations that have not been undone yet after confirmation. Operations
+/// made by the sweep on startup are skipped unless `--include-sweeps` is set. If the current sprint
+/// no longer exists afterwards, the latest open sprint becomes the current sprint.
+pub fn undo_operations(
+    connection: &mut SqliteConnection,
+    undo_args: &UndoArgs,
+    config: Config,
+) -> Result<(), FettersError> {
+    let mut audit_repo = AuditRepository { connection };
+    let operations =
+        audit_repo.list_undoable_operations(undo_args.count, undo_args.include_sweeps)?;
+
+    if operations.is_empty() {
+        println!("{}", "Nothing to undo.".green().bold());
+        return Ok(());
+    }
+
+    display_audit_log(
+        &operations,
+        &format!("UNDO [{} OPERATION(S)]", operations.len()),
+    );
+
+    if !confirm(
+        &format!("Undo {} operation(s)?", operations.len()),
+        undo_args.yes,
+    )? {
+        return Ok(());
+    }
+
+    let operation_ids: Vec<i32> = operations.iter().map(|operation| operation.id).collect();
+    audit_repo.undo_operations(&operation_ids)?;
+
+    println!(
+        "{}",
+        format!("\nUndid {} operation(s)!\n", operation_ids.len())
+            .green()
+            .bold()
+    );
+
+    fall_back_to_latest_sprint(connection, config)
+}
@@ -6,52 +6,64 @@ use owo_colors::OwoColorize;
 use strum::{Display, EnumIter, IntoEnumIterator};
 
 use crate::{
-    cli::QueryArgs,
+    cli::UpdateArgs,
     errors::FettersError,
-    models::{job::JobUpdate, sprint::QueriedSprint, title::NewTitle},
+    models::{
+        job::{JobUpdate, WorkMode},
+        sprint::QueriedSprint,
+        title::NewTitle,
+    },
     repositories::{
-        job::JobRepository, sprint::SprintRepository, statuses::StatusRepository,
-        title::TitleRepository,
+        job::JobRepository, source::SourceRepository, sprint::SprintRepository,
+        statuses::StatusRepository, title::TitleRepository,
     },
     utils::{
-        display::display_jobs,
         prompt::get_inquire_config,
-        titles::{TitleType, create_or_use_title},
+        selection::select_job,
+        tags::normalize_tags,
+        titles::{TitleType, create_or_use_title, use_title_by_name},
     },
 };
 
 /// Update a tracked job application.
 pub fn update_job(
     connection: &mut SqliteConnection,
-    query_args: &mut QueryArgs,
+    update_args: &mut UpdateArgs,
     current_sprint: &QueriedSprint,
 ) -> Result<(), FettersError> {
-    let default_sprint = Some(current_sprint.name.clone());
+    let selected_job = select_job(
+        connection,
+        &mut update_args.query_args,
+        current_sprint,
+        "Select the job you want to modify:",
+    )?;
 
-    // Search the default sprint if no sprint filter was specified.
-    if query_args.sprint.is_none() {
-        query_args.sprint = default_sprint;
-    }
+    // Apply the values from the `--set-*` flags without prompting if any were provided.
+    if let Some(ref job) = selected_job
+        && update_args.has_updates()
+    {
+        let resolved_updates = ResolvedUpdates::resolve(connection, update_args)?;
+        let job_update = resolved_updates.job_update(update_args);
 
-    let mut job_repo = JobRepository { connection };
-    let matched_jobs = job_repo.list_jobs(query_args, current_sprint)?;
-
-    if matched_jobs.is_empty() {
-        return Err(FettersError::NoJobsAvailable(
-            query_args
-                .sprint
-                .clone()
-                .as_ref()
-                .unwrap_or(&current_sprint.name.clone())
-                .to_string(),
-        ));
-    }
+        let mut job_repo = JobRepository { connection };
+        job_repo.update_job(
+            job.id,
+            job_update,
+            &normalize_tags(&update_args.add_tag),
+            &normalize_tags(&update_args.remove_tag),
+        )?;
 
-    display_jobs(&matched_jobs, &current_sprint.name);
+        println!(
+            "{}",
+            format!("\nUpdated entry with ID [{}]!\n", job.id)
+                .green()
+                .bold()
+        );
 
-    if let Some(job) = Select::new("Select the job you want to modify:", matched_jobs)
-        .with_render_config(get_inquire_config())
-        .prompt_skippable()?
+        return Ok(());
+    }
+
+    if let Some(job) = selected_job
         && let Some(selections) = MultiSelect::new(
             "Select the fields you want to update:",
             UpdatableField::iter().collect(),
@@ -65,33 +77,62 @@ pub fn update_job(
         let mut new_sprint_id: Option<i32> = None;
         let mut new_status_id: Option<i32> = None;
         let mut new_title_id: Option<i32> = None;
+        let mut new_location: Option<String> = None;
+        let mut new_country: Option<String> = None;
+        let mut new_work_mode: Option<String> = None;
+        let mut new_source_id: Option<i32> = None;
+        let mut new_tags: Option<Vec<String>> = None;
 
         for selection in selections {
             match selection {
                 UpdatableField::CompanyName => {
                     new_company_name = Some(input_prompt(&selection, &job.company_name)?);
                 }
+                UpdatableField::Country => {
+                    new_country = Some(input_prompt(
+                        &selection,
+                        &job.country.clone().unwrap_or("".to_string()),
+                    )?);
+                }
                 UpdatableField::Link => {
                     new_link = Some(input_prompt(
                         &selection,
                         &job.link.clone().unwrap_or("".to_string()),
                     )?);
                 }
+                UpdatableField::Location => {
+                    new_location = Some(input_prompt(
+                        &selection,
+                        &job.location.clone().unwrap_or("".to_string()),
+                    )?);
+                }
                 UpdatableField::Notes => {
                     new_notes = Some(input_prompt(
                         &selection,
                         &job.notes.clone().unwrap_or("".to_string()),
                     )?);
                 }
+                UpdatableField::Source => {
+                    set_new_source(connection, &mut new_source_id)?;
+                }
                 UpdatableField::Sprint => {
                     set_new_sprint(connection, &mut new_sprint_id)?;
                 }
                 UpdatableField::Status => {
                     set_new_status(connection, &mut new_status_id)?;
                 }
+                UpdatableField::Tags => {
+                    let tags = input_prompt(&selection, &job.tags.clone().unwrap_or_default())?;
+                    new_tags = Some(normalize_tags(
+                        &tags.split(',').map(str::to_string).collect::<Vec<String>>(),
+                    ));
+                }
                 UpdatableField::Title => {
                     set_new_title(connection, &mut new_title_id)?;
                 }
+                UpdatableField::WorkMode => {
+                    set_new_work_mode(&mut new_work_mode)?;
+                }
             }
         }
 
@@ -105,13 +146,40 @@ pub fn update_job(
                     company_name: new_company_name.as_deref(),
                     title_id: new_title_id,
                     status_id: new_status_id,
-                    link: new_link.as_deref(),
-                    notes: new_notes.as_deref(),
+                    link: clear_if_empty(new_link.as_deref()),
+                    notes: clear_if_empty(new_notes.as_deref()),
                     sprint_id: new_sprint_id,
+                    location: clear_if_empty(new_location.as_deref()),
+                    country: clear_if_empty(new_country.as_deref()),
+                    work_mode: clear_if_empty(new_work_mode.as_deref()),
+                    source_id: new_source_id,
+                };
+
+                // Replace the current tags by removing the ones missing from the new tags.
+                let (add_tags, remove_tags) = match new_tags {
+                    Some(new_tags) => {
+                        let removed_tags = job
+                            .tags
+                            .as_deref()
+                            .map(|tags| {
+                                normalize_tags(
+                                    &tags.split(',').map(str::to_string).collect::<Vec<String>>(),
+                                )
+                            })
+                            .unwrap_or_default()
+                            .into_iter()
+                            .filter(|tag| !new_tags.contains(tag))
+                            .collect();
+
+                        (new_tags, removed_tags)
+                    }
+                    None => (Vec::new(), Vec::new()),
                 };
 
-                let mut job_repo = JobRepository { connection };
-                job_repo.update_job(job.id, job_update)?;
+                if job_update.has_changes() || !add_tags.is_empty() || !remove_tags.is_empty() {
+                    let mut job_repo = JobRepository { connection };
+                    job_repo.update_job(job.id, job_update, &add_tags, &remove_tags)?;
+                }
 
                 println!(
                     "{}",
@@ -151,9 +219,117 @@ enum UpdatableField {
     /// Update notes for this application.
     #[strum(to_string = "Notes")]
     Notes,
+    /// Update the work arrangement of this job.
+    #[strum(to_string = "Work Mode")]
+    WorkMode,
+    /// Update the city or region of this job.
+    #[strum(to_string = "Location")]
+    Location,
+    /// Update the country of this job.
+    #[strum(to_string = "Country")]
+    Country,
+    /// Update where this application was found.
+    #[strum(to_string = "Source")]
+    Source,
     /// Update the sprint this job belongs to.
     #[strum(to_string = "Sprint")]
     Sprint,
+    /// Update the tags attached to this application.
+    #[strum(to_string = "Tags")]
+    Tags,
+}
+
+/// The record IDs and values resolved from the `--set-*` flags of `UpdateArgs`.
+pub struct ResolvedUpdates {
+    /// The ID of the new job title.
+    title_id: Option<i32>,
+    /// The ID of the new application status.
+    status_id: Option<i32>,
+    /// The ID of the sprint to move the job application to.
+    sprint_id: Option<i32>,
+    /// The ID of the new application source.
+    source_id: Option<i32>,
+    /// The new work arrangement. An empty string clears it.
+    work_mode: Option<String>,
+}
+
+impl ResolvedUpdates {
+    /// Look up the records referenced by the `--set-*` flags. Titles and sources are created if
+    /// they do not already exist, while statuses and sprints must already exist.
+    pub fn resolve(
+        connection: &mut SqliteConnection,
+        update_args: &UpdateArgs,
+    ) -> Result<Self, FettersError> {
+        let title_id = match &update_args.set_title {
+            Some(title_name) => Some(get_or_add_title_id(connection, title_name)?),
+            None => None,
+        };
+        let status_id = match &update_args.set_status {
+            Some(status_name) => {
+                let mut status_repo = StatusRepository { connection };
+                Some(
+                    status_repo
+                        .get_status_by_name(status_name)?
+                        .ok_or(FettersError::StatusNotFound(status_name.to_string()))?
+                        .id,
+                )
+            }
+            None => None,
+        };
+        let sprint_id = match &update_args.set_sprint {
+            Some(sprint_name) => {
+                let mut sprint_repo = SprintRepository { connection };
+                Some(
+                    sprint_repo
+                        .get_sprint_by_name(sprint_name)?
+                        .ok_or(FettersError::SprintNotFound(sprint_name.to_string()))?
+                        .id,
+                )
+            }
+            None => None,
+        };
+        let source_id = match &update_args.set_source {
+            Some(source_name) => {
+                let mut source_repo = SourceRepository { connection };
+                Some(source_repo.add_source(source_name)?.id)
+            }
+            None => None,
+        };
+        let work_mode = match &update_args.set_work_mode {
+            Some(work_mode) if work_mode.trim().is_empty() => Some("".to_string()),
+            Some(work_mode) => Some(WorkMode::parse(work_mode)?.to_string()),
+            None => None,
+        };
+
+        Ok(Self {
+            title_id,
+            status_id,
+            sprint_id,
+            source_id,
+            work_mode,
+        })
+    }
+
+    /// Build the `JobUpdate` containing every change set with the `--set-*` flags.
+    pub fn job_update<'a>(&'a self, update_args: &'a UpdateArgs) -> JobUpdate<'a> {
+        JobUpdate {
+            company_name: update_args.set_company.as_deref(),
+            title_id: self.title_id,
+            status_id: self.status_id,
+            link: clear_if_empty(update_args.set_link.as_deref()),
+            notes: clear_if_empty(update_args.set_notes.as_deref()),
+            sprint_id: self.sprint_id,
+            location: clear_if_empty(update_args.set_location.as_deref()),
+            country: clear_if_empty(update_args.set_country.as_deref()),
+            work_mode: clear_if_empty(self.work_mode.as_deref()),
+            source_id: self.source_id,
+        }
+    }
+}
+
+/// Map an empty value to `Some(None)` so the field is cleared instead of set to an empty string.
+fn clear_if_empty(value: Option<&str>) -> Option<Option<&str>> {
+    value.map(|value| Some(value).filter(|value| !value.trim().is_empty()))
 }
 
 /// Show an input prompt for text-based fields.
@@ -166,6 +342,9 @@ fn input_prompt(
         UpdatableField::Title => "Enter a new job title:",
         UpdatableField::Link => "Enter a new link to this job listing:",
         UpdatableField::Notes => "Enter new notes for this application:",
+        UpdatableField::Location => "Enter a new city or region for this job:",
+        UpdatableField::Country => "Enter a new country for this job:",
+        UpdatableField::Tags => "Enter comma-separated tags for this application:",
         _ => "Shiiii something went wrong here...",
     };
 
@@ -185,9 +364,14 @@ fn input_prompt(
             {
                 println!("{}", "A new value is required for this field!".red().bold())
             }
-            (Some(input), UpdatableField::Link | UpdatableField::Notes)
-                if input.trim().is_empty() =>
-            {
+            (
+                Some(input),
+                UpdatableField::Link
+                | UpdatableField::Notes
+                | UpdatableField::Location
+                | UpdatableField::Country
+                | UpdatableField::Tags,
+            ) if input.trim().is_empty() => {
                 return Ok("".to_string());
             }
             _ => {
@@ -246,6 +430,53 @@ fn set_new_status(
     }
 }
 
+/// Set a new source for this application.
+fn set_new_source(
+    connection: &mut SqliteConnection,
+    new_source_id: &mut Option<i32>,
+) -> Result<(), FettersError> {
+    let mut source_repo = SourceRepository { connection };
+    let all_sources = source_repo.get_all_sources()?;
+
+    let source_selection = Select::new("Select a new source:", all_sources)
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?;
+
+    if let Some(queried_source) = source_selection {
+        *new_source_id = Some(queried_source.id);
+    }
+
+    Ok(())
+}
+
+/// Set a new work arrangement for this application.
+fn set_new_work_mode(new_work_mode: &mut Option<String>) -> Result<(), FettersError> {
+    let work_mode_selection =
+        Select::new("Select a new work arrangement:", WorkMode::iter().collect())
+            .with_render_config(get_inquire_config())
+            .prompt_skippable()?;
+
+    if let Some(work_mode) = work_mode_selection {
+        *new_work_mode = Some(work_mode.to_string());
+    }
+
+    Ok(())
+}
+
+/// Get the ID of the title matching the given name, creating the title if it does not exist.
+fn get_or_add_title_id(
+    connection: &mut SqliteConnection,
+    title_name: &str,
+) -> Result<i32, FettersError> {
+    Ok(match use_title_by_name(connection, title_name)? {
+        TitleType::NewTitle(new_title) => {
+            let mut title_repo = TitleRepository { connection };
+            title_repo.add_title(NewTitle { name: &new_title })?.id
+        }
+        TitleType::QueriedTitle(queried_title) => queried_title.id,
+    })
+}
+
 /// Set a new title for this application.
 fn set_new_title(
     connection: &mut SqliteConnection,
@@ -21,6 +21,65 @@ pub struct Config {
     pub current_sprint: String,
     /// The path to the SQLite database.
     pub db_path: String,
+    /// Rules for automatically creating follow-up reminders.
+    #[serde(default = "default_reminder_rules")]
+    pub reminder_rules: Vec<ReminderRule>,
+    /// Settings for automatically marking inactive job applications as `GHOSTED`.
+    #[serde(default)]
+    pub sweep: SweepSettings,
+}
+
+/// Defines a rule for automatically creating a follow-up reminder after a job application has
+/// entered a status.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ReminderRule {
+    /// The name of the status that triggers this rule.
+    pub status: String,
+    /// The number of days after entering the status at which the reminder is due.
+    pub days: i64,
+    /// The reminder message.
+    pub message: String,
+}
+
+/// Defines when `PENDING` job applications are considered inactive and marked as `GHOSTED`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SweepSettings {
+    /// The number of days without any activity after which a `PENDING` job application is
+    /// considered ghosted.
+    #[serde(default = "default_inactivity_days")]
+    pub inactivity_days: i64,
+    /// Whether to check for inactive job applications every time `fetters` runs. The check only
+    /// lists them unless `auto_apply` is set.
+    #[serde(default)]
+    pub on_startup: bool,
+    /// Whether the check on startup marks inactive job applications as `GHOSTED` without asking
+    /// for confirmation.
+    #[serde(default)]
+    pub auto_apply: bool,
+}
+
+impl Default for SweepSettings {
+    fn default() -> Self {
+        SweepSettings {
+            inactivity_days: default_inactivity_days(),
+            on_startup: false,
+            auto_apply: false,
+        }
+    }
+}
+
+/// Returns the inactivity threshold used when none is configured.
+fn default_inactivity_days() -> i64 {
+    30
+}
+
+/// Returns the reminder rules used when none are configured.
+fn default_reminder_rules() -> Vec<ReminderRule> {
+    vec![ReminderRule {
+        status: "PENDING".to_string(),
+        days: 7,
+        message: "Follow up on this application".to_string(),
+    }]
 }
 
 impl Config {
@@ -47,6 +106,8 @@ impl Config {
                     .join("fetters.db")
                     .to_string_lossy()
                     .into_owned(),
+                reminder_rules: default_reminder_rules(),
+                sweep: SweepSettings::default(),
             };
             config.save_to_file()?;
 
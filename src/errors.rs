@@ -9,6 +9,19 @@ pub enum FettersError {
     #[error("Could not retrieve system application directories!")]
     ApplicationError,
 
+    /// This error may be raised if an archive contains records that reference records that do not
+    /// exist in the archive.
+    #[error("Archive integrity check failed: {0}")]
+    ArchiveIntegrityError(String),
+
+    /// This error may be raised if the user targets a contact ID that does not exist.
+    #[error("No contact found with ID [{0}]")]
+    ContactNotFound(i32),
+
+    /// Something went wrong when reading or writing CSV.
+    #[error("CSV error: {0}")]
+    CSVError(#[from] csv::Error),
+
     /// Something went wrong when attempting to get the result after creating or updating a job in
     /// SQLite.
     #[error("Diesel query result error: {0}")]
@@ -22,24 +35,149 @@ pub enum FettersError {
     #[error("Inquire error: {0}")]
     InquireError(#[from] inquire::error::InquireError),
 
+    /// This error may be raised if the user provides an amount of money that could not be parsed.
+    #[error(
+        "Invalid amount [{0}]. Use a whole number, optionally with a k or m suffix (ie. 120000 or 120k)."
+    )]
+    InvalidAmount(String),
+
+    /// This error may be raised if an audit log entry cannot be undone because its action, table,
+    /// or snapshot is invalid.
+    #[error("Audit log entry [{0}] is invalid and cannot be undone")]
+    InvalidAuditEntry(i32),
+
+    /// This error may be raised if the user provides a color that is not an ANSI color name or
+    /// a hex color.
+    #[error(
+        "Invalid color [{0}]. Use an ANSI color name (ie. red or bright-green) or a hex color (ie. #ff8800)."
+    )]
+    InvalidColor(String),
+
+    /// This error may be raised if the user provides a date or timestamp that is not in the
+    /// `YYYY-MM-DD` or `YYYY-MM-DD HH:MM:SS` format.
+    #[error("Invalid date [{0}]. Use the YYYY-MM-DD or YYYY-MM-DD HH:MM:SS format.")]
+    InvalidDate(String),
+
+    /// This error may be raised if a user-provided duration could not be parsed.
+    #[error("Invalid duration [{0}]. Use a number followed by h, d, or w (ie. 7d)")]
+    InvalidDuration(String),
+
+    /// This error may be raised if a user-provided sprint goal could not be parsed.
+    #[error(
+        "Invalid goal [{0}]. Use a positive number of applications, optionally per week (ie. 50 or 10/week)."
+    )]
+    InvalidGoal(String),
+
+    /// This error may be raised if the user provides a search query that is not valid full-text
+    /// search syntax.
+    #[error("Invalid search query [{0}]: {1}")]
+    InvalidSearchQuery(String, String),
+
+    /// This error may be raised if a sprint's start date would be after its end date.
+    #[error("Invalid sprint dates. The start date [{0}] must be on or before the end date [{1}].")]
+    InvalidSprintDates(String, String),
+
+    /// This error may be raised if the user provides a work mode that is not remote, hybrid, or
+    /// on-site.
+    #[error("Invalid work mode [{0}]. Use remote, hybrid, or on-site.")]
+    InvalidWorkMode(String),
+
+    /// This error may be raised if the user targets a job ID that does not exist.
+    #[error("No job application found with ID [{0}]")]
+    JobNotFound(i32),
+
+    /// This error may be raised if the user tries to restore a job ID that is not in the trash.
+    #[error("No job application found in the trash with ID [{0}]")]
+    JobNotInTrash(i32),
+
+    /// Something went wrong when serializing or deserializing JSON.
+    #[error("JSON error: {0}")]
+    JSONError(#[from] serde_json::Error),
+
     /// Something fucked up when running the SQLite migrations with `diesel_migrations`.
     #[error("Failed to run migrations!")]
     MigrationFailure,
 
+    /// This error may be raised if a CSV file does not contain a required column.
+    #[error("Missing required column [{0}] in the CSV header")]
+    MissingColumn(String),
+
+    /// This error may be raised if a required column is empty in an imported row.
+    #[error("Missing required value in column [{0}]")]
+    MissingImportField(String),
+
+    /// This error may be raised if a required field was not provided while prompts are disabled.
+    #[error("Missing required field [{0}]. Provide it with the --{0} flag.")]
+    MissingInput(String),
+
     /// This error may be raised if the user tries to update or delete a job, but no job
     /// applications have been tracked for the current sprint.
     #[error("No job applications tracked for the current sprint [{0}]")]
     NoJobsAvailable(String),
 
+    /// This error may be raised if the user runs a bulk update without any `--set-*` or tag flags.
+    #[error("No updates provided. Use the --set-* or tag flags to choose the changes to apply.")]
+    NoUpdatesProvided,
+
+    /// This error may be raised if the user targets a reminder ID that does not exist.
+    #[error("No reminder found with ID [{0}]")]
+    ReminderNotFound(i32),
+
     /// This error may be raised if the user attempts to create two new sprints in the same day,
     /// causing a sprint naming conflict (all sprint names should be unique).
     #[error("There is already a sprint with name {0}. Try renaming the sprint.")]
     SprintNameConflict(String),
 
+    /// This error may be raised if the user tries to delete a sprint that still contains job
+    /// applications without moving them to another sprint.
+    #[error(
+        "Sprint [{0}] contains {1} job application(s). Move them to another sprint with --reassign-to."
+    )]
+    SprintNotEmpty(String, i64),
+
+    /// This error may be raised if the user tries to delete a sprint whose only job applications
+    /// are in the trash without moving them to another sprint.
+    #[error(
+        "Sprint [{0}] has {1} job application(s) in the trash. Purge them with `fetters trash purge` or move them to another sprint with --reassign-to."
+    )]
+    SprintHasTrashedJobs(String, i64),
+
+    /// This error may be raised if the user tries to delete the current sprint without selecting
+    /// a sprint to switch to.
+    #[error(
+        "Sprint [{0}] is the current sprint. Switch to another sprint first or provide one with --reassign-to."
+    )]
+    SprintInUse(String),
+
+    /// This error may be raised if the user references a sprint that does not exist.
+    #[error("No sprint found with name [{0}]")]
+    SprintNotFound(String),
+
     /// Something went wrong when trying to connect to the SQLite database.
     #[error("Failed to connect to SQLite database: {0}")]
     SQLiteConnectionError(#[from] diesel::ConnectionError),
 
+    /// This error may be raised if the user references a status that does not exist.
+    #[error("No status found with name [{0}]")]
+    StatusNotFound(String),
+
+    /// This error may be raised if the user tries to add or rename a status to a name that is
+    /// already taken.
+    #[error("There is already a status with name [{0}]")]
+    StatusNameConflict(String),
+
+    /// This error may be raised if the user tries to remove a status that is still referenced
+    /// by job applications or their status history without providing a replacement.
+    #[error(
+        "Status [{0}] is referenced by {1} record(s). Provide a replacement with --replace-with."
+    )]
+    StatusInUse(String, i64),
+
+    /// This error may be raised if the user tries to rename or remove one of the built-in
+    /// statuses.
+    #[error("Status [{0}] is a built-in status and cannot be renamed or removed.")]
+    StatusIsBuiltIn(String),
+
     /// Something went wrong when deserializing TOML.
     #[error("TOML deserialization error: {0}")]
     TOMLDeserializationError(#[from] toml::de::Error),
@@ -51,4 +189,12 @@ pub enum FettersError {
     /// An unknown error occurred.
     #[error("{0}")]
     UnknownError(String),
+
+    /// This error may be raised if an archive was created by a newer version of `fetters` or has
+    /// an invalid format version.
+    #[error(
+        "Unsupported archive format version [{0}]. This version of fetters restores archive versions 1 to {max}.",
+        max = crate::models::archive::ARCHIVE_FORMAT_VERSION
+    )]
+    UnsupportedArchiveVersion(u32),
 }
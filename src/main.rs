@@ -10,24 +10,56 @@ mod schema;
 mod sqlite;
 mod utils;
 
+use std::io::{IsTerminal, stderr, stdout};
+use std::process::ExitCode;
+
 use clap::Parser;
 use lazy_static::lazy_static;
 use owo_colors::OwoColorize;
 
-use crate::cli::{Cli, Command, ConfigOption, SprintOption};
+use crate::cli::{
+    BulkOption, Cli, Command, ConfigOption, ContactOption, ImportOption, InterviewOption,
+    OffersOption, OutputFormat, SprintOption, StatusOption, SweepArgs, TrashOption,
+};
 use crate::commands::add::add_job;
+use crate::commands::bulk::{bulk_delete_jobs, bulk_update_jobs};
 use crate::commands::config::edit_config;
+use crate::commands::contact::{add_contact, link_contacts, list_contacts, unlink_contacts};
 use crate::commands::delete::delete_job;
+use crate::commands::doctor::run_doctor;
+use crate::commands::export::export_all;
+use crate::commands::history::show_history;
+use crate::commands::import::import_csv;
 use crate::commands::insights::show_insights;
+use crate::commands::interview::{
+    add_interview, delete_interview, list_interviews, update_interview,
+};
 use crate::commands::list::list_jobs;
+use crate::commands::log::show_log;
+use crate::commands::offers::{compare_offers, set_compensation};
 use crate::commands::open::open_application;
+use crate::commands::reminder::{add_reminder, show_due_reminders};
+use crate::commands::restore::restore_database;
+use crate::commands::search::search_jobs;
 use crate::commands::sprint::{
-    create_new_sprint, set_sprint, show_all_sprints, show_current_sprint,
+    create_new_sprint, delete_sprint, edit_sprint, merge_sprints, rename_sprint, set_sprint,
+    show_all_sprints, show_current_sprint,
+};
+use crate::commands::status::{
+    add_status, list_statuses, remove_status, rename_status, reorder_status, set_status_color,
+    set_status_terminal,
 };
+use crate::commands::sweep::sweep_inactive_jobs;
+use crate::commands::trash::{list_trash, purge_trash, restore_trashed_jobs};
+use crate::commands::undo::undo_operations;
 use crate::commands::update::update_job;
 use crate::config::configuration::Config;
 use crate::errors::FettersError;
-use crate::repositories::{sprint::SprintRepository, statuses::StatusRepository};
+use crate::models::audit::STARTUP_SWEEP_COMMAND;
+use crate::repositories::{
+    audit::AuditRepository, source::SourceRepository, sprint::SprintRepository,
+    statuses::StatusRepository,
+};
 use crate::sqlite::Database;
 use crate::utils::migrations::run_migrations;
 
@@ -36,8 +68,28 @@ lazy_static! {
     static ref ASCII_ART: &'static [u8; 695] = include_bytes!("../art.txt");
 }
 
-/// Run `fetters`.
-fn main() -> Result<(), FettersError> {
+/// Run `fetters`. Errors are printed to stderr and exit with a non-zero status code.
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            print_error(&error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Print an error to stderr. The error is only colored if stderr is a terminal.
+fn print_error(error: &FettersError) {
+    if stderr().is_terminal() {
+        eprintln!("{}", error.red().bold());
+    } else {
+        eprintln!("{error}");
+    }
+}
+
+/// Load the configuration and database, then run the command.
+fn run() -> Result<(), FettersError> {
     let config = Config::load_or_create()?;
     let mut database = Database::new_connection(&config.db_path)?;
 
@@ -49,6 +101,12 @@ fn main() -> Result<(), FettersError> {
     };
     status_repo.seed_statuses()?;
 
+    // Ensure the default sources are stored in the `sources` SQLite table.
+    let mut source_repo = SourceRepository {
+        connection: &mut database.connection,
+    };
+    source_repo.seed_sources()?;
+
     let mut sprint_repo = SprintRepository {
         connection: &mut database.connection,
     };
@@ -56,75 +114,264 @@ fn main() -> Result<(), FettersError> {
 
     let cli = Cli::parse();
 
+    // Check for inactive job applications before running the command if enabled. The check never
+    // prompts and is skipped when the output is piped or machine-readable, and for commands that
+    // inspect or restore earlier changes. Inactive job applications are only listed unless
+    // `auto_apply` is set, and changes made by the sweep are skipped by `undo` by default.
+    if config.sweep.on_startup
+        && cli.format == OutputFormat::Table
+        && stdout().is_terminal()
+        && !matches!(
+            cli.command,
+            Command::Doctor(_)
+                | Command::Export(_)
+                | Command::Log(_)
+                | Command::Restore(_)
+                | Command::Sweep(_)
+                | Command::Trash(_)
+                | Command::Undo(_)
+        )
+    {
+        AuditRepository::start_operation(Some(STARTUP_SWEEP_COMMAND));
+
+        if let Err(error) = sweep_inactive_jobs(
+            &mut database.connection,
+            &SweepArgs {
+                days: None,
+                dry_run: !config.sweep.auto_apply,
+                yes: true,
+            },
+            &config.sweep,
+            true,
+        ) {
+            print_error(&error);
+        }
+    }
+
+    AuditRepository::start_operation(None);
     match cli.command {
-        Command::Add { company } => {
-            if let Err(error) = add_job(&mut database.connection, &company, &current_sprint) {
-                println!("{}", error.red().bold());
-            }
+        Command::Add(add_args) => {
+            add_job(&mut database.connection, &add_args, &current_sprint)?;
         }
         Command::Banner => println!("{}", String::from_utf8_lossy(&ASCII_ART[..]).red().bold()),
+        Command::Bulk(bulk_option) => {
+            match bulk_option {
+                BulkOption::Update(mut bulk_update_args) => bulk_update_jobs(
+                    &mut database.connection,
+                    &mut bulk_update_args,
+                    &current_sprint,
+                ),
+                BulkOption::Delete(mut bulk_delete_args) => bulk_delete_jobs(
+                    &mut database.connection,
+                    &mut bulk_delete_args,
+                    &current_sprint,
+                ),
+            }?;
+        }
         Command::Config(config_option) => match config_option {
             ConfigOption::Edit => {
-                if let Err(error) = edit_config() {
-                    println!("{}", error.red().bold());
-                }
+                edit_config()?;
             }
             ConfigOption::Show => {
                 println!("{config:#?}");
             }
         },
-        Command::Delete(mut query_args) => {
-            if let Err(error) =
-                delete_job(&mut database.connection, &mut query_args, &current_sprint)
-            {
-                println!("{}", error.red().bold());
-            }
+        Command::Contact(contact_option) => {
+            match contact_option {
+                ContactOption::Add(contact_args) => {
+                    add_contact(&mut database.connection, &contact_args)
+                }
+                ContactOption::List { company } => {
+                    list_contacts(&mut database.connection, company.as_deref(), cli.format)
+                }
+                ContactOption::Link(mut link_args) => {
+                    link_contacts(&mut database.connection, &mut link_args, &current_sprint)
+                }
+                ContactOption::Unlink(mut link_args) => {
+                    unlink_contacts(&mut database.connection, &mut link_args, &current_sprint)
+                }
+            }?;
+        }
+        Command::Delete(mut delete_args) => {
+            delete_job(&mut database.connection, &mut delete_args, &current_sprint)?;
+        }
+        Command::Doctor(doctor_args) => {
+            run_doctor(&mut database.connection, &doctor_args, &current_sprint)?;
+        }
+        Command::Due(due_args) => {
+            show_due_reminders(
+                &mut database.connection,
+                &due_args,
+                &config.reminder_rules,
+                cli.format,
+            )?;
+        }
+        Command::Export(export_args) => {
+            export_all(&mut database.connection, &export_args)?;
+        }
+        Command::History(mut query_args) => {
+            show_history(&mut database.connection, &mut query_args, &current_sprint)?;
         }
-        Command::Insights => {
-            if let Err(error) = show_insights(&mut database.connection, &current_sprint) {
-                println!("{}", error.red().bold());
+        Command::Import(import_option) => match import_option {
+            ImportOption::Csv(import_args) => {
+                import_csv(&mut database.connection, &import_args, &current_sprint)?;
             }
+        },
+        Command::Insights(insights_args) => {
+            show_insights(
+                &mut database.connection,
+                &insights_args,
+                &current_sprint,
+                cli.format,
+            )?;
+        }
+        Command::Interview(interview_option) => {
+            match interview_option {
+                InterviewOption::Add(mut query_args) => {
+                    add_interview(&mut database.connection, &mut query_args, &current_sprint)
+                }
+                InterviewOption::List(mut query_args) => list_interviews(
+                    &mut database.connection,
+                    &mut query_args,
+                    &current_sprint,
+                    cli.format,
+                ),
+                InterviewOption::Update(mut query_args) => {
+                    update_interview(&mut database.connection, &mut query_args, &current_sprint)
+                }
+                InterviewOption::Delete(mut query_args) => {
+                    delete_interview(&mut database.connection, &mut query_args, &current_sprint)
+                }
+            }?;
         }
         Command::List(query_args) => {
-            if let Err(error) = list_jobs(&mut database.connection, &query_args, &current_sprint) {
-                println!("{}", error.red().bold());
-            }
+            list_jobs(
+                &mut database.connection,
+                &query_args,
+                &current_sprint,
+                cli.format,
+            )?;
+        }
+        Command::Log(log_args) => {
+            show_log(&mut database.connection, &log_args, cli.format)?;
+        }
+        Command::Offers(offers_option) => {
+            match offers_option {
+                OffersOption::Compare => compare_offers(&mut database.connection, cli.format),
+                OffersOption::Set(mut compensation_args) => set_compensation(
+                    &mut database.connection,
+                    &mut compensation_args,
+                    &current_sprint,
+                ),
+            }?;
         }
         Command::Open(mut query_args) => {
-            if let Err(error) =
-                open_application(&mut database.connection, &mut query_args, &current_sprint)
-            {
-                println!("{}", error.red().bold());
-            }
+            open_application(&mut database.connection, &mut query_args, &current_sprint)?;
+        }
+        Command::Remind(mut remind_args) => {
+            add_reminder(&mut database.connection, &mut remind_args, &current_sprint)?;
+        }
+        Command::Restore(restore_args) => {
+            restore_database(&mut database.connection, &restore_args, config)?;
+        }
+        Command::Search(search_args) => {
+            search_jobs(&mut database.connection, &search_args, cli.format)?;
         }
         Command::Sprint(sprint_option) => match sprint_option {
             SprintOption::Current => {
                 show_current_sprint(current_sprint);
             }
-            SprintOption::New { name } => {
-                if let Err(error) =
-                    create_new_sprint(&mut database.connection, &name, config, &current_sprint)
-                {
-                    println!("{}", error.red().bold());
-                }
+            SprintOption::New { name, goal } => {
+                create_new_sprint(
+                    &mut database.connection,
+                    &name,
+                    goal.as_deref(),
+                    config,
+                    &current_sprint,
+                )?;
+            }
+            SprintOption::Edit(edit_args) => {
+                edit_sprint(&mut database.connection, &edit_args, &current_sprint)?;
+            }
+            SprintOption::Rename { name, new_name } => {
+                rename_sprint(&mut database.connection, &name, &new_name, config)?;
+            }
+            SprintOption::Delete {
+                name,
+                reassign_to,
+                yes,
+            } => {
+                delete_sprint(
+                    &mut database.connection,
+                    &name,
+                    reassign_to.as_deref(),
+                    yes,
+                    config,
+                )?;
+            }
+            SprintOption::Merge { from, into, yes } => {
+                merge_sprints(&mut database.connection, &from, &into, yes, config)?;
             }
             SprintOption::ShowAll => {
-                if let Err(error) = show_all_sprints(&mut database.connection) {
-                    println!("{}", error.red().bold());
-                }
+                show_all_sprints(&mut database.connection, cli.format)?;
             }
             SprintOption::Set => {
-                if let Err(error) = set_sprint(&mut database.connection, config, &current_sprint) {
-                    println!("{}", error.red().bold());
-                }
+                set_sprint(&mut database.connection, config, &current_sprint)?;
             }
         },
-        Command::Update(mut query_args) => {
-            if let Err(error) =
-                update_job(&mut database.connection, &mut query_args, &current_sprint)
-            {
-                println!("{}", error.red().bold());
-            }
+        Command::Status(status_option) => {
+            match status_option {
+                StatusOption::Add {
+                    name,
+                    color,
+                    terminal,
+                } => add_status(&mut database.connection, &name, color.as_deref(), terminal),
+                StatusOption::Color { name, color } => {
+                    set_status_color(&mut database.connection, &name, &color)
+                }
+                StatusOption::List => list_statuses(&mut database.connection, cli.format),
+                StatusOption::Remove {
+                    name,
+                    replace_with,
+                    yes,
+                } => remove_status(
+                    &mut database.connection,
+                    &name,
+                    replace_with.as_deref(),
+                    yes,
+                ),
+                StatusOption::Rename { name, new_name } => {
+                    rename_status(&mut database.connection, &name, &new_name)
+                }
+                StatusOption::Reorder { name, position } => {
+                    reorder_status(&mut database.connection, &name, position)
+                }
+                StatusOption::Terminal { name, is_terminal } => {
+                    set_status_terminal(&mut database.connection, &name, is_terminal)
+                }
+            }?;
+        }
+        Command::Sweep(sweep_args) => {
+            sweep_inactive_jobs(&mut database.connection, &sweep_args, &config.sweep, false)?;
+        }
+        Command::Trash(trash_option) => {
+            match trash_option {
+                TrashOption::List { older_than } => {
+                    list_trash(&mut database.connection, older_than.as_deref(), cli.format)
+                }
+                TrashOption::Restore { id, all, yes } => {
+                    restore_trashed_jobs(&mut database.connection, &id, all, yes)
+                }
+                TrashOption::Purge { older_than, yes } => {
+                    purge_trash(&mut database.connection, older_than.as_deref(), yes)
+                }
+            }?;
+        }
+        Command::Undo(undo_args) => {
+            undo_operations(&mut database.connection, &undo_args, config)?;
+        }
+        Command::Update(mut update_args) => {
+            update_job(&mut database.connection, &mut update_args, &current_sprint)?;
         }
     }
 
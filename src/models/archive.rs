@@ -0,0 +1,80 @@
+//! Contains the model for portable database archives.
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{
+    compensation::Compensation,
+    contact::{JobContact, QueriedContact},
+    interview::QueriedInterview,
+    job::QueriedJob,
+    reminder::QueriedReminder,
+    source::QueriedSource,
+    sprint::QueriedSprint,
+    status::QueriedStatus,
+    status_change::QueriedStatusChange,
+    tag::{JobTag, QueriedTag},
+    title::QueriedTitle,
+};
+
+/// The current version of the archive format. Increment this whenever tables or fields are added
+/// to the archive and add an upgrade step for the previous version when restoring.
+///
+/// - Version 1 contains job applications, sprints, statuses, status changes, and titles.
+/// - Version 2 adds interviews, contacts, reminders, compensation details, tags, and sources, as
+///   well as status colors, ordering, and terminal flags, job locations and work modes, sprint
+///   goals, and deletion timestamps.
+/// - Version 3 adds the timestamps at which contacts were linked to job applications.
+pub const ARCHIVE_FORMAT_VERSION: u32 = 3;
+
+/// This struct defines a portable JSON archive containing every table in the database. Record IDs
+/// are only used to resolve references between records and are remapped when the archive is
+/// restored.
+///
+/// All tables default to empty so that archives created before a table was added can still be
+/// restored.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Archive {
+    /// The version of the archive format.
+    pub format_version: u32,
+    /// The timestamp at which this archive was created.
+    pub exported: String,
+    /// The compensation details of all job applications.
+    #[serde(default)]
+    pub compensation: Vec<Compensation>,
+    /// All contacts.
+    #[serde(default)]
+    pub contacts: Vec<QueriedContact>,
+    /// All interviews.
+    #[serde(default)]
+    pub interviews: Vec<QueriedInterview>,
+    /// All links between job applications and contacts.
+    #[serde(default)]
+    pub job_contacts: Vec<JobContact>,
+    /// All links between job applications and tags.
+    #[serde(default)]
+    pub job_tags: Vec<JobTag>,
+    /// All job applications.
+    #[serde(default)]
+    pub jobs: Vec<QueriedJob>,
+    /// All reminders.
+    #[serde(default)]
+    pub reminders: Vec<QueriedReminder>,
+    /// All application sources.
+    #[serde(default)]
+    pub sources: Vec<QueriedSource>,
+    /// All sprints.
+    #[serde(default)]
+    pub sprints: Vec<QueriedSprint>,
+    /// All status changes.
+    #[serde(default)]
+    pub status_changes: Vec<QueriedStatusChange>,
+    /// All statuses.
+    #[serde(default)]
+    pub statuses: Vec<QueriedStatus>,
+    /// All tags.
+    #[serde(default)]
+    pub tags: Vec<QueriedTag>,
+    /// All job titles.
+    #[serde(default)]
+    pub titles: Vec<QueriedTitle>,
+}
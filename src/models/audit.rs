@@ -0,0 +1,154 @@
+//! Contains all models for the audit log.
+
+use diesel::sqlite::Sqlite;
+use diesel::{Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use strum::{Display as StrumDisplay, EnumString};
+use tabled::Tabled;
+
+use crate::models::{
+    compensation::Compensation, contact::JobContact, interview::QueriedInterview, job::QueriedJob,
+    reminder::QueriedReminder, status_change::QueriedStatusChange, tag::JobTag,
+};
+use crate::schema::audit_log;
+
+/// The command recorded for changes made by the sweep that runs on startup. Operations recorded
+/// under it are skipped by `undo` unless they are requested explicitly.
+pub const STARTUP_SWEEP_COMMAND: &str = "sweep (on startup)";
+
+/// The kind of change recorded in the audit log.
+#[derive(Clone, Copy, Debug, EnumString, PartialEq, StrumDisplay)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum AuditAction {
+    /// A record was inserted.
+    Insert,
+    /// A record was updated.
+    Update,
+    /// A record was deleted.
+    Delete,
+}
+
+/// The tables whose changes are recorded in the audit log.
+#[derive(Clone, Copy, Debug, EnumString, PartialEq, StrumDisplay)]
+#[strum(serialize_all = "lowercase")]
+pub enum AuditTable {
+    /// The `jobs` table. Snapshots are stored as `JobSnapshot`s.
+    Jobs,
+    /// The `sprints` table. Snapshots are stored as `QueriedSprint`s.
+    Sprints,
+    /// The `titles` table. Snapshots are stored as `QueriedTitle`s.
+    Titles,
+}
+
+/// This struct defines a new audit log entry that will be written to the `audit_log` table in
+/// SQLite.
+#[derive(Debug, Insertable)]
+#[diesel(table_name = audit_log)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewAuditEntry<'a> {
+    /// The ID shared by all entries written by the same invocation of `fetters`.
+    pub operation_id: i32,
+    /// The timestamp at which the change was made.
+    pub created: &'a str,
+    /// The command that made the change.
+    pub command: &'a str,
+    /// The kind of change (ie. `INSERT`).
+    pub action: &'a str,
+    /// The name of the changed table.
+    pub table_name: &'a str,
+    /// The ID of the changed record.
+    pub record_id: i32,
+    /// A human-readable name for the changed record.
+    pub label: &'a str,
+    /// A JSON snapshot of the record before the change. This is `None` for inserts.
+    pub before: Option<&'a str>,
+    /// A JSON snapshot of the record after the change. This is `None` for deletes.
+    pub after: Option<&'a str>,
+}
+
+/// This struct defines the audit log entry object returned from querying SQLite.
+#[allow(dead_code)]
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = audit_log)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct QueriedAuditEntry {
+    /// The SQLite ID.
+    pub id: i32,
+    /// The ID shared by all entries written by the same invocation of `fetters`.
+    pub operation_id: i32,
+    /// The timestamp at which the change was made.
+    pub created: String,
+    /// The command that made the change.
+    pub command: String,
+    /// The kind of change (ie. `INSERT`).
+    pub action: String,
+    /// The name of the changed table.
+    pub table_name: String,
+    /// The ID of the changed record.
+    pub record_id: i32,
+    /// A human-readable name for the changed record.
+    pub label: String,
+    /// A JSON snapshot of the record before the change. This is `None` for inserts.
+    pub before: Option<String>,
+    /// A JSON snapshot of the record after the change. This is `None` for deletes.
+    pub after: Option<String>,
+    /// Whether the change has been undone.
+    pub undone: bool,
+}
+
+/// This struct defines a snapshot of a job application along with every record attached to it, so
+/// that a deleted job application can be restored in full.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct JobSnapshot {
+    /// The job application.
+    pub job: QueriedJob,
+    /// The status history of the job application.
+    #[serde(default)]
+    pub status_changes: Vec<QueriedStatusChange>,
+    /// The interviews of the job application.
+    #[serde(default)]
+    pub interviews: Vec<QueriedInterview>,
+    /// The reminders of the job application.
+    #[serde(default)]
+    pub reminders: Vec<QueriedReminder>,
+    /// The compensation details of the job application.
+    #[serde(default)]
+    pub compensation: Option<Compensation>,
+    /// The links between the job application and its contacts.
+    #[serde(default)]
+    pub job_contacts: Vec<JobContact>,
+    /// The links between the job application and its tags.
+    #[serde(default)]
+    pub job_tags: Vec<JobTag>,
+}
+
+/// Contains every change made by a single invocation of `fetters`. This is used when displaying
+/// the audit log in a table.
+#[derive(Debug, Serialize, Tabled)]
+pub struct LoggedOperation {
+    /// The operation ID.
+    #[tabled(rename = "ID")]
+    pub id: i32,
+    /// The timestamp of the first change in this operation.
+    #[tabled(rename = "Time")]
+    pub created: String,
+    /// The command that made the changes.
+    #[tabled(rename = "Command")]
+    pub command: String,
+    /// A description of each change, one per line.
+    #[tabled(rename = "Changes")]
+    pub changes: String,
+    /// Whether the operation has been undone.
+    #[tabled(rename = "Undone")]
+    #[tabled(display("display_undone"))]
+    pub undone: bool,
+}
+
+/// Display whether an operation has been undone.
+fn display_undone(undone: &bool) -> String {
+    if *undone {
+        "yes".to_string()
+    } else {
+        "no".to_string()
+    }
+}
@@ -0,0 +1,127 @@
+//! Contains all models for the compensation details of job applications.
+
+use diesel::sqlite::Sqlite;
+use diesel::{Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use tabled::Tabled;
+use tabled::derive::display;
+
+use crate::schema::compensation;
+
+/// This struct defines the compensation details of a job application that are written to and
+/// queried from the `compensation` table in SQLite. All amounts are yearly and stored in whole
+/// units of the currency.
+#[derive(Clone, Debug, Default, Deserialize, Insertable, Queryable, Selectable, Serialize)]
+#[diesel(table_name = compensation)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct Compensation {
+    /// The job ID. References the record ID in SQLite.
+    pub job_id: i32,
+    /// The lower end of the salary range posted in the job listing.
+    pub posted_min: Option<i64>,
+    /// The upper end of the salary range posted in the job listing.
+    pub posted_max: Option<i64>,
+    /// The salary you asked for.
+    pub expected: Option<i64>,
+    /// The offered base salary.
+    pub offered_base: Option<i64>,
+    /// The offered yearly bonus.
+    pub offered_bonus: Option<i64>,
+    /// The yearly value of the offered equity.
+    pub offered_equity: Option<i64>,
+    /// The currency of all amounts (ie. USD).
+    pub currency: Option<String>,
+    /// The date by which the offer must be accepted.
+    pub offer_deadline: Option<String>,
+    /// Notes about the offered benefits.
+    pub benefits: Option<String>,
+}
+
+impl Compensation {
+    /// Returns the total yearly compensation of the offer, or `None` if no base salary was
+    /// offered. The total saturates instead of overflowing on very large amounts.
+    pub fn total_offered(&self) -> Option<i64> {
+        self.offered_base.map(|base| {
+            base.saturating_add(self.offered_bonus.unwrap_or(0))
+                .saturating_add(self.offered_equity.unwrap_or(0))
+        })
+    }
+}
+
+/// This struct defines an offer with details about its job application and is used when comparing
+/// offers side by side.
+#[derive(Debug, Serialize, Tabled)]
+pub struct OfferComparison {
+    /// The name of the company.
+    #[tabled(rename = "Company Name")]
+    pub company_name: String,
+    /// The job ID. References the record ID in SQLite.
+    #[tabled(rename = "Job ID")]
+    pub job_id: i32,
+    /// The job title.
+    #[tabled(rename = "Title")]
+    pub title: String,
+    /// The application status.
+    #[tabled(rename = "Status")]
+    pub status: String,
+    /// The currency of all amounts.
+    #[tabled(rename = "Currency")]
+    #[tabled(display("display::option", "N/A"))]
+    pub currency: Option<String>,
+    /// The offered base salary.
+    #[tabled(rename = "Base")]
+    #[tabled(display("display_amount"))]
+    pub offered_base: Option<i64>,
+    /// The offered yearly bonus.
+    #[tabled(rename = "Bonus")]
+    #[tabled(display("display_amount"))]
+    pub offered_bonus: Option<i64>,
+    /// The yearly value of the offered equity.
+    #[tabled(rename = "Equity / Year")]
+    #[tabled(display("display_amount"))]
+    pub offered_equity: Option<i64>,
+    /// The total yearly compensation.
+    #[tabled(rename = "Total / Year")]
+    #[tabled(display("display_amount"))]
+    pub total: Option<i64>,
+    /// The posted salary range.
+    #[tabled(rename = "Posted Range")]
+    #[tabled(display("display::option", "N/A"))]
+    pub posted_range: Option<String>,
+    /// The salary you asked for.
+    #[tabled(rename = "Expected")]
+    #[tabled(display("display_amount"))]
+    pub expected: Option<i64>,
+    /// The date by which the offer must be accepted.
+    #[tabled(rename = "Deadline")]
+    #[tabled(display("display::option", "N/A"))]
+    pub offer_deadline: Option<String>,
+    /// Notes about the offered benefits.
+    #[tabled(rename = "Benefits")]
+    #[tabled(display("display::option", "N/A"))]
+    pub benefits: Option<String>,
+}
+
+/// Format an amount with thousands separators (ie. `120,000`).
+pub fn format_amount(amount: i64) -> String {
+    let digits = amount.unsigned_abs().to_string();
+    let mut formatted = String::new();
+
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            formatted.push(',');
+        }
+        formatted.push(digit);
+    }
+
+    if amount < 0 {
+        format!("-{formatted}")
+    } else {
+        formatted
+    }
+}
+
+/// Display an optional amount with thousands separators in a table.
+fn display_amount(amount: &Option<i64>) -> String {
+    amount.map(format_amount).unwrap_or("N/A".to_string())
+}
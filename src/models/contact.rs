@@ -0,0 +1,98 @@
+//! Contains all models for contacts associated with job applications.
+
+use std::fmt::{self, Display, Formatter};
+
+use diesel::sqlite::Sqlite;
+use diesel::{Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use tabled::Tabled;
+use tabled::derive::display;
+
+use crate::schema::{contacts, job_contacts};
+
+/// This struct defines a new contact that will be written to the `contacts` table in SQLite.
+#[derive(Debug, Insertable)]
+#[diesel(table_name = contacts)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewContact<'a> {
+    /// The name of the contact.
+    pub name: &'a str,
+    /// The email address of the contact.
+    pub email: Option<&'a str>,
+    /// The phone number of the contact.
+    pub phone: Option<&'a str>,
+    /// The role of the contact (ie. recruiter or hiring manager).
+    pub role: Option<&'a str>,
+    /// The company the contact works for.
+    pub company: Option<&'a str>,
+    /// The LinkedIn profile URL of the contact.
+    pub linkedin_url: Option<&'a str>,
+    /// Any notes about this contact.
+    pub notes: Option<&'a str>,
+}
+
+/// This struct defines the contact object returned from querying SQLite.
+#[derive(Debug, Deserialize, Queryable, Selectable, Serialize, Tabled)]
+#[diesel(table_name = contacts)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct QueriedContact {
+    /// The SQLite ID.
+    #[tabled(rename = "ID")]
+    pub id: i32,
+    /// The name of the contact.
+    #[tabled(rename = "Name")]
+    pub name: String,
+    /// The email address of the contact.
+    #[tabled(rename = "Email")]
+    #[tabled(display("display::option", "N/A"))]
+    pub email: Option<String>,
+    /// The phone number of the contact.
+    #[tabled(rename = "Phone")]
+    #[tabled(display("display::option", "N/A"))]
+    pub phone: Option<String>,
+    /// The role of the contact (ie. recruiter or hiring manager).
+    #[tabled(rename = "Role")]
+    #[tabled(display("display::option", "N/A"))]
+    pub role: Option<String>,
+    /// The company the contact works for.
+    #[tabled(rename = "Company")]
+    #[tabled(display("display::option", "N/A"))]
+    pub company: Option<String>,
+    /// The LinkedIn profile URL of the contact.
+    #[tabled(rename = "LinkedIn")]
+    #[tabled(display("display::option", "N/A"))]
+    pub linkedin_url: Option<String>,
+    /// Any notes about this contact.
+    #[tabled(rename = "Notes")]
+    #[tabled(display("display::option", "N/A"))]
+    pub notes: Option<String>,
+}
+
+/// Implementing `Display` allows this struct to be displayed in the `MultiSelect` Inquire menu.
+impl Display for QueriedContact {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} | {} | {}",
+            self.name,
+            self.role.clone().unwrap_or("N/A".to_string()),
+            self.company.clone().unwrap_or("N/A".to_string())
+        )
+    }
+}
+
+/// This struct defines a link between a job application and a contact in the `job_contacts`
+/// table.
+#[derive(Debug, Deserialize, Insertable, Queryable, Selectable, Serialize)]
+#[diesel(table_name = job_contacts)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct JobContact {
+    /// The job ID. References the record ID in SQLite.
+    pub job_id: i32,
+    /// The contact ID. References the record ID in SQLite.
+    pub contact_id: i32,
+    /// The timestamp at which the contact was linked. Links created before timestamps were
+    /// recorded have none.
+    #[serde(default)]
+    pub linked: Option<String>,
+}
@@ -1,11 +1,16 @@
 //! Contains all models for job inslghts.
 
+use serde::Serialize;
 use tabled::Tabled;
 
 /// Contains the name of the field, the total count for the field, as well as the percentage over
 /// the total number of jobs or in the target sprint.
-#[derive(Debug, Tabled)]
+#[derive(Debug, Serialize, Tabled)]
 pub struct CountAndPercentage {
+    /// The category this field belongs to (ie. `status` or `sprint`). This is omitted from colored
+    /// tables, which display each category separately.
+    #[tabled(rename = "Category")]
+    pub category: String,
     /// The name of the field.
     #[tabled(rename = "Label")]
     pub label: String,
@@ -19,3 +24,131 @@ pub struct CountAndPercentage {
     #[tabled(rename = "% Overall")]
     pub overall_percentage: String,
 }
+
+/// Contains the number of job applications from a source and how many of them received a
+/// response or an offer.
+#[derive(Debug, Serialize, Tabled)]
+pub struct SourceInsight {
+    /// The name of the source.
+    #[tabled(rename = "Source")]
+    pub source: String,
+    /// The count of job applications from the source.
+    #[tabled(rename = "# of Jobs")]
+    pub count: i64,
+    /// The count of job applications that received a response.
+    #[tabled(rename = "# of Responses")]
+    pub responses: i64,
+    /// The percentage of job applications that received a response.
+    #[tabled(rename = "Response Rate")]
+    pub response_rate: String,
+    /// The count of job applications that received an offer.
+    #[tabled(rename = "# of Offers")]
+    pub offers: i64,
+    /// The percentage of job applications that received an offer.
+    #[tabled(rename = "Offer Rate")]
+    pub offer_rate: String,
+}
+
+/// Contains the number of job applications that reached a stage of the application funnel, the
+/// conversion from the previous stage, and the number of job applications that were ghosted or
+/// rejected after reaching this stage.
+#[derive(Debug, Serialize, Tabled)]
+pub struct FunnelStage {
+    /// The scope of the funnel (ie. `current sprint` or `overall`). This is only included in
+    /// machine-readable output formats.
+    #[tabled(skip)]
+    pub scope: String,
+    /// The name of the stage.
+    #[tabled(rename = "Stage")]
+    pub stage: String,
+    /// The count of job applications that reached the stage.
+    #[tabled(rename = "# of Jobs")]
+    pub count: i64,
+    /// The percentage of all job applications that reached the stage.
+    #[tabled(rename = "% of Applied")]
+    pub applied_percentage: String,
+    /// The percentage of job applications in the previous stage that reached this stage.
+    #[tabled(rename = "Conversion")]
+    pub conversion_rate: String,
+    /// The count of job applications that were ghosted after reaching this stage.
+    #[tabled(rename = "Ghosted")]
+    pub ghosted: i64,
+    /// The count of job applications that were rejected after reaching this stage.
+    #[tabled(rename = "Rejected")]
+    pub rejected: i64,
+}
+
+/// Contains the number of job applications created within a period of time.
+#[derive(Debug, Serialize, Tabled)]
+pub struct TimelineCount {
+    /// The start of the period (ie. `2026-10-12` or `2026-10` when grouping by month).
+    #[tabled(rename = "Period")]
+    pub period: String,
+    /// The count of job applications created within the period.
+    #[tabled(rename = "# of Jobs")]
+    pub count: i64,
+}
+
+/// Contains statistics about the number of days job applications spent between two events, such
+/// as applying and receiving a first response or moving from one status to another.
+#[derive(Debug, Serialize, Tabled)]
+pub struct DurationStats {
+    /// The category the statistics are grouped by (ie. `overall`, `company`, or `title`).
+    #[tabled(rename = "Group")]
+    pub category: String,
+    /// The company name or title the statistics belong to.
+    #[tabled(rename = "Name")]
+    pub name: String,
+    /// The stage that was measured (ie. `FIRST RESPONSE` or `PENDING -> IN PROGRESS`).
+    #[tabled(rename = "Stage")]
+    pub stage: String,
+    /// The number of measured durations.
+    #[tabled(rename = "Samples")]
+    pub samples: i64,
+    /// The shortest duration in days.
+    #[tabled(rename = "Min")]
+    pub min_days: f64,
+    /// The median duration in days.
+    #[tabled(rename = "Median")]
+    pub median_days: f64,
+    /// The mean duration in days.
+    #[tabled(rename = "Mean")]
+    pub mean_days: f64,
+    /// The 90th percentile duration in days.
+    #[tabled(rename = "P90")]
+    pub p90_days: f64,
+}
+
+/// Contains the stage duration statistics together with the slow applications. This is used when
+/// printing the stage durations as JSON.
+#[derive(Debug, Serialize)]
+pub struct DurationInsights<'a> {
+    /// The duration statistics for each stage.
+    pub durations: &'a [DurationStats],
+    /// The job applications that took much longer than the historical median of a stage.
+    pub outliers: &'a [DurationOutlier],
+}
+
+/// Contains a job application that took much longer than the historical median to move through a
+/// stage.
+#[derive(Debug, Serialize, Tabled)]
+pub struct DurationOutlier {
+    /// The job ID.
+    #[tabled(rename = "ID")]
+    pub job_id: i32,
+    /// The company name.
+    #[tabled(rename = "Company")]
+    pub company_name: String,
+    /// The job title.
+    #[tabled(rename = "Title")]
+    pub title: String,
+    /// The stage that took longer than usual.
+    #[tabled(rename = "Stage")]
+    pub stage: String,
+    /// The number of days the job application spent in the stage.
+    #[tabled(rename = "Days")]
+    pub days: f64,
+    /// The historical median number of days for the stage.
+    #[tabled(rename = "Median")]
+    pub median_days: f64,
+}
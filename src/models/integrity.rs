@@ -0,0 +1,109 @@
+//! Contains all models for database integrity checks.
+
+use tabled::Tabled;
+
+use crate::models::{sprint::QueriedSprint, title::QueriedTitle};
+
+/// Contains all integrity problems found in the database.
+#[derive(Debug, Default)]
+pub struct IntegrityReport {
+    /// Sprints whose `num_jobs` count does not match the number of job applications they contain,
+    /// along with the actual number of job applications.
+    pub drifted_sprints: Vec<(QueriedSprint, i64)>,
+    /// Titles that are not referenced by any job application.
+    pub orphaned_titles: Vec<QueriedTitle>,
+    /// Job applications referencing a sprint that does not exist as (job ID, company name, sprint
+    /// ID).
+    pub jobs_missing_sprints: Vec<(i32, String, i32)>,
+    /// Job applications referencing a status that does not exist as (job ID, company name, status
+    /// ID).
+    pub jobs_missing_statuses: Vec<(i32, String, i32)>,
+    /// Job applications whose status history references a status that does not exist as (job ID,
+    /// company name, status ID).
+    pub history_missing_statuses: Vec<(i32, String, i32)>,
+}
+
+impl IntegrityReport {
+    /// Returns `true` if no integrity problems were found.
+    pub fn is_empty(&self) -> bool {
+        self.drifted_sprints.is_empty()
+            && self.orphaned_titles.is_empty()
+            && self.jobs_missing_sprints.is_empty()
+            && self.jobs_missing_statuses.is_empty()
+            && self.history_missing_statuses.is_empty()
+    }
+
+    /// List every integrity problem along with how it will be repaired.
+    pub fn issues(&self, fallback_sprint: &str, fallback_status: &str) -> Vec<IntegrityIssue> {
+        let drifted_sprints = self
+            .drifted_sprints
+            .iter()
+            .map(|(sprint, actual)| IntegrityIssue {
+                check: "Sprint job count".to_string(),
+                record: format!("Sprint [{}]", sprint.name),
+                problem: format!(
+                    "Stores {} job(s) but contains {actual} job(s)",
+                    sprint.num_jobs
+                ),
+                repair: "Recalculate the job count".to_string(),
+            });
+        let orphaned_titles = self.orphaned_titles.iter().map(|title| IntegrityIssue {
+            check: "Orphaned title".to_string(),
+            record: format!("Title [{}]", title.name),
+            problem: "Not used by any job application".to_string(),
+            repair: "Delete the title".to_string(),
+        });
+        let jobs_missing_sprints =
+            self.jobs_missing_sprints
+                .iter()
+                .map(|(job_id, company_name, sprint_id)| IntegrityIssue {
+                    check: "Missing sprint".to_string(),
+                    record: format!("Job {job_id} ({company_name})"),
+                    problem: format!("References missing sprint {sprint_id}"),
+                    repair: format!("Move to sprint [{fallback_sprint}]"),
+                });
+        let jobs_missing_statuses =
+            self.jobs_missing_statuses
+                .iter()
+                .map(|(job_id, company_name, status_id)| IntegrityIssue {
+                    check: "Missing status".to_string(),
+                    record: format!("Job {job_id} ({company_name})"),
+                    problem: format!("References missing status {status_id}"),
+                    repair: format!("Set status to [{fallback_status}]"),
+                });
+        let history_missing_statuses =
+            self.history_missing_statuses
+                .iter()
+                .map(|(job_id, company_name, status_id)| IntegrityIssue {
+                    check: "Missing history status".to_string(),
+                    record: format!("Job {job_id} ({company_name})"),
+                    problem: format!("Status history references missing status {status_id}"),
+                    repair: format!("Replace with [{fallback_status}]"),
+                });
+
+        drifted_sprints
+            .chain(orphaned_titles)
+            .chain(jobs_missing_sprints)
+            .chain(jobs_missing_statuses)
+            .chain(history_missing_statuses)
+            .collect()
+    }
+}
+
+/// Contains a single integrity problem and how it will be repaired. This is used when displaying
+/// the results of `fetters doctor` in a table.
+#[derive(Debug, Tabled)]
+pub struct IntegrityIssue {
+    /// The name of the check that found the problem.
+    #[tabled(rename = "Check")]
+    pub check: String,
+    /// The affected record.
+    #[tabled(rename = "Record")]
+    pub record: String,
+    /// A description of the problem.
+    #[tabled(rename = "Problem")]
+    pub problem: String,
+    /// A description of the repair.
+    #[tabled(rename = "Repair")]
+    pub repair: String,
+}
@@ -0,0 +1,162 @@
+//! Contains all models for job interviews.
+
+use std::fmt::{self, Display, Formatter};
+
+use diesel::sqlite::Sqlite;
+use diesel::{AsChangeset, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use strum::{Display as StrumDisplay, EnumIter};
+use tabled::Tabled;
+use tabled::derive::display;
+
+use crate::schema::interviews;
+
+/// Contains all types of interviews users can choose from.
+#[derive(Debug, EnumIter, StrumDisplay)]
+pub enum InterviewType {
+    /// A phone or recruiter screen.
+    #[strum(to_string = "PHONE")]
+    Phone,
+    /// A technical interview (ie. live coding or system design).
+    #[strum(to_string = "TECHNICAL")]
+    Technical,
+    /// A behavioral interview.
+    #[strum(to_string = "BEHAVIORAL")]
+    Behavioral,
+    /// A take-home assignment.
+    #[strum(to_string = "TAKE-HOME")]
+    TakeHome,
+    /// An onsite interview.
+    #[strum(to_string = "ONSITE")]
+    Onsite,
+    /// Any other type of interview.
+    #[strum(to_string = "OTHER")]
+    Other,
+}
+
+/// Contains all outcomes of an interview round.
+#[derive(Debug, EnumIter, StrumDisplay)]
+pub enum InterviewOutcome {
+    /// The interview has not happened yet or the result is not known yet.
+    #[strum(to_string = "PENDING")]
+    Pending,
+    /// The interview was passed.
+    #[strum(to_string = "PASSED")]
+    Passed,
+    /// The interview was failed.
+    #[strum(to_string = "FAILED")]
+    Failed,
+    /// The interview was cancelled.
+    #[strum(to_string = "CANCELLED")]
+    Cancelled,
+}
+
+/// This struct defines a new interview that will be written to the `interviews` table in SQLite.
+#[derive(Debug, Insertable)]
+#[diesel(table_name = interviews)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewInterview<'a> {
+    /// The job ID. References the record ID in SQLite.
+    pub job_id: i32,
+    /// The name of the interview round.
+    pub round: &'a str,
+    /// The type of interview.
+    pub interview_type: &'a str,
+    /// The timestamp at which the interview is scheduled.
+    pub scheduled: Option<&'a str>,
+    /// The names of the interviewers.
+    pub interviewers: Option<&'a str>,
+    /// The outcome of the interview.
+    pub outcome: &'a str,
+    /// Any notes about this interview.
+    pub notes: Option<&'a str>,
+}
+
+/// This struct defines the interview object returned from querying SQLite.
+#[derive(Debug, Deserialize, Insertable, Queryable, Selectable, Serialize)]
+#[diesel(table_name = interviews)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct QueriedInterview {
+    /// The SQLite ID.
+    pub id: i32,
+    /// The job ID. References the record ID in SQLite.
+    pub job_id: i32,
+    /// The name of the interview round.
+    pub round: String,
+    /// The type of interview.
+    pub interview_type: String,
+    /// The timestamp at which the interview is scheduled.
+    pub scheduled: Option<String>,
+    /// The names of the interviewers.
+    pub interviewers: Option<String>,
+    /// The outcome of the interview.
+    pub outcome: String,
+    /// Any notes about this interview.
+    pub notes: Option<String>,
+}
+
+/// Implementing `Display` allows this struct to be displayed in the `Select` Inquire menu.
+impl Display for QueriedInterview {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}) | Scheduled: {} | Outcome: {}",
+            self.round,
+            self.interview_type,
+            self.scheduled.clone().unwrap_or("N/A".to_string()),
+            self.outcome
+        )
+    }
+}
+
+/// This struct defines an updated interview that will overwrite an existing one in SQLite.
+#[derive(Debug, Default, AsChangeset)]
+#[diesel(table_name = interviews)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct InterviewUpdate<'a> {
+    /// The name of the interview round.
+    pub round: Option<&'a str>,
+    /// The type of interview.
+    pub interview_type: Option<&'a str>,
+    /// The timestamp at which the interview is scheduled.
+    pub scheduled: Option<Option<&'a str>>,
+    /// The names of the interviewers.
+    pub interviewers: Option<Option<&'a str>>,
+    /// The outcome of the interview.
+    pub outcome: Option<&'a str>,
+    /// Any notes about this interview.
+    pub notes: Option<Option<&'a str>>,
+}
+
+/// This struct defines an interview with the company name of its job application and is used when
+/// displaying interviews in tables.
+#[derive(Debug, Queryable, Serialize, Tabled)]
+pub struct TabledInterview {
+    /// The SQLite ID.
+    #[tabled(rename = "ID")]
+    pub id: i32,
+    /// The name of the company.
+    #[tabled(rename = "Company Name")]
+    pub company_name: String,
+    /// The name of the interview round.
+    #[tabled(rename = "Round")]
+    pub round: String,
+    /// The type of interview.
+    #[tabled(rename = "Type")]
+    pub interview_type: String,
+    /// The timestamp at which the interview is scheduled.
+    #[tabled(rename = "Scheduled")]
+    #[tabled(display("display::option", "N/A"))]
+    pub scheduled: Option<String>,
+    /// The names of the interviewers.
+    #[tabled(rename = "Interviewers")]
+    #[tabled(display("display::option", "N/A"))]
+    pub interviewers: Option<String>,
+    /// The outcome of the interview.
+    #[tabled(rename = "Outcome")]
+    pub outcome: String,
+    /// Any notes about this interview.
+    #[tabled(rename = "Notes")]
+    #[tabled(display("display::option", "N/A"))]
+    pub notes: Option<String>,
+}
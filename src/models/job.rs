@@ -1,18 +1,23 @@
 //! Contains all models for job applications.
 
 use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
 
 use diesel::sqlite::Sqlite;
 use diesel::{AsChangeset, Insertable, Queryable, Selectable};
 use owo_colors::OwoColorize;
+use serde::{Deserialize, Serialize};
+use strum::{Display as StrumDisplay, EnumIter, EnumString};
 use tabled::Tabled;
 use tabled::derive::display;
 
+use crate::errors::FettersError;
 use crate::schema::jobs;
+use crate::utils::colors::paint;
 
 /// This struct defines the job object returned from querying SQLite.
 #[allow(dead_code)]
-#[derive(Debug, Queryable, Selectable)]
+#[derive(Debug, Deserialize, Insertable, Queryable, Selectable, Serialize)]
 #[diesel(table_name = jobs)]
 #[diesel(check_for_backend(Sqlite))]
 pub struct QueriedJob {
@@ -32,6 +37,22 @@ pub struct QueriedJob {
     pub notes: Option<String>,
     /// The sprint ID. References the record ID in SQLite.
     pub sprint_id: i32,
+    /// The city or region of the job.
+    #[serde(default)]
+    pub location: Option<String>,
+    /// The country of the job.
+    #[serde(default)]
+    pub country: Option<String>,
+    /// The work arrangement of the job (ie. `REMOTE`).
+    #[serde(default)]
+    pub work_mode: Option<String>,
+    /// The source ID. References the record ID in SQLite.
+    #[serde(default)]
+    pub source_id: Option<i32>,
+    /// The timestamp at which this job application was moved to the trash. This is `None` unless
+    /// the job application was deleted.
+    #[serde(default)]
+    pub deleted_at: Option<String>,
 }
 
 /// This struct defines a new job application that will be inserted into SQLite.
@@ -53,10 +74,21 @@ pub struct NewJob<'a> {
     pub notes: Option<&'a str>,
     /// The sprint ID. References the record ID in SQLite.
     pub sprint_id: i32,
+    /// The city or region of the job.
+    pub location: Option<&'a str>,
+    /// The country of the job.
+    pub country: Option<&'a str>,
+    /// The work arrangement of the job.
+    pub work_mode: Option<&'a str>,
+    /// The source ID. References the record ID in SQLite.
+    pub source_id: Option<i32>,
+    /// The timestamp at which this job application was moved to the trash.
+    pub deleted_at: Option<&'a str>,
 }
 
 /// This struct defines an updated job application that will overwrite an existing one in SQLite.
-#[derive(Debug, Default, AsChangeset)]
+/// Optional fields set to `Some(None)` are cleared.
+#[derive(Clone, Debug, Default, AsChangeset)]
 #[diesel(table_name = jobs)]
 #[diesel(check_for_backend(Sqlite))]
 pub struct JobUpdate<'a> {
@@ -67,17 +99,41 @@ pub struct JobUpdate<'a> {
     /// The application status ID. References the record ID in SQLite.
     pub status_id: Option<i32>,
     /// The link to the job application.
-    pub link: Option<&'a str>,
+    pub link: Option<Option<&'a str>>,
     /// Any notes about this job application.
-    pub notes: Option<&'a str>,
+    pub notes: Option<Option<&'a str>>,
     /// The sprint ID. References the record ID in SQLite.
     pub sprint_id: Option<i32>,
+    /// The city or region of the job.
+    pub location: Option<Option<&'a str>>,
+    /// The country of the job.
+    pub country: Option<Option<&'a str>>,
+    /// The work arrangement of the job.
+    pub work_mode: Option<Option<&'a str>>,
+    /// The source ID. References the record ID in SQLite.
+    pub source_id: Option<i32>,
+}
+
+impl JobUpdate<'_> {
+    /// Returns whether any field of the job application was changed.
+    pub fn has_changes(&self) -> bool {
+        self.company_name.is_some()
+            || self.title_id.is_some()
+            || self.status_id.is_some()
+            || self.link.is_some()
+            || self.notes.is_some()
+            || self.sprint_id.is_some()
+            || self.location.is_some()
+            || self.country.is_some()
+            || self.work_mode.is_some()
+            || self.source_id.is_some()
+    }
 }
 
 /// This struct defines a job application with the title, status, and sprint name after querying
 /// SQLite for those fields based on their record IDs and is used when displaying job applications
 /// in tables.
-#[derive(Clone, Debug, Queryable, Tabled)]
+#[derive(Clone, Debug, Queryable, Serialize, Tabled)]
 pub struct TabledJob {
     /// The SQLite ID.
     #[tabled(rename = "ID")]
@@ -104,29 +160,36 @@ pub struct TabledJob {
     #[tabled(rename = "Notes")]
     #[tabled(display("display::option", "N/A"))]
     pub notes: Option<String>,
+    /// The work arrangement of the job.
+    #[tabled(rename = "Work Mode")]
+    #[tabled(display("display::option", "N/A"))]
+    pub work_mode: Option<String>,
+    /// The city or region of the job.
+    #[tabled(rename = "Location")]
+    #[tabled(display("display::option", "N/A"))]
+    pub location: Option<String>,
+    /// The country of the job.
+    #[tabled(rename = "Country")]
+    #[tabled(display("display::option", "N/A"))]
+    pub country: Option<String>,
+    /// The source of the job application.
+    #[tabled(rename = "Source")]
+    #[tabled(display("display::option", "N/A"))]
+    pub source: Option<String>,
+    /// The tags attached to this job application, separated by commas.
+    #[tabled(rename = "Tags")]
+    #[tabled(display("display::option", "N/A"))]
+    pub tags: Option<String>,
+    /// The color of the application status.
+    #[serde(skip)]
+    #[tabled(skip)]
+    pub status_color: Option<String>,
 }
 
 impl TabledJob {
-    /// Colorize a string based on the `status` field of the job application.
+    /// Colorize a string with the color of the job application's status.
     fn colorize_field(&self, field_name: &str) -> String {
-        if let Some(ref status) = self.status {
-            match status {
-                val if val == "GHOSTED" => {
-                    return field_name.white().bold().to_string();
-                }
-                val if val == "HIRED" => return field_name.green().bold().to_string(),
-                val if val == "IN PROGRESS" => return field_name.yellow().bold().to_string(),
-                val if val == "NOT HIRING ANYMORE" => {
-                    return field_name.fg_rgb::<201, 201, 201>().to_string();
-                }
-                val if val == "OFFER RECEIVED" => return field_name.magenta().bold().to_string(),
-                val if val == "PENDING" => return field_name.blue().bold().to_string(),
-                val if val == "REJECTED" => return field_name.red().bold().to_string(),
-                _ => return field_name.to_string(),
-            }
-        }
-
-        field_name.to_string()
+        paint(field_name, self.status_color.as_deref())
     }
 }
 
@@ -142,3 +205,71 @@ impl Display for TabledJob {
         )
     }
 }
+
+/// This struct defines a job application in the trash and is used when displaying the trash in a
+/// table.
+#[derive(Clone, Debug, Queryable, Serialize, Tabled)]
+pub struct TrashedJob {
+    /// The SQLite ID.
+    #[tabled(rename = "ID")]
+    pub id: i32,
+    /// The timestamp at which this job application was moved to the trash.
+    #[tabled(rename = "Deleted")]
+    pub deleted_at: String,
+    /// The name of the company.
+    #[tabled(rename = "Company Name")]
+    pub company_name: String,
+    /// The job title.
+    #[tabled(rename = "Title")]
+    #[tabled(display("display::option", "N/A"))]
+    pub title: Option<String>,
+    /// The application status.
+    #[tabled(rename = "Status")]
+    #[tabled(display("display::option", "N/A"))]
+    pub status: Option<String>,
+    /// The name of the sprint the job application belongs to.
+    #[tabled(rename = "Sprint")]
+    #[tabled(display("display::option", "N/A"))]
+    pub sprint: Option<String>,
+}
+
+impl Display for TrashedJob {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ID: {} | Company: {} | Title: {} | Deleted: {}",
+            self.id.white().bold(),
+            self.company_name,
+            self.title.clone().unwrap_or("".to_string()),
+            self.deleted_at
+        )
+    }
+}
+
+/// This enum contains all work arrangements a job application can have.
+#[derive(Clone, Copy, Debug, EnumIter, EnumString, PartialEq, StrumDisplay)]
+#[strum(ascii_case_insensitive)]
+pub enum WorkMode {
+    /// The job is fully remote.
+    #[strum(to_string = "REMOTE")]
+    Remote,
+    /// The job is partially remote.
+    #[strum(to_string = "HYBRID")]
+    Hybrid,
+    /// The job is in the office.
+    #[strum(
+        to_string = "ON-SITE",
+        serialize = "onsite",
+        serialize = "on site",
+        serialize = "on_site"
+    )]
+    OnSite,
+}
+
+impl WorkMode {
+    /// Parse a user-provided work mode, raising an error if it is not a known work mode.
+    pub fn parse(input: &str) -> Result<WorkMode, FettersError> {
+        WorkMode::from_str(input.trim())
+            .map_err(|_| FettersError::InvalidWorkMode(input.to_string()))
+    }
+}
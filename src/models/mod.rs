@@ -1,7 +1,18 @@
 //! Contains all models used in `fetters`.
 
+pub mod archive;
+pub mod audit;
+pub mod compensation;
+pub mod contact;
 pub mod insight;
+pub mod integrity;
+pub mod interview;
 pub mod job;
+pub mod reminder;
+pub mod search;
+pub mod source;
 pub mod sprint;
 pub mod status;
+pub mod status_change;
+pub mod tag;
 pub mod title;
@@ -0,0 +1,71 @@
+//! Contains all models for follow-up reminders.
+
+use diesel::sqlite::Sqlite;
+use diesel::{Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use tabled::Tabled;
+
+use crate::schema::reminders;
+
+/// This struct defines a new reminder that will be written to the `reminders` table in SQLite.
+#[derive(Debug, Insertable)]
+#[diesel(table_name = reminders)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewReminder<'a> {
+    /// The job ID. References the record ID in SQLite.
+    pub job_id: i32,
+    /// The timestamp at which the reminder is due.
+    pub due: &'a str,
+    /// The reminder message.
+    pub message: &'a str,
+    /// Whether the reminder has been completed.
+    pub done: bool,
+    /// Whether the reminder was created from a reminder rule.
+    pub automatic: bool,
+}
+
+/// This struct defines the reminder object returned from querying SQLite.
+#[derive(Debug, Deserialize, Insertable, Queryable, Selectable, Serialize)]
+#[diesel(table_name = reminders)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct QueriedReminder {
+    /// The SQLite ID.
+    pub id: i32,
+    /// The job ID. References the record ID in SQLite.
+    pub job_id: i32,
+    /// The timestamp at which the reminder is due.
+    pub due: String,
+    /// The reminder message.
+    pub message: String,
+    /// Whether the reminder has been completed.
+    pub done: bool,
+    /// Whether the reminder was created from a reminder rule.
+    pub automatic: bool,
+}
+
+/// This struct defines a reminder with details about its job application and is used when
+/// displaying reminders in tables.
+#[derive(Debug, Serialize, Tabled)]
+pub struct TabledReminder {
+    /// The SQLite ID.
+    #[tabled(rename = "ID")]
+    pub id: i32,
+    /// Whether the reminder is overdue, due today, or upcoming.
+    #[tabled(rename = "State")]
+    pub state: String,
+    /// The timestamp at which the reminder is due.
+    #[tabled(rename = "Due")]
+    pub due: String,
+    /// The job ID. References the record ID in SQLite.
+    #[tabled(rename = "Job ID")]
+    pub job_id: i32,
+    /// The name of the company.
+    #[tabled(rename = "Company Name")]
+    pub company_name: String,
+    /// The current application status.
+    #[tabled(rename = "Status")]
+    pub status: String,
+    /// The reminder message.
+    #[tabled(rename = "Message")]
+    pub message: String,
+}
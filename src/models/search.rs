@@ -0,0 +1,83 @@
+//! Contains all models for full-text search results.
+
+use diesel::QueryableByName;
+use diesel::sql_types::{Integer, Nullable, Text};
+use serde::Serialize;
+use tabled::Tabled;
+use tabled::derive::display;
+
+/// Marks the start of a matched term in a highlighted search result field.
+pub const HIGHLIGHT_START: char = '\u{2}';
+
+/// Marks the end of a matched term in a highlighted search result field.
+pub const HIGHLIGHT_END: char = '\u{3}';
+
+/// This struct defines a job application matching a full-text search query, ordered by relevance.
+/// Matched terms in the company name, title, and notes snippet are wrapped in `HIGHLIGHT_START`
+/// and `HIGHLIGHT_END` markers.
+#[derive(Debug, QueryableByName, Serialize, Tabled)]
+pub struct SearchResult {
+    /// The SQLite ID.
+    #[diesel(sql_type = Integer)]
+    #[tabled(rename = "ID")]
+    pub id: i32,
+    /// The name of the company.
+    #[diesel(sql_type = Text)]
+    #[tabled(rename = "Company Name")]
+    pub company_name: String,
+    /// The job title.
+    #[diesel(sql_type = Nullable<Text>)]
+    #[tabled(rename = "Title")]
+    #[tabled(display("display::option", "N/A"))]
+    pub title: Option<String>,
+    /// The application status.
+    #[diesel(sql_type = Nullable<Text>)]
+    #[tabled(rename = "Status")]
+    #[tabled(display("display::option", "N/A"))]
+    pub status: Option<String>,
+    /// The name of the sprint the job application belongs to.
+    #[diesel(sql_type = Nullable<Text>)]
+    #[tabled(rename = "Sprint")]
+    #[tabled(display("display::option", "N/A"))]
+    pub sprint: Option<String>,
+    /// A snippet of the notes surrounding the best match.
+    #[diesel(sql_type = Nullable<Text>)]
+    #[tabled(rename = "Notes")]
+    #[tabled(display("display::option", "N/A"))]
+    pub snippet: Option<String>,
+}
+
+impl SearchResult {
+    /// Replace the highlight markers in the company name, title, and notes snippet with the
+    /// output of `highlight` for each matched term.
+    pub fn apply_highlights(&mut self, highlight: impl Fn(&str) -> String) {
+        self.company_name = replace_markers(&self.company_name, &highlight);
+        self.title = self
+            .title
+            .as_deref()
+            .map(|title| replace_markers(title, &highlight));
+        self.snippet = self
+            .snippet
+            .as_deref()
+            .filter(|snippet| !snippet.is_empty())
+            .map(|snippet| replace_markers(snippet, &highlight));
+    }
+}
+
+/// Replace each term wrapped in highlight markers with the output of `highlight`.
+fn replace_markers(text: &str, highlight: &impl Fn(&str) -> String) -> String {
+    let mut parts = text.split(HIGHLIGHT_START);
+    let mut highlighted = parts.next().unwrap_or_default().to_string();
+
+    for part in parts {
+        match part.split_once(HIGHLIGHT_END) {
+            Some((matched, rest)) => {
+                highlighted.push_str(&highlight(matched));
+                highlighted.push_str(rest);
+            }
+            None => highlighted.push_str(part),
+        }
+    }
+
+    highlighted
+}
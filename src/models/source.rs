@@ -0,0 +1,37 @@
+//! Contains all models for application sources.
+
+use std::fmt::{self, Display, Formatter};
+
+use diesel::sqlite::Sqlite;
+use diesel::{Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::schema::sources;
+
+/// This struct defines a new application source that will be written to the `sources` table in
+/// SQLite.
+#[derive(Debug, Insertable)]
+#[diesel(table_name = sources)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewSource<'a> {
+    /// The name of the source.
+    pub name: &'a str,
+}
+
+/// This struct defines the source object returned from querying SQLite.
+#[derive(Debug, Deserialize, Queryable, Selectable, Serialize)]
+#[diesel(table_name = sources)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct QueriedSource {
+    /// The SQLite ID.
+    pub id: i32,
+    /// The name of the source.
+    pub name: String,
+}
+
+/// Implementing `Display` allows this struct to be displayed in the `Select` Inquire menu.
+impl Display for QueriedSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
@@ -1,13 +1,19 @@
 //! Contains all models for job sprints.
 
 use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
 
+use chrono::{Duration, NaiveDate};
 use diesel::sqlite::Sqlite;
 use diesel::{AsChangeset, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use strum::{Display as StrumDisplay, EnumString};
 use tabled::Tabled;
 use tabled::derive::display;
 
+use crate::errors::FettersError;
 use crate::schema::sprints;
+use crate::utils::dates::DATE_FORMAT;
 
 /// This struct defines a new sprint title that will be written to the `sprints` table in SQLite.
 #[derive(Debug, Insertable)]
@@ -22,10 +28,14 @@ pub struct NewSprint<'a> {
     pub end_date: Option<&'a str>,
     /// The number of jobs in this sprint.
     pub num_jobs: &'a i32,
+    /// The number of job applications targeted by this sprint.
+    pub goal: Option<i32>,
+    /// The period the goal covers (ie. `SPRINT` or `WEEK`).
+    pub goal_period: Option<&'a str>,
 }
 
 /// This struct defines the sprint object returned from querying SQLite.
-#[derive(Debug, Queryable, Selectable, Tabled)]
+#[derive(Debug, Deserialize, Insertable, Queryable, Selectable, Serialize, Tabled)]
 #[diesel(table_name = sprints)]
 #[diesel(check_for_backend(Sqlite))]
 pub struct QueriedSprint {
@@ -45,6 +55,93 @@ pub struct QueriedSprint {
     /// The number of jobs in this sprint.
     #[tabled(rename = "# of Jobs")]
     pub num_jobs: i32,
+    /// The number of job applications targeted by this sprint.
+    #[serde(default)]
+    #[tabled(rename = "Goal")]
+    #[tabled(display("display_goal", self))]
+    pub goal: Option<i32>,
+    /// The period the goal covers (ie. `SPRINT` or `WEEK`).
+    #[serde(default)]
+    #[tabled(skip)]
+    pub goal_period: Option<String>,
+}
+
+impl QueriedSprint {
+    /// Get the goal of this sprint, if one is set.
+    pub fn sprint_goal(&self) -> Option<SprintGoal> {
+        Some(SprintGoal {
+            target: self.goal?,
+            period: self
+                .goal_period
+                .as_deref()
+                .and_then(|goal_period| GoalPeriod::from_str(goal_period).ok())
+                .unwrap_or(GoalPeriod::Sprint),
+        })
+    }
+
+    /// Get the progress of this sprint towards its goal as of `today`, projecting the current
+    /// application pace to the end date of the sprint. Sprints without an end date are projected
+    /// until the goal is reached.
+    pub fn progress(&self, today: NaiveDate) -> Option<SprintProgress> {
+        let goal = self.sprint_goal()?;
+        let start_date = NaiveDate::parse_from_str(&self.start_date, DATE_FORMAT).ok()?;
+        let end_date = self
+            .end_date
+            .as_deref()
+            .and_then(|end_date| NaiveDate::parse_from_str(end_date, DATE_FORMAT).ok());
+
+        let elapsed_days =
+            ((end_date.unwrap_or(today).min(today) - start_date).num_days() + 1).max(1);
+        let daily_rate = self.num_jobs as f64 / elapsed_days as f64;
+        let target = match goal.period {
+            GoalPeriod::Sprint => goal.target,
+            GoalPeriod::Week => {
+                let weeks = match end_date {
+                    Some(end_date) => (end_date - start_date).num_days() + 1,
+                    None => elapsed_days,
+                } as f64
+                    / 7.0;
+                (goal.target as f64 * weeks.ceil().max(1.0)) as i32
+            }
+        };
+
+        let projection = match end_date {
+            Some(end_date) if end_date < today => format!(
+                "The sprint ended on {} with {}/{target} applications.",
+                end_date.format(DATE_FORMAT),
+                self.num_jobs
+            ),
+            Some(end_date) => format!(
+                "At this rate you'll hit {}/{target} by {}.",
+                self.num_jobs + (daily_rate * (end_date - today).num_days() as f64).round() as i32,
+                end_date.format(DATE_FORMAT)
+            ),
+            None if self.num_jobs >= target => "Goal reached!".to_string(),
+            None if daily_rate > 0.0 => {
+                let remaining_days = ((target - self.num_jobs) as f64 / daily_rate).ceil() as i64;
+                format!(
+                    "At this rate you'll hit {target}/{target} by {}.",
+                    (today + Duration::days(remaining_days)).format(DATE_FORMAT)
+                )
+            }
+            None => "Track a job application to project your pace.".to_string(),
+        };
+
+        Some(SprintProgress {
+            goal,
+            applied: self.num_jobs,
+            target,
+            weekly_average: daily_rate * 7.0,
+            projection,
+        })
+    }
+}
+
+/// Display the goal of a sprint in a table.
+fn display_goal(_goal: &Option<i32>, sprint: &QueriedSprint) -> String {
+    sprint
+        .sprint_goal()
+        .map_or("N/A".to_string(), |goal| goal.to_string())
 }
 
 impl Display for QueriedSprint {
@@ -68,4 +165,76 @@ pub struct SprintUpdate<'a> {
     pub start_date: Option<&'a str>,
     /// The end date for this sprint.
     pub end_date: Option<Option<&'a str>>,
+    /// The number of job applications targeted by this sprint.
+    pub goal: Option<Option<i32>>,
+    /// The period the goal covers (ie. `SPRINT` or `WEEK`).
+    pub goal_period: Option<Option<&'a str>>,
+}
+
+/// This enum contains all periods a sprint goal can cover.
+#[derive(Clone, Copy, Debug, EnumString, PartialEq, StrumDisplay)]
+#[strum(ascii_case_insensitive)]
+pub enum GoalPeriod {
+    /// The goal covers the whole sprint.
+    #[strum(to_string = "SPRINT")]
+    Sprint,
+    /// The goal is repeated every week of the sprint.
+    #[strum(to_string = "WEEK", serialize = "w", serialize = "weekly")]
+    Week,
+}
+
+/// Contains the number of job applications a sprint targets and the period the target covers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SprintGoal {
+    /// The number of job applications to track.
+    pub target: i32,
+    /// The period the target covers.
+    pub period: GoalPeriod,
+}
+
+impl SprintGoal {
+    /// Parse a user-provided goal. A plain number (ie. `50`) covers the whole sprint and a number
+    /// followed by a period (ie. `10/week` or `10 per week`) is repeated every period.
+    pub fn parse(input: &str) -> Result<SprintGoal, FettersError> {
+        let invalid_goal = || FettersError::InvalidGoal(input.to_string());
+        let normalized = input.trim().to_lowercase().replace(" per ", "/");
+        let (target, period) = match normalized.split_once('/') {
+            Some((target, period)) => (
+                target.trim(),
+                GoalPeriod::from_str(period.trim()).map_err(|_| invalid_goal())?,
+            ),
+            None => (normalized.as_str(), GoalPeriod::Sprint),
+        };
+
+        match target.parse::<i32>() {
+            Ok(target) if target > 0 => Ok(SprintGoal { target, period }),
+            _ => Err(invalid_goal()),
+        }
+    }
+}
+
+impl Display for SprintGoal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.period {
+            GoalPeriod::Sprint => write!(f, "{} applications", self.target),
+            GoalPeriod::Week => write!(f, "{} applications per week", self.target),
+        }
+    }
+}
+
+/// Contains the progress of a sprint towards its goal.
+#[derive(Debug)]
+pub struct SprintProgress {
+    /// The goal of the sprint.
+    pub goal: SprintGoal,
+    /// The number of job applications tracked in the sprint.
+    pub applied: i32,
+    /// The number of job applications the sprint targets so far. Weekly goals are multiplied by
+    /// the number of weeks in the sprint, or the number of elapsed weeks if the sprint has no end
+    /// date.
+    pub target: i32,
+    /// The average number of job applications tracked per week.
+    pub weekly_average: f64,
+    /// A sentence projecting the current pace.
+    pub projection: String,
 }
@@ -3,7 +3,10 @@
 use std::fmt::{self, Display, Formatter};
 
 use diesel::sqlite::Sqlite;
-use diesel::{Insertable, Queryable, Selectable};
+use diesel::{AsChangeset, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use tabled::Tabled;
+use tabled::derive::display;
 
 use crate::schema::statuses;
 
@@ -14,17 +17,38 @@ use crate::schema::statuses;
 pub struct NewStatus<'a> {
     /// The status name.
     pub name: &'a str,
+    /// The color used when displaying this status.
+    pub color: Option<&'a str>,
+    /// The position of this status when statuses are listed.
+    pub sort_order: i32,
+    /// Whether job applications in this status are finished.
+    pub is_terminal: bool,
 }
 
 /// This struct defines the status object returned from querying SQLite.
-#[derive(Debug, Queryable, Selectable)]
+#[derive(Debug, Deserialize, Queryable, Selectable, Serialize, Tabled)]
 #[diesel(table_name = statuses)]
 #[diesel(check_for_backend(Sqlite))]
 pub struct QueriedStatus {
     /// The SQLite ID.
+    #[tabled(skip)]
     pub id: i32,
     /// The status title.
+    #[tabled(rename = "Status")]
     pub name: String,
+    /// The color used when displaying this status.
+    #[serde(default)]
+    #[tabled(rename = "Color")]
+    #[tabled(display("display::option", "N/A"))]
+    pub color: Option<String>,
+    /// The position of this status when statuses are listed.
+    #[serde(default)]
+    #[tabled(rename = "Order")]
+    pub sort_order: i32,
+    /// Whether job applications in this status are finished.
+    #[serde(default)]
+    #[tabled(rename = "Terminal")]
+    pub is_terminal: bool,
 }
 
 /// Implementing `Display` allows this struct to be displayed in the `Select` Inquire menu.
@@ -33,3 +57,18 @@ impl Display for QueriedStatus {
         write!(f, "{}", self.name)
     }
 }
+
+/// This struct defines an updated status that will overwrite an existing one in SQLite.
+#[derive(Debug, Default, AsChangeset)]
+#[diesel(table_name = statuses)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct StatusUpdate<'a> {
+    /// The status name.
+    pub name: Option<&'a str>,
+    /// The color used when displaying this status.
+    pub color: Option<Option<&'a str>>,
+    /// The position of this status when statuses are listed.
+    pub sort_order: Option<i32>,
+    /// Whether job applications in this status are finished.
+    pub is_terminal: Option<bool>,
+}
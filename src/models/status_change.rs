@@ -0,0 +1,60 @@
+//! Contains all models for job application status changes.
+
+use diesel::sqlite::Sqlite;
+use diesel::{Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use tabled::Tabled;
+
+use crate::schema::status_changes;
+
+/// This struct defines a new status change that will be written to the `status_changes` table in
+/// SQLite.
+#[derive(Debug, Insertable)]
+#[diesel(table_name = status_changes)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewStatusChange<'a> {
+    /// The job ID. References the record ID in SQLite.
+    pub job_id: i32,
+    /// The previous application status ID. This is `None` when the job was first created.
+    pub old_status_id: Option<i32>,
+    /// The new application status ID. References the record ID in SQLite.
+    pub new_status_id: i32,
+    /// The timestamp at which the status changed.
+    pub changed: &'a str,
+}
+
+/// This struct defines the status change object returned from querying SQLite.
+#[allow(dead_code)]
+#[derive(Debug, Deserialize, Insertable, Queryable, Selectable, Serialize)]
+#[diesel(table_name = status_changes)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct QueriedStatusChange {
+    /// The SQLite ID.
+    pub id: i32,
+    /// The job ID. References the record ID in SQLite.
+    pub job_id: i32,
+    /// The previous application status ID. This is `None` when the job was first created.
+    pub old_status_id: Option<i32>,
+    /// The new application status ID. References the record ID in SQLite.
+    pub new_status_id: i32,
+    /// The timestamp at which the status changed.
+    pub changed: String,
+}
+
+/// This struct defines a single entry in a job application's status timeline with the status names
+/// resolved and is used when displaying the timeline in a table.
+#[derive(Debug, Tabled)]
+pub struct TabledStatusChange {
+    /// The timestamp at which the status changed.
+    #[tabled(rename = "Changed")]
+    pub changed: String,
+    /// The previous application status.
+    #[tabled(rename = "From")]
+    pub old_status: String,
+    /// The new application status.
+    #[tabled(rename = "To")]
+    pub new_status: String,
+    /// How long the application stayed in the new status.
+    #[tabled(rename = "Time In Status")]
+    pub duration: String,
+}
@@ -0,0 +1,38 @@
+//! Contains all models for tags attached to job applications.
+
+use diesel::sqlite::Sqlite;
+use diesel::{Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{job_tags, tags};
+
+/// This struct defines a new tag that will be written to the `tags` table in SQLite.
+#[derive(Debug, Insertable)]
+#[diesel(table_name = tags)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct NewTag<'a> {
+    /// The name of the tag.
+    pub name: &'a str,
+}
+
+/// This struct defines the tag object returned from querying SQLite.
+#[derive(Debug, Deserialize, Queryable, Selectable, Serialize)]
+#[diesel(table_name = tags)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct QueriedTag {
+    /// The SQLite ID.
+    pub id: i32,
+    /// The name of the tag.
+    pub name: String,
+}
+
+/// This struct defines a link between a job application and a tag in the `job_tags` table.
+#[derive(Debug, Deserialize, Insertable, Queryable, Selectable, Serialize)]
+#[diesel(table_name = job_tags)]
+#[diesel(check_for_backend(Sqlite))]
+pub struct JobTag {
+    /// The job ID. References the record ID in SQLite.
+    pub job_id: i32,
+    /// The tag ID. References the record ID in SQLite.
+    pub tag_id: i32,
+}
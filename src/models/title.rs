@@ -4,6 +4,7 @@ use std::fmt::{self, Display, Formatter};
 
 use diesel::sqlite::Sqlite;
 use diesel::{Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
 
 use crate::schema::titles;
 
@@ -17,7 +18,7 @@ pub struct NewTitle<'a> {
 }
 
 /// This struct defines the title object returned from querying SQLite.
-#[derive(Debug, Queryable, Selectable)]
+#[derive(Debug, Deserialize, Insertable, Queryable, Selectable, Serialize)]
 #[diesel(table_name = titles)]
 #[diesel(check_for_backend(Sqlite))]
 pub struct QueriedTitle {
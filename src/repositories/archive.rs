@@ -0,0 +1,600 @@
+//! Contains the archive repository abstraction class.
+
+use std::collections::{HashMap, HashSet};
+
+use diesel::prelude::*;
+use diesel::{delete, insert_into};
+
+use crate::errors::FettersError;
+use crate::models::{
+    archive::{ARCHIVE_FORMAT_VERSION, Archive},
+    compensation::Compensation,
+    contact::{JobContact, NewContact, QueriedContact},
+    interview::{NewInterview, QueriedInterview},
+    job::{NewJob, QueriedJob},
+    reminder::{NewReminder, QueriedReminder},
+    source::{NewSource, QueriedSource},
+    sprint::{NewSprint, QueriedSprint},
+    status::{NewStatus, QueriedStatus},
+    status_change::{NewStatusChange, QueriedStatusChange},
+    tag::{JobTag, NewTag, QueriedTag},
+    title::{NewTitle, QueriedTitle},
+};
+use crate::repositories::statuses::DEFAULT_STATUSES;
+use crate::schema::{
+    audit_log, compensation, contacts, interviews, job_contacts, job_tags, jobs, reminders,
+    sources, sprints, status_changes, statuses, tags, titles,
+};
+use crate::utils::dates::now_timestamp;
+
+/// Contains all methods pertaining to exporting and restoring the entire database.
+pub struct ArchiveRepository<'a> {
+    pub connection: &'a mut SqliteConnection,
+}
+
+impl<'a> ArchiveRepository<'a> {
+    /// Dumps every table into an `Archive`.
+    pub fn export_archive(&mut self) -> Result<Archive, FettersError> {
+        Ok(Archive {
+            format_version: ARCHIVE_FORMAT_VERSION,
+            exported: now_timestamp(),
+            compensation: compensation::table
+                .order(compensation::job_id)
+                .select(Compensation::as_select())
+                .load(self.connection)?,
+            contacts: contacts::table
+                .order(contacts::id)
+                .select(QueriedContact::as_select())
+                .load(self.connection)?,
+            interviews: interviews::table
+                .order(interviews::id)
+                .select(QueriedInterview::as_select())
+                .load(self.connection)?,
+            job_contacts: job_contacts::table
+                .order((job_contacts::job_id, job_contacts::contact_id))
+                .select(JobContact::as_select())
+                .load(self.connection)?,
+            job_tags: job_tags::table
+                .order((job_tags::job_id, job_tags::tag_id))
+                .select(JobTag::as_select())
+                .load(self.connection)?,
+            jobs: jobs::table
+                .order(jobs::id)
+                .select(QueriedJob::as_select())
+                .load(self.connection)?,
+            reminders: reminders::table
+                .order(reminders::id)
+                .select(QueriedReminder::as_select())
+                .load(self.connection)?,
+            sources: sources::table
+                .order(sources::id)
+                .select(QueriedSource::as_select())
+                .load(self.connection)?,
+            sprints: sprints::table
+                .order(sprints::id)
+                .select(QueriedSprint::as_select())
+                .load(self.connection)?,
+            status_changes: status_changes::table
+                .order(status_changes::id)
+                .select(QueriedStatusChange::as_select())
+                .load(self.connection)?,
+            statuses: statuses::table
+                .order(statuses::id)
+                .select(QueriedStatus::as_select())
+                .load(self.connection)?,
+            tags: tags::table
+                .order(tags::id)
+                .select(QueriedTag::as_select())
+                .load(self.connection)?,
+            titles: titles::table
+                .order(titles::id)
+                .select(QueriedTitle::as_select())
+                .load(self.connection)?,
+        })
+    }
+
+    /// Replaces all data in the database with the contents of an `Archive`. The archive is
+    /// verified and upgraded to the current archive format before anything is written. All record
+    /// IDs are remapped, so the restored records may have different IDs than the ones stored in
+    /// the archive.
+    pub fn restore_archive(&mut self, archive: Archive) -> Result<(), FettersError> {
+        verify_archive(&archive)?;
+        let archive = upgrade_archive(archive);
+
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                // Snapshots in the audit log reference the replaced record IDs, so the audit log is
+                // cleared as well.
+                delete(audit_log::table).execute(connection)?;
+                delete(job_tags::table).execute(connection)?;
+                delete(tags::table).execute(connection)?;
+                delete(compensation::table).execute(connection)?;
+                delete(reminders::table).execute(connection)?;
+                delete(job_contacts::table).execute(connection)?;
+                delete(contacts::table).execute(connection)?;
+                delete(interviews::table).execute(connection)?;
+                delete(status_changes::table).execute(connection)?;
+                delete(jobs::table).execute(connection)?;
+                delete(sprints::table).execute(connection)?;
+                delete(titles::table).execute(connection)?;
+                delete(sources::table).execute(connection)?;
+                delete(statuses::table).execute(connection)?;
+
+                let mut status_ids: HashMap<i32, i32> = HashMap::new();
+                for status in archive.statuses.iter() {
+                    let new_id = insert_into(statuses::table)
+                        .values(&NewStatus {
+                            name: &status.name,
+                            color: status.color.as_deref(),
+                            sort_order: status.sort_order,
+                            is_terminal: status.is_terminal,
+                        })
+                        .returning(statuses::id)
+                        .get_result(connection)?;
+                    status_ids.insert(status.id, new_id);
+                }
+
+                let mut title_ids: HashMap<i32, i32> = HashMap::new();
+                for title in archive.titles.iter() {
+                    let new_id = insert_into(titles::table)
+                        .values(&NewTitle { name: &title.name })
+                        .returning(titles::id)
+                        .get_result(connection)?;
+                    title_ids.insert(title.id, new_id);
+                }
+
+                let mut source_ids: HashMap<i32, i32> = HashMap::new();
+                for source in archive.sources.iter() {
+                    let new_id = insert_into(sources::table)
+                        .values(&NewSource { name: &source.name })
+                        .returning(sources::id)
+                        .get_result(connection)?;
+                    source_ids.insert(source.id, new_id);
+                }
+
+                // The number of jobs in each sprint is recounted from the archived jobs instead of
+                // trusting the archived `num_jobs` values. Jobs in the trash are not counted.
+                let mut sprint_ids: HashMap<i32, i32> = HashMap::new();
+                for sprint in archive.sprints.iter() {
+                    let num_jobs = archive
+                        .jobs
+                        .iter()
+                        .filter(|job| job.sprint_id == sprint.id && job.deleted_at.is_none())
+                        .count() as i32;
+
+                    let new_id = insert_into(sprints::table)
+                        .values(&NewSprint {
+                            name: &sprint.name,
+                            start_date: &sprint.start_date,
+                            end_date: sprint.end_date.as_deref(),
+                            num_jobs: &num_jobs,
+                            goal: sprint.goal,
+                            goal_period: sprint.goal_period.as_deref(),
+                        })
+                        .returning(sprints::id)
+                        .get_result(connection)?;
+                    sprint_ids.insert(sprint.id, new_id);
+                }
+
+                let mut job_ids: HashMap<i32, i32> = HashMap::new();
+                for job in archive.jobs.iter() {
+                    let new_id = insert_into(jobs::table)
+                        .values(&NewJob {
+                            company_name: &job.company_name,
+                            created: job.created.clone(),
+                            title_id: title_ids[&job.title_id],
+                            status_id: status_ids[&job.status_id],
+                            link: job.link.as_deref(),
+                            notes: job.notes.as_deref(),
+                            sprint_id: sprint_ids[&job.sprint_id],
+                            location: job.location.as_deref(),
+                            country: job.country.as_deref(),
+                            work_mode: job.work_mode.as_deref(),
+                            source_id: job.source_id.map(|source_id| source_ids[&source_id]),
+                            deleted_at: job.deleted_at.as_deref(),
+                        })
+                        .returning(jobs::id)
+                        .get_result(connection)?;
+                    job_ids.insert(job.id, new_id);
+                }
+
+                for status_change in archive.status_changes.iter() {
+                    insert_into(status_changes::table)
+                        .values(&NewStatusChange {
+                            job_id: job_ids[&status_change.job_id],
+                            old_status_id: status_change
+                                .old_status_id
+                                .map(|status_id| status_ids[&status_id]),
+                            new_status_id: status_ids[&status_change.new_status_id],
+                            changed: &status_change.changed,
+                        })
+                        .execute(connection)?;
+                }
+
+                for interview in archive.interviews.iter() {
+                    insert_into(interviews::table)
+                        .values(&NewInterview {
+                            job_id: job_ids[&interview.job_id],
+                            round: &interview.round,
+                            interview_type: &interview.interview_type,
+                            scheduled: interview.scheduled.as_deref(),
+                            interviewers: interview.interviewers.as_deref(),
+                            outcome: &interview.outcome,
+                            notes: interview.notes.as_deref(),
+                        })
+                        .execute(connection)?;
+                }
+
+                for reminder in archive.reminders.iter() {
+                    insert_into(reminders::table)
+                        .values(&NewReminder {
+                            job_id: job_ids[&reminder.job_id],
+                            due: &reminder.due,
+                            message: &reminder.message,
+                            done: reminder.done,
+                            automatic: reminder.automatic,
+                        })
+                        .execute(connection)?;
+                }
+
+                for job_compensation in archive.compensation.iter() {
+                    insert_into(compensation::table)
+                        .values(&Compensation {
+                            job_id: job_ids[&job_compensation.job_id],
+                            ..job_compensation.clone()
+                        })
+                        .execute(connection)?;
+                }
+
+                let mut contact_ids: HashMap<i32, i32> = HashMap::new();
+                for contact in archive.contacts.iter() {
+                    let new_id = insert_into(contacts::table)
+                        .values(&NewContact {
+                            name: &contact.name,
+                            email: contact.email.as_deref(),
+                            phone: contact.phone.as_deref(),
+                            role: contact.role.as_deref(),
+                            company: contact.company.as_deref(),
+                            linkedin_url: contact.linkedin_url.as_deref(),
+                            notes: contact.notes.as_deref(),
+                        })
+                        .returning(contacts::id)
+                        .get_result(connection)?;
+                    contact_ids.insert(contact.id, new_id);
+                }
+
+                for job_contact in archive.job_contacts.iter() {
+                    insert_into(job_contacts::table)
+                        .values(&JobContact {
+                            job_id: job_ids[&job_contact.job_id],
+                            contact_id: contact_ids[&job_contact.contact_id],
+                            linked: job_contact.linked.clone(),
+                        })
+                        .execute(connection)?;
+                }
+
+                let mut tag_ids: HashMap<i32, i32> = HashMap::new();
+                for tag in archive.tags.iter() {
+                    let new_id = insert_into(tags::table)
+                        .values(&NewTag { name: &tag.name })
+                        .returning(tags::id)
+                        .get_result(connection)?;
+                    tag_ids.insert(tag.id, new_id);
+                }
+
+                for job_tag in archive.job_tags.iter() {
+                    insert_into(job_tags::table)
+                        .values(&JobTag {
+                            job_id: job_ids[&job_tag.job_id],
+                            tag_id: tag_ids[&job_tag.tag_id],
+                        })
+                        .execute(connection)?;
+                }
+
+                Ok(())
+            })
+    }
+}
+
+/// Upgrade an archive created by an older version of `fetters` to the current archive format.
+/// Tables and fields that are missing from older archives are already filled with their defaults
+/// when the archive is deserialized.
+fn upgrade_archive(mut archive: Archive) -> Archive {
+    // Version 1 archives created before statuses had colors, ordering, and terminal flags have a
+    // sort order of 0 for every status. The default statuses get their default attributes back
+    // and custom statuses are ordered after them.
+    if archive.format_version < 2 && archive.statuses.iter().all(|status| status.sort_order == 0) {
+        for (index, status) in archive.statuses.iter_mut().enumerate() {
+            match DEFAULT_STATUSES
+                .iter()
+                .position(|(status_name, ..)| *status_name == status.name)
+            {
+                Some(position) => {
+                    let (_, color, is_terminal) = DEFAULT_STATUSES[position];
+                    status.color.get_or_insert(color.to_string());
+                    status.sort_order = position as i32 + 1;
+                    status.is_terminal = is_terminal;
+                }
+                None => status.sort_order = (DEFAULT_STATUSES.len() + index) as i32 + 1,
+            }
+        }
+    }
+
+    archive.format_version = ARCHIVE_FORMAT_VERSION;
+    archive
+}
+
+/// Verify that an archive can be restored by checking its format version and that every record
+/// references records that exist in the archive.
+fn verify_archive(archive: &Archive) -> Result<(), FettersError> {
+    if archive.format_version == 0 || archive.format_version > ARCHIVE_FORMAT_VERSION {
+        return Err(FettersError::UnsupportedArchiveVersion(
+            archive.format_version,
+        ));
+    }
+
+    let status_ids: HashSet<i32> = archive.statuses.iter().map(|status| status.id).collect();
+    let title_ids: HashSet<i32> = archive.titles.iter().map(|title| title.id).collect();
+    let sprint_ids: HashSet<i32> = archive.sprints.iter().map(|sprint| sprint.id).collect();
+    let job_ids: HashSet<i32> = archive.jobs.iter().map(|job| job.id).collect();
+    let contact_ids: HashSet<i32> = archive.contacts.iter().map(|contact| contact.id).collect();
+    let tag_ids: HashSet<i32> = archive.tags.iter().map(|tag| tag.id).collect();
+    let source_ids: HashSet<i32> = archive.sources.iter().map(|source| source.id).collect();
+
+    let check = |exists: bool, message: String| {
+        if exists {
+            Ok(())
+        } else {
+            Err(FettersError::ArchiveIntegrityError(message))
+        }
+    };
+
+    check(
+        status_ids.len() == archive.statuses.len()
+            && title_ids.len() == archive.titles.len()
+            && sprint_ids.len() == archive.sprints.len()
+            && job_ids.len() == archive.jobs.len()
+            && contact_ids.len() == archive.contacts.len()
+            && tag_ids.len() == archive.tags.len()
+            && source_ids.len() == archive.sources.len(),
+        "The archive contains duplicate record IDs".to_string(),
+    )?;
+
+    for job in archive.jobs.iter() {
+        check(
+            title_ids.contains(&job.title_id),
+            format!("Job {} references missing title {}", job.id, job.title_id),
+        )?;
+        check(
+            status_ids.contains(&job.status_id),
+            format!("Job {} references missing status {}", job.id, job.status_id),
+        )?;
+        check(
+            sprint_ids.contains(&job.sprint_id),
+            format!("Job {} references missing sprint {}", job.id, job.sprint_id),
+        )?;
+        check(
+            job.source_id
+                .is_none_or(|source_id| source_ids.contains(&source_id)),
+            format!("Job {} references a missing source", job.id),
+        )?;
+    }
+
+    for status_change in archive.status_changes.iter() {
+        check(
+            job_ids.contains(&status_change.job_id),
+            format!(
+                "Status change {} references missing job {}",
+                status_change.id, status_change.job_id
+            ),
+        )?;
+        check(
+            status_change
+                .old_status_id
+                .is_none_or(|status_id| status_ids.contains(&status_id))
+                && status_ids.contains(&status_change.new_status_id),
+            format!(
+                "Status change {} references a missing status",
+                status_change.id
+            ),
+        )?;
+    }
+
+    for interview in archive.interviews.iter() {
+        check(
+            job_ids.contains(&interview.job_id),
+            format!(
+                "Interview {} references missing job {}",
+                interview.id, interview.job_id
+            ),
+        )?;
+    }
+
+    for reminder in archive.reminders.iter() {
+        check(
+            job_ids.contains(&reminder.job_id),
+            format!(
+                "Reminder {} references missing job {}",
+                reminder.id, reminder.job_id
+            ),
+        )?;
+    }
+
+    let mut compensation_job_ids: HashSet<i32> = HashSet::new();
+    for job_compensation in archive.compensation.iter() {
+        check(
+            job_ids.contains(&job_compensation.job_id),
+            format!(
+                "Compensation details reference missing job {}",
+                job_compensation.job_id
+            ),
+        )?;
+        check(
+            compensation_job_ids.insert(job_compensation.job_id),
+            format!(
+                "Job {} has compensation details more than once",
+                job_compensation.job_id
+            ),
+        )?;
+    }
+
+    let mut job_contact_pairs: HashSet<(i32, i32)> = HashSet::new();
+    for job_contact in archive.job_contacts.iter() {
+        check(
+            job_ids.contains(&job_contact.job_id) && contact_ids.contains(&job_contact.contact_id),
+            format!(
+                "Contact link between job {} and contact {} references a missing record",
+                job_contact.job_id, job_contact.contact_id
+            ),
+        )?;
+        check(
+            job_contact_pairs.insert((job_contact.job_id, job_contact.contact_id)),
+            format!(
+                "Contact {} is linked to job {} more than once",
+                job_contact.contact_id, job_contact.job_id
+            ),
+        )?;
+    }
+
+    let mut job_tag_pairs: HashSet<(i32, i32)> = HashSet::new();
+    for job_tag in archive.job_tags.iter() {
+        check(
+            job_ids.contains(&job_tag.job_id) && tag_ids.contains(&job_tag.tag_id),
+            format!(
+                "Tag link between job {} and tag {} references a missing record",
+                job_tag.job_id, job_tag.tag_id
+            ),
+        )?;
+        check(
+            job_tag_pairs.insert((job_tag.job_id, job_tag.tag_id)),
+            format!(
+                "Tag {} is attached to job {} more than once",
+                job_tag.tag_id, job_tag.job_id
+            ),
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{
+        interview::InterviewRepository, job::JobRepository, sprint::SprintRepository,
+        tag::TagRepository,
+    };
+    use crate::utils::testing::TestDatabase;
+
+    #[test]
+    fn restoring_an_archive_remaps_record_ids() -> Result<(), FettersError> {
+        let mut database = TestDatabase::new()?;
+        let job = database.add_job("Acme", "IN PROGRESS", "2026-01-01 09:00:00")?;
+        let trashed_job = database.add_job("Globex", "PENDING", "2026-01-02 09:00:00")?;
+        JobRepository {
+            connection: &mut database.connection,
+        }
+        .delete_job(trashed_job.id)?;
+        TagRepository {
+            connection: &mut database.connection,
+        }
+        .add_job_tags(job.id, &["remote".to_string()])?;
+        InterviewRepository {
+            connection: &mut database.connection,
+        }
+        .add_interview(NewInterview {
+            job_id: job.id,
+            round: "Phone Screen",
+            interview_type: "PHONE",
+            scheduled: Some("2026-01-05 10:00:00"),
+            interviewers: None,
+            outcome: "PENDING",
+            notes: None,
+        })?;
+
+        let mut archive_repo = ArchiveRepository {
+            connection: &mut database.connection,
+        };
+        let archive = archive_repo.export_archive()?;
+        let archived_job_id = archive.jobs[0].id;
+
+        // Restoring into the same database gives every record a new ID because the tables use
+        // `AUTOINCREMENT`.
+        archive_repo.restore_archive(archive)?;
+
+        let restored_jobs: Vec<QueriedJob> = jobs::table
+            .order(jobs::id)
+            .select(QueriedJob::as_select())
+            .load(&mut database.connection)?;
+        assert_eq!(restored_jobs.len(), 2);
+
+        let restored_job = &restored_jobs[0];
+        assert_ne!(restored_job.id, archived_job_id);
+        assert_eq!(restored_job.company_name, "Acme");
+
+        let (status_name, title_name, sprint_name): (String, String, String) = jobs::table
+            .inner_join(statuses::table.on(jobs::status_id.eq(statuses::id)))
+            .inner_join(titles::table.on(jobs::title_id.eq(titles::id)))
+            .inner_join(sprints::table.on(jobs::sprint_id.eq(sprints::id)))
+            .filter(jobs::id.eq(restored_job.id))
+            .select((statuses::name, titles::name, sprints::name))
+            .first(&mut database.connection)?;
+        assert_eq!(status_name, "IN PROGRESS");
+        assert_eq!(title_name, "Software Engineer");
+        assert_eq!(sprint_name, "test-sprint");
+
+        let restored_status_changes: Vec<i32> = status_changes::table
+            .select(status_changes::job_id)
+            .load(&mut database.connection)?;
+        assert!(
+            restored_status_changes
+                .iter()
+                .all(|job_id| restored_jobs.iter().any(|job| job.id == *job_id))
+        );
+
+        let mut interview_repo = InterviewRepository {
+            connection: &mut database.connection,
+        };
+        assert_eq!(interview_repo.get_interviews(restored_job.id)?.len(), 1);
+
+        let mut tag_repo = TagRepository {
+            connection: &mut database.connection,
+        };
+        assert_eq!(
+            tag_repo.get_tags_by_job(&[restored_job.id])?[&restored_job.id],
+            vec!["remote".to_string()]
+        );
+
+        // The trashed job stays in the trash and is not counted in its sprint.
+        assert!(restored_jobs[1].deleted_at.is_some());
+        let mut sprint_repo = SprintRepository {
+            connection: &mut database.connection,
+        };
+        assert_eq!(sprint_repo.get_current_sprint("test-sprint")?.num_jobs, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn archives_with_missing_references_are_rejected() -> Result<(), FettersError> {
+        let mut database = TestDatabase::new()?;
+        database.add_job("Acme", "PENDING", "2026-01-01 09:00:00")?;
+
+        let mut archive_repo = ArchiveRepository {
+            connection: &mut database.connection,
+        };
+        let mut archive = archive_repo.export_archive()?;
+        archive.titles.clear();
+
+        assert!(matches!(
+            archive_repo.restore_archive(archive),
+            Err(FettersError::ArchiveIntegrityError(_))
+        ));
+
+        // Nothing is replaced when an archive is rejected.
+        let remaining_jobs: i64 = jobs::table.count().get_result(&mut database.connection)?;
+        assert_eq!(remaining_jobs, 1);
+
+        Ok(())
+    }
+}
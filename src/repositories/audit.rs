@@ -0,0 +1,680 @@
+//! Contains the audit log repository abstraction class.
+
+use std::env;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use diesel::dsl::max;
+use diesel::prelude::*;
+use diesel::{delete, insert_into, replace_into, update};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::errors::FettersError;
+use crate::models::{
+    audit::{
+        AuditAction, AuditTable, JobSnapshot, LoggedOperation, NewAuditEntry, QueriedAuditEntry,
+        STARTUP_SWEEP_COMMAND,
+    },
+    compensation::Compensation,
+    contact::JobContact,
+    interview::QueriedInterview,
+    job::QueriedJob,
+    reminder::QueriedReminder,
+    sprint::QueriedSprint,
+    status_change::QueriedStatusChange,
+    tag::JobTag,
+    title::QueriedTitle,
+};
+use crate::schema::{
+    audit_log, compensation, interviews, job_contacts, job_tags, jobs, reminders, sprints,
+    status_changes, titles,
+};
+use crate::utils::dates::now_timestamp;
+
+/// The operation currently being recorded as its ID and the command it is recorded under. The ID is
+/// assigned the first time a change is recorded, and all audit log entries written until the next
+/// operation is started share it.
+static CURRENT_OPERATION: Mutex<(Option<i32>, Option<String>)> = Mutex::new((None, None));
+
+/// Contains all methods pertaining to recording, listing, and undoing changes in the `audit_log`
+/// table.
+pub struct AuditRepository<'a> {
+    pub connection: &'a mut SqliteConnection,
+}
+
+impl<'a> AuditRepository<'a> {
+    /// Start a new operation so the changes recorded after this call can be undone separately from
+    /// earlier ones. The changes are recorded under `command` if it is provided, and under the
+    /// arguments `fetters` was invoked with otherwise.
+    pub fn start_operation(command: Option<&str>) {
+        let mut current_operation = CURRENT_OPERATION
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+        *current_operation = (None, command.map(str::to_string));
+    }
+
+    /// Take a snapshot of a job application and every record attached to it.
+    pub fn snapshot_job(&mut self, job_id: i32) -> Result<JobSnapshot, FettersError> {
+        Ok(JobSnapshot {
+            job: jobs::table
+                .find(job_id)
+                .select(QueriedJob::as_select())
+                .first(self.connection)?,
+            status_changes: status_changes::table
+                .filter(status_changes::job_id.eq(job_id))
+                .order(status_changes::id)
+                .select(QueriedStatusChange::as_select())
+                .load(self.connection)?,
+            interviews: interviews::table
+                .filter(interviews::job_id.eq(job_id))
+                .order(interviews::id)
+                .select(QueriedInterview::as_select())
+                .load(self.connection)?,
+            reminders: reminders::table
+                .filter(reminders::job_id.eq(job_id))
+                .order(reminders::id)
+                .select(QueriedReminder::as_select())
+                .load(self.connection)?,
+            compensation: compensation::table
+                .find(job_id)
+                .select(Compensation::as_select())
+                .first(self.connection)
+                .optional()?,
+            job_contacts: job_contacts::table
+                .filter(job_contacts::job_id.eq(job_id))
+                .select(JobContact::as_select())
+                .load(self.connection)?,
+            job_tags: job_tags::table
+                .filter(job_tags::job_id.eq(job_id))
+                .select(JobTag::as_select())
+                .load(self.connection)?,
+        })
+    }
+
+    /// Record a change to a job application.
+    pub fn record_job(
+        &mut self,
+        action: AuditAction,
+        before: Option<&JobSnapshot>,
+        after: Option<&JobSnapshot>,
+    ) -> Result<(), FettersError> {
+        let Some(snapshot) = after.or(before) else {
+            return Ok(());
+        };
+
+        self.record(
+            action,
+            AuditTable::Jobs,
+            (snapshot.job.id, &snapshot.job.company_name),
+            before,
+            after,
+        )
+    }
+
+    /// Record a change to a sprint.
+    pub fn record_sprint(
+        &mut self,
+        action: AuditAction,
+        before: Option<&QueriedSprint>,
+        after: Option<&QueriedSprint>,
+    ) -> Result<(), FettersError> {
+        let Some(sprint) = after.or(before) else {
+            return Ok(());
+        };
+
+        self.record(
+            action,
+            AuditTable::Sprints,
+            (sprint.id, &sprint.name),
+            before,
+            after,
+        )
+    }
+
+    /// Record a change to a job title.
+    pub fn record_title(
+        &mut self,
+        action: AuditAction,
+        before: Option<&QueriedTitle>,
+        after: Option<&QueriedTitle>,
+    ) -> Result<(), FettersError> {
+        let Some(title) = after.or(before) else {
+            return Ok(());
+        };
+
+        self.record(
+            action,
+            AuditTable::Titles,
+            (title.id, &title.name),
+            before,
+            after,
+        )
+    }
+
+    /// List the most recent operations, newest first.
+    pub fn list_operations(&mut self, limit: i64) -> Result<Vec<LoggedOperation>, FettersError> {
+        let operation_ids: Vec<i32> = audit_log::table
+            .select(audit_log::operation_id)
+            .distinct()
+            .order(audit_log::operation_id.desc())
+            .limit(limit)
+            .load(self.connection)?;
+
+        self.load_operations(&operation_ids)
+    }
+
+    /// List the most recent operations that have not been undone, newest first. Operations made by
+    /// the sweep on startup are skipped unless `include_startup_sweeps` is set.
+    pub fn list_undoable_operations(
+        &mut self,
+        limit: i64,
+        include_startup_sweeps: bool,
+    ) -> Result<Vec<LoggedOperation>, FettersError> {
+        let mut query = audit_log::table
+            .filter(audit_log::undone.eq(false))
+            .into_boxed();
+
+        if !include_startup_sweeps {
+            query = query.filter(audit_log::command.ne(STARTUP_SWEEP_COMMAND));
+        }
+
+        let operation_ids: Vec<i32> = query
+            .select(audit_log::operation_id)
+            .distinct()
+            .order(audit_log::operation_id.desc())
+            .limit(limit)
+            .load(self.connection)?;
+
+        self.load_operations(&operation_ids)
+    }
+
+    /// Revert operations in the given order and mark them as undone. The changes in each operation
+    /// are reverted from newest to oldest by restoring their snapshots. All operations are reverted
+    /// in a single transaction.
+    pub fn undo_operations(&mut self, operation_ids: &[i32]) -> Result<(), FettersError> {
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                for operation_id in operation_ids {
+                    let entries = audit_log::table
+                        .filter(audit_log::operation_id.eq(operation_id))
+                        .order(audit_log::id.desc())
+                        .select(QueriedAuditEntry::as_select())
+                        .load(connection)?;
+
+                    for entry in entries.iter() {
+                        revert_entry(connection, entry)?;
+                    }
+
+                    update(audit_log::table.filter(audit_log::operation_id.eq(operation_id)))
+                        .set(audit_log::undone.eq(true))
+                        .execute(connection)?;
+                }
+
+                Ok(())
+            })
+    }
+
+    /// Write a new entry to the audit log. The changed record is passed as its ID and label.
+    fn record<T: Serialize>(
+        &mut self,
+        action: AuditAction,
+        table: AuditTable,
+        (record_id, label): (i32, &str),
+        before: Option<&T>,
+        after: Option<&T>,
+    ) -> Result<(), FettersError> {
+        let before = before.map(serde_json::to_string).transpose()?;
+        let after = after.map(serde_json::to_string).transpose()?;
+        let (operation_id, command) = self.current_operation()?;
+
+        insert_into(audit_log::table)
+            .values(&NewAuditEntry {
+                operation_id,
+                created: &now_timestamp(),
+                command: &command,
+                action: &action.to_string(),
+                table_name: &table.to_string(),
+                record_id,
+                label,
+                before: before.as_deref(),
+                after: after.as_deref(),
+            })
+            .execute(self.connection)?;
+
+        Ok(())
+    }
+
+    /// Get the ID and command of the current operation. The operation is assigned the next ID the
+    /// first time a change is recorded after it was started.
+    fn current_operation(&mut self) -> Result<(i32, String), FettersError> {
+        let mut current_operation = CURRENT_OPERATION
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+
+        let operation_id = match current_operation.0 {
+            Some(operation_id) => operation_id,
+            None => {
+                let last_operation_id: Option<i32> = audit_log::table
+                    .select(max(audit_log::operation_id))
+                    .first(self.connection)?;
+                let operation_id = last_operation_id.unwrap_or_default() + 1;
+                current_operation.0 = Some(operation_id);
+
+                operation_id
+            }
+        };
+
+        let command = current_operation.1.clone().unwrap_or_else(|| {
+            env::args()
+                .skip(1)
+                .map(|arg| {
+                    if arg.contains(char::is_whitespace) {
+                        format!("\"{arg}\"")
+                    } else {
+                        arg
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join(" ")
+        });
+
+        Ok((operation_id, command))
+    }
+
+    /// Load the operations with the given IDs in the given order. The changes in each operation are
+    /// summarized by action and table, listing the name of each changed record once.
+    fn load_operations(
+        &mut self,
+        operation_ids: &[i32],
+    ) -> Result<Vec<LoggedOperation>, FettersError> {
+        let entries = audit_log::table
+            .filter(audit_log::operation_id.eq_any(operation_ids))
+            .order(audit_log::id)
+            .select(QueriedAuditEntry::as_select())
+            .load(self.connection)?;
+
+        let mut operations = Vec::new();
+        for operation_id in operation_ids {
+            let operation_entries: Vec<&QueriedAuditEntry> = entries
+                .iter()
+                .filter(|entry| entry.operation_id == *operation_id)
+                .collect();
+            let Some(first_entry) = operation_entries.first() else {
+                continue;
+            };
+
+            let mut changes: Vec<(String, Vec<&str>)> = Vec::new();
+            for entry in operation_entries.iter() {
+                let change = format!("{} {}", entry.action, entry.table_name);
+                match changes.iter_mut().find(|(existing, _)| *existing == change) {
+                    Some((_, labels)) if !labels.contains(&entry.label.as_str()) => {
+                        labels.push(&entry.label)
+                    }
+                    Some(_) => {}
+                    None => changes.push((change, vec![&entry.label])),
+                }
+            }
+
+            operations.push(LoggedOperation {
+                id: *operation_id,
+                created: first_entry.created.clone(),
+                command: format!("fetters {}", first_entry.command),
+                changes: changes
+                    .iter()
+                    .map(|(change, labels)| format!("{change}: {}", labels.join(", ")))
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+                undone: first_entry.undone,
+            });
+        }
+
+        Ok(operations)
+    }
+}
+
+/// Revert a single audit log entry by restoring the snapshot taken before the change. Inserted
+/// records are deleted. Reverting an update to a job application also restores its status history
+/// and tags, while reverting a deletion restores every record attached to it.
+fn revert_entry(
+    connection: &mut SqliteConnection,
+    entry: &QueriedAuditEntry,
+) -> Result<(), FettersError> {
+    let action = AuditAction::from_str(&entry.action)
+        .map_err(|_| FettersError::InvalidAuditEntry(entry.id))?;
+    let table = AuditTable::from_str(&entry.table_name)
+        .map_err(|_| FettersError::InvalidAuditEntry(entry.id))?;
+
+    match (table, action) {
+        (AuditTable::Jobs, AuditAction::Insert) => {
+            delete_job_records(connection, entry.record_id)?;
+            delete(jobs::table.find(entry.record_id)).execute(connection)?;
+        }
+        (AuditTable::Jobs, AuditAction::Update) => {
+            let snapshot: JobSnapshot = parse_snapshot(entry)?;
+
+            replace_into(jobs::table)
+                .values(&snapshot.job)
+                .execute(connection)?;
+
+            delete(status_changes::table.filter(status_changes::job_id.eq(entry.record_id)))
+                .execute(connection)?;
+            for status_change in snapshot.status_changes.iter() {
+                insert_into(status_changes::table)
+                    .values(status_change)
+                    .execute(connection)?;
+            }
+
+            delete(job_tags::table.filter(job_tags::job_id.eq(entry.record_id)))
+                .execute(connection)?;
+            for job_tag in snapshot.job_tags.iter() {
+                insert_into(job_tags::table)
+                    .values(job_tag)
+                    .execute(connection)?;
+            }
+        }
+        (AuditTable::Jobs, AuditAction::Delete) => {
+            let snapshot: JobSnapshot = parse_snapshot(entry)?;
+
+            // Remove any records left behind so the snapshot can be restored as-is.
+            delete_job_records(connection, entry.record_id)?;
+            replace_into(jobs::table)
+                .values(&snapshot.job)
+                .execute(connection)?;
+
+            for status_change in snapshot.status_changes.iter() {
+                insert_into(status_changes::table)
+                    .values(status_change)
+                    .execute(connection)?;
+            }
+            for interview in snapshot.interviews.iter() {
+                insert_into(interviews::table)
+                    .values(interview)
+                    .execute(connection)?;
+            }
+            for reminder in snapshot.reminders.iter() {
+                insert_into(reminders::table)
+                    .values(reminder)
+                    .execute(connection)?;
+            }
+            if let Some(job_compensation) = snapshot.compensation.as_ref() {
+                insert_into(compensation::table)
+                    .values(job_compensation)
+                    .execute(connection)?;
+            }
+            for job_contact in snapshot.job_contacts.iter() {
+                insert_into(job_contacts::table)
+                    .values(job_contact)
+                    .execute(connection)?;
+            }
+            for job_tag in snapshot.job_tags.iter() {
+                insert_into(job_tags::table)
+                    .values(job_tag)
+                    .execute(connection)?;
+            }
+        }
+        (AuditTable::Sprints, AuditAction::Insert) => {
+            delete(sprints::table.find(entry.record_id)).execute(connection)?;
+        }
+        (AuditTable::Sprints, AuditAction::Update | AuditAction::Delete) => {
+            let snapshot: QueriedSprint = parse_snapshot(entry)?;
+
+            replace_into(sprints::table)
+                .values(&snapshot)
+                .execute(connection)?;
+        }
+        (AuditTable::Titles, AuditAction::Insert) => {
+            delete(titles::table.find(entry.record_id)).execute(connection)?;
+        }
+        (AuditTable::Titles, AuditAction::Update | AuditAction::Delete) => {
+            let snapshot: QueriedTitle = parse_snapshot(entry)?;
+
+            replace_into(titles::table)
+                .values(&snapshot)
+                .execute(connection)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Delete every record attached to a job application.
+fn delete_job_records(connection: &mut SqliteConnection, job_id: i32) -> Result<(), FettersError> {
+    delete(status_changes::table.filter(status_changes::job_id.eq(job_id))).execute(connection)?;
+    delete(interviews::table.filter(interviews::job_id.eq(job_id))).execute(connection)?;
+    delete(reminders::table.filter(reminders::job_id.eq(job_id))).execute(connection)?;
+    delete(compensation::table.find(job_id)).execute(connection)?;
+    delete(job_contacts::table.filter(job_contacts::job_id.eq(job_id))).execute(connection)?;
+    delete(job_tags::table.filter(job_tags::job_id.eq(job_id))).execute(connection)?;
+
+    Ok(())
+}
+
+/// Parse the snapshot taken before the change recorded in an audit log entry.
+fn parse_snapshot<T: DeserializeOwned>(entry: &QueriedAuditEntry) -> Result<T, FettersError> {
+    let snapshot = entry
+        .before
+        .as_deref()
+        .ok_or(FettersError::InvalidAuditEntry(entry.id))?;
+
+    Ok(serde_json::from_str(snapshot)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::job::JobUpdate;
+    use crate::repositories::{
+        job::JobRepository, sprint::SprintRepository, status_change::StatusChangeRepository,
+        statuses::StatusRepository, tag::TagRepository,
+    };
+    use crate::utils::testing::TestDatabase;
+
+    /// Get the ID of the most recent operation that can be undone.
+    fn last_operation_id(connection: &mut SqliteConnection) -> Result<i32, FettersError> {
+        let mut audit_repo = AuditRepository { connection };
+        Ok(audit_repo.list_undoable_operations(1, true)?[0].id)
+    }
+
+    #[test]
+    fn undoing_an_insert_deletes_the_job_and_its_records() -> Result<(), FettersError> {
+        let mut database = TestDatabase::new()?;
+        let job = database.add_job("Acme", "PENDING", "2026-01-01 09:00:00")?;
+
+        let operation_id = last_operation_id(&mut database.connection)?;
+        let mut audit_repo = AuditRepository {
+            connection: &mut database.connection,
+        };
+        audit_repo.undo_operations(&[operation_id])?;
+
+        let remaining_jobs: i64 = jobs::table.count().get_result(&mut database.connection)?;
+        assert_eq!(remaining_jobs, 0);
+
+        let mut status_change_repo = StatusChangeRepository {
+            connection: &mut database.connection,
+        };
+        assert!(status_change_repo.get_status_changes(job.id)?.is_empty());
+
+        let mut sprint_repo = SprintRepository {
+            connection: &mut database.connection,
+        };
+        assert_eq!(sprint_repo.get_current_sprint("test-sprint")?.num_jobs, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn undoing_an_update_restores_fields_status_history_and_tags() -> Result<(), FettersError> {
+        let mut database = TestDatabase::new()?;
+        let job = database.add_job("Acme", "PENDING", "2026-01-01 09:00:00")?;
+        TagRepository {
+            connection: &mut database.connection,
+        }
+        .add_job_tags(job.id, &["remote".to_string()])?;
+
+        let rejected = StatusRepository {
+            connection: &mut database.connection,
+        }
+        .get_status_by_name("REJECTED")?
+        .expect("the default statuses are seeded");
+
+        AuditRepository::start_operation(Some("update"));
+        JobRepository {
+            connection: &mut database.connection,
+        }
+        .update_job(
+            job.id,
+            JobUpdate {
+                company_name: Some("Acme Corp"),
+                status_id: Some(rejected.id),
+                notes: Some(Some("No longer hiring")),
+                ..Default::default()
+            },
+            &["backend".to_string()],
+            &["remote".to_string()],
+        )?;
+
+        let operation_id = last_operation_id(&mut database.connection)?;
+        let mut audit_repo = AuditRepository {
+            connection: &mut database.connection,
+        };
+        audit_repo.undo_operations(&[operation_id])?;
+
+        let restored_job: QueriedJob = jobs::table
+            .find(job.id)
+            .select(QueriedJob::as_select())
+            .first(&mut database.connection)?;
+        assert_eq!(restored_job.company_name, "Acme");
+        assert_eq!(restored_job.status_id, job.status_id);
+        assert_eq!(restored_job.notes, None);
+
+        let mut status_change_repo = StatusChangeRepository {
+            connection: &mut database.connection,
+        };
+        let status_changes = status_change_repo.get_status_changes(job.id)?;
+        assert_eq!(status_changes.len(), 1);
+        assert_eq!(status_changes[0].new_status_id, job.status_id);
+
+        let mut tag_repo = TagRepository {
+            connection: &mut database.connection,
+        };
+        assert_eq!(
+            tag_repo.get_tags_by_job(&[job.id])?[&job.id],
+            vec!["remote".to_string()]
+        );
+
+        let mut audit_repo = AuditRepository {
+            connection: &mut database.connection,
+        };
+        assert!(
+            audit_repo
+                .list_undoable_operations(10, true)?
+                .iter()
+                .all(|operation| operation.id != operation_id)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn undoing_a_purge_restores_the_job_and_its_records() -> Result<(), FettersError> {
+        let mut database = TestDatabase::new()?;
+        let job = database.add_job("Acme", "PENDING", "2026-01-01 09:00:00")?;
+        TagRepository {
+            connection: &mut database.connection,
+        }
+        .add_job_tags(job.id, &["remote".to_string()])?;
+
+        AuditRepository::start_operation(Some("delete"));
+        JobRepository {
+            connection: &mut database.connection,
+        }
+        .delete_job(job.id)?;
+
+        AuditRepository::start_operation(Some("trash purge"));
+        JobRepository {
+            connection: &mut database.connection,
+        }
+        .purge_job(job.id)?;
+
+        let operation_id = last_operation_id(&mut database.connection)?;
+        let mut audit_repo = AuditRepository {
+            connection: &mut database.connection,
+        };
+        audit_repo.undo_operations(&[operation_id])?;
+
+        let restored_job: QueriedJob = jobs::table
+            .find(job.id)
+            .select(QueriedJob::as_select())
+            .first(&mut database.connection)?;
+        assert!(restored_job.deleted_at.is_some());
+
+        let mut status_change_repo = StatusChangeRepository {
+            connection: &mut database.connection,
+        };
+        assert_eq!(status_change_repo.get_status_changes(job.id)?.len(), 1);
+
+        let mut tag_repo = TagRepository {
+            connection: &mut database.connection,
+        };
+        assert_eq!(
+            tag_repo.get_tags_by_job(&[job.id])?[&job.id],
+            vec!["remote".to_string()]
+        );
+
+        // Undoing the deletion as well takes the job back out of the trash.
+        let operation_id = last_operation_id(&mut database.connection)?;
+        let mut audit_repo = AuditRepository {
+            connection: &mut database.connection,
+        };
+        audit_repo.undo_operations(&[operation_id])?;
+
+        let restored_job: QueriedJob = jobs::table
+            .find(job.id)
+            .select(QueriedJob::as_select())
+            .first(&mut database.connection)?;
+        assert!(restored_job.deleted_at.is_none());
+
+        let mut sprint_repo = SprintRepository {
+            connection: &mut database.connection,
+        };
+        assert_eq!(sprint_repo.get_current_sprint("test-sprint")?.num_jobs, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn startup_sweeps_are_only_undoable_when_included() -> Result<(), FettersError> {
+        let mut database = TestDatabase::new()?;
+
+        AuditRepository::start_operation(Some("add"));
+        database.add_job("Acme", "PENDING", "2026-01-01 09:00:00")?;
+
+        AuditRepository::start_operation(Some(STARTUP_SWEEP_COMMAND));
+        database.add_job("Globex", "PENDING", "2026-01-01 09:00:00")?;
+
+        let mut audit_repo = AuditRepository {
+            connection: &mut database.connection,
+        };
+        let operations = audit_repo.list_undoable_operations(10, false)?;
+        assert!(
+            operations
+                .iter()
+                .all(|operation| !operation.command.ends_with(STARTUP_SWEEP_COMMAND))
+        );
+        assert!(
+            operations
+                .iter()
+                .any(|operation| operation.command.ends_with("add"))
+        );
+
+        let operations = audit_repo.list_undoable_operations(10, true)?;
+        assert!(
+            operations
+                .iter()
+                .any(|operation| operation.command.ends_with(STARTUP_SWEEP_COMMAND))
+        );
+
+        Ok(())
+    }
+}
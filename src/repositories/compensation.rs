@@ -0,0 +1,64 @@
+//! Contains the compensation repository abstraction class.
+
+use diesel::prelude::*;
+use diesel::{delete, replace_into};
+
+use crate::errors::FettersError;
+use crate::models::compensation::Compensation;
+use crate::schema::{compensation, jobs, statuses, titles};
+
+/// Contains all methods pertaining to CRUD operations for the `compensation` table.
+pub struct CompensationRepository<'a> {
+    pub connection: &'a mut SqliteConnection,
+}
+
+impl<'a> CompensationRepository<'a> {
+    /// Retrieves the compensation details of a job application.
+    pub fn get_compensation(&mut self, job_id: i32) -> Result<Option<Compensation>, FettersError> {
+        Ok(compensation::table
+            .find(job_id)
+            .select(Compensation::as_select())
+            .first(self.connection)
+            .optional()?)
+    }
+
+    /// Saves the compensation details of a job application, replacing any existing details.
+    pub fn save_compensation(
+        &mut self,
+        job_compensation: &Compensation,
+    ) -> Result<(), FettersError> {
+        replace_into(compensation::table)
+            .values(job_compensation)
+            .execute(self.connection)?;
+
+        Ok(())
+    }
+
+    /// Retrieves the compensation details of all job applications with an offered base salary,
+    /// excluding job applications in the trash. Each offer is returned with the company name, job
+    /// title, and status of its job application.
+    pub fn list_offers(
+        &mut self,
+    ) -> Result<Vec<(Compensation, String, String, String)>, FettersError> {
+        Ok(compensation::table
+            .inner_join(jobs::table.on(compensation::job_id.eq(jobs::id)))
+            .inner_join(statuses::table.on(jobs::status_id.eq(statuses::id)))
+            .inner_join(titles::table.on(jobs::title_id.eq(titles::id)))
+            .filter(compensation::offered_base.is_not_null())
+            .filter(jobs::deleted_at.is_null())
+            .select((
+                Compensation::as_select(),
+                jobs::company_name,
+                titles::name,
+                statuses::name,
+            ))
+            .load(self.connection)?)
+    }
+
+    /// Deletes the compensation details of a job application.
+    pub fn delete_compensation(&mut self, job_id: i32) -> Result<(), FettersError> {
+        delete(compensation::table.find(job_id)).execute(self.connection)?;
+
+        Ok(())
+    }
+}
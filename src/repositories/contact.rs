@@ -0,0 +1,95 @@
+//! Contains the contact repository abstraction class.
+
+use diesel::prelude::*;
+use diesel::{delete, insert_into, insert_or_ignore_into};
+
+use crate::errors::FettersError;
+use crate::models::contact::{JobContact, NewContact, QueriedContact};
+use crate::schema::{contacts, job_contacts};
+use crate::sqlite::lower;
+use crate::utils::dates::now_timestamp;
+
+/// Contains all methods pertaining to CRUD operations for the `contacts` and `job_contacts`
+/// tables.
+pub struct ContactRepository<'a> {
+    pub connection: &'a mut SqliteConnection,
+}
+
+impl<'a> ContactRepository<'a> {
+    /// Adds a new contact into the `contacts` table.
+    pub fn add_contact(&mut self, new_contact: NewContact) -> Result<QueriedContact, FettersError> {
+        Ok(insert_into(contacts::table)
+            .values(&new_contact)
+            .returning(QueriedContact::as_returning())
+            .get_result(self.connection)?)
+    }
+
+    /// Retrieves a contact by its ID.
+    pub fn get_contact(&mut self, contact_id: i32) -> Result<Option<QueriedContact>, FettersError> {
+        Ok(contacts::table
+            .find(contact_id)
+            .select(QueriedContact::as_select())
+            .first(self.connection)
+            .optional()?)
+    }
+
+    /// Lists all contacts ordered by name, optionally filtered by company. The company filter
+    /// supports searching with partial text.
+    pub fn list_contacts(
+        &mut self,
+        company: Option<&str>,
+    ) -> Result<Vec<QueriedContact>, FettersError> {
+        let mut query = contacts::table.into_boxed();
+
+        if let Some(company) = company {
+            query = query.filter(
+                lower(contacts::company.assume_not_null())
+                    .like(format!("%{}%", company.to_lowercase())),
+            );
+        }
+
+        Ok(query
+            .order((contacts::name.asc(), contacts::id.asc()))
+            .select(QueriedContact::as_select())
+            .load(self.connection)?)
+    }
+
+    /// Retrieves all contacts linked to a job application.
+    pub fn get_job_contacts(&mut self, job_id: i32) -> Result<Vec<QueriedContact>, FettersError> {
+        Ok(contacts::table
+            .inner_join(job_contacts::table)
+            .filter(job_contacts::job_id.eq(job_id))
+            .order((contacts::name.asc(), contacts::id.asc()))
+            .select(QueriedContact::as_select())
+            .load(self.connection)?)
+    }
+
+    /// Links a contact to a job application. Linking a contact that is already linked does
+    /// nothing.
+    pub fn link_contact(&mut self, job_id: i32, contact_id: i32) -> Result<(), FettersError> {
+        insert_or_ignore_into(job_contacts::table)
+            .values(&JobContact {
+                job_id,
+                contact_id,
+                linked: Some(now_timestamp()),
+            })
+            .execute(self.connection)?;
+
+        Ok(())
+    }
+
+    /// Unlinks a contact from a job application.
+    pub fn unlink_contact(&mut self, job_id: i32, contact_id: i32) -> Result<(), FettersError> {
+        delete(job_contacts::table.find((job_id, contact_id))).execute(self.connection)?;
+
+        Ok(())
+    }
+
+    /// Unlinks all contacts from a job application.
+    pub fn delete_job_contacts(&mut self, job_id: i32) -> Result<(), FettersError> {
+        delete(job_contacts::table.filter(job_contacts::job_id.eq(job_id)))
+            .execute(self.connection)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+//! Contains the integrity repository abstraction class.
+
+use std::collections::HashMap;
+
+use diesel::dsl::{count, exists, not};
+use diesel::prelude::*;
+
+use crate::errors::FettersError;
+use crate::models::{
+    integrity::IntegrityReport, job::JobUpdate, sprint::QueriedSprint, title::QueriedTitle,
+};
+use crate::repositories::{job::JobRepository, sprint::SprintRepository, title::TitleRepository};
+use crate::schema::{jobs, sprints, status_changes, statuses, titles};
+
+/// Contains all methods pertaining to detecting and repairing integrity problems across tables.
+pub struct IntegrityRepository<'a> {
+    pub connection: &'a mut SqliteConnection,
+}
+
+impl<'a> IntegrityRepository<'a> {
+    /// Check the database for drifted sprint job counts, orphaned titles, and job applications or
+    /// status history entries referencing missing sprints or statuses.
+    pub fn check(&mut self) -> Result<IntegrityReport, FettersError> {
+        // Jobs in the trash do not count towards a sprint's `num_jobs` count.
+        let job_counts: HashMap<i32, i64> = jobs::table
+            .filter(jobs::deleted_at.is_null())
+            .group_by(jobs::sprint_id)
+            .select((jobs::sprint_id, count(jobs::id)))
+            .load::<(i32, i64)>(self.connection)?
+            .into_iter()
+            .collect();
+        let drifted_sprints = sprints::table
+            .select(QueriedSprint::as_select())
+            .order(sprints::id)
+            .load(self.connection)?
+            .into_iter()
+            .filter_map(|sprint| {
+                let actual = job_counts.get(&sprint.id).copied().unwrap_or_default();
+                (sprint.num_jobs as i64 != actual).then_some((sprint, actual))
+            })
+            .collect();
+
+        let orphaned_titles = titles::table
+            .filter(not(exists(
+                jobs::table.filter(jobs::title_id.eq(titles::id)),
+            )))
+            .select(QueriedTitle::as_select())
+            .order(titles::id)
+            .load(self.connection)?;
+
+        let jobs_missing_sprints = jobs::table
+            .filter(not(exists(
+                sprints::table.filter(sprints::id.eq(jobs::sprint_id)),
+            )))
+            .select((jobs::id, jobs::company_name, jobs::sprint_id))
+            .order(jobs::id)
+            .load(self.connection)?;
+
+        let jobs_missing_statuses = jobs::table
+            .filter(not(exists(
+                statuses::table.filter(statuses::id.eq(jobs::status_id)),
+            )))
+            .select((jobs::id, jobs::company_name, jobs::status_id))
+            .order(jobs::id)
+            .load(self.connection)?;
+
+        let mut history_missing_statuses: Vec<(i32, String, i32)> = jobs::table
+            .inner_join(status_changes::table.on(status_changes::job_id.eq(jobs::id)))
+            .filter(not(exists(
+                statuses::table.filter(statuses::id.eq(status_changes::new_status_id)),
+            )))
+            .select((jobs::id, jobs::company_name, status_changes::new_status_id))
+            .load(self.connection)?;
+        history_missing_statuses.extend(
+            jobs::table
+                .inner_join(status_changes::table.on(status_changes::job_id.eq(jobs::id)))
+                .filter(status_changes::old_status_id.is_not_null())
+                .filter(not(exists(statuses::table.filter(
+                    statuses::id.nullable().eq(status_changes::old_status_id),
+                ))))
+                .select((
+                    jobs::id,
+                    jobs::company_name,
+                    status_changes::old_status_id.assume_not_null(),
+                ))
+                .load::<(i32, String, i32)>(self.connection)?,
+        );
+        history_missing_statuses.sort_by_key(|(job_id, _, status_id)| (*job_id, *status_id));
+        history_missing_statuses.dedup_by_key(|(job_id, _, status_id)| (*job_id, *status_id));
+
+        Ok(IntegrityReport {
+            drifted_sprints,
+            orphaned_titles,
+            jobs_missing_sprints,
+            jobs_missing_statuses,
+            history_missing_statuses,
+        })
+    }
+
+    /// Repair all problems in an integrity report in a single transaction. Job applications
+    /// referencing missing sprints or statuses are moved to the fallback sprint or status, status
+    /// history entries referencing missing statuses are moved to the fallback status, orphaned
+    /// titles are deleted, and the `num_jobs` count of every sprint that still drifted afterwards is
+    /// recalculated. Every repair is recorded in the audit log.
+    pub fn repair(
+        &mut self,
+        report: &IntegrityReport,
+        fallback_sprint_id: i32,
+        fallback_status_id: i32,
+    ) -> Result<(), FettersError> {
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                let mut job_repo = JobRepository { connection };
+                for (job_id, _, _) in report.jobs_missing_sprints.iter() {
+                    job_repo.update_job(
+                        *job_id,
+                        JobUpdate {
+                            sprint_id: Some(fallback_sprint_id),
+                            ..Default::default()
+                        },
+                        &[],
+                        &[],
+                    )?;
+                }
+
+                // Job applications and status history entries referencing a missing status are
+                // moved to the fallback status without recording a new status change.
+                let mut missing_status_ids: Vec<i32> = report
+                    .jobs_missing_statuses
+                    .iter()
+                    .chain(report.history_missing_statuses.iter())
+                    .map(|(_, _, status_id)| *status_id)
+                    .collect();
+                missing_status_ids.sort_unstable();
+                missing_status_ids.dedup();
+
+                for status_id in missing_status_ids {
+                    job_repo.replace_status(status_id, fallback_status_id)?;
+                }
+
+                let mut title_repo = TitleRepository { connection };
+                for title in report.orphaned_titles.iter() {
+                    title_repo.delete_title(title.id)?;
+                }
+
+                // Moving job applications adjusts the `num_jobs` counts, so the counts are
+                // checked again before recalculating them.
+                let mut integrity_repo = IntegrityRepository { connection };
+                let drifted_sprints = integrity_repo.check()?.drifted_sprints;
+
+                let mut sprint_repo = SprintRepository { connection };
+                for (sprint, _) in drifted_sprints.iter() {
+                    sprint_repo.recount_num_jobs(sprint.id)?;
+                }
+
+                Ok(())
+            })
+    }
+}
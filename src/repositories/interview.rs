@@ -0,0 +1,86 @@
+//! Contains the interview repository abstraction class.
+
+use diesel::prelude::*;
+use diesel::{delete, insert_into, update};
+
+use crate::errors::FettersError;
+use crate::models::interview::{InterviewUpdate, NewInterview, QueriedInterview, TabledInterview};
+use crate::schema::{interviews, jobs};
+
+/// Contains all methods pertaining to CRUD operations for the `interviews` table.
+pub struct InterviewRepository<'a> {
+    pub connection: &'a mut SqliteConnection,
+}
+
+impl<'a> InterviewRepository<'a> {
+    /// Adds a new interview into the `interviews` table.
+    pub fn add_interview(
+        &mut self,
+        new_interview: NewInterview,
+    ) -> Result<QueriedInterview, FettersError> {
+        Ok(insert_into(interviews::table)
+            .values(&new_interview)
+            .returning(QueriedInterview::as_returning())
+            .get_result(self.connection)?)
+    }
+
+    /// Retrieves all interviews for a job ordered by their scheduled timestamp.
+    pub fn get_interviews(&mut self, job_id: i32) -> Result<Vec<QueriedInterview>, FettersError> {
+        Ok(interviews::table
+            .filter(interviews::job_id.eq(job_id))
+            .order((interviews::scheduled.asc(), interviews::id.asc()))
+            .select(QueriedInterview::as_select())
+            .load(self.connection)?)
+    }
+
+    /// Retrieves all interviews for the given jobs with the company name of each job.
+    pub fn list_interviews(
+        &mut self,
+        job_ids: &[i32],
+    ) -> Result<Vec<TabledInterview>, FettersError> {
+        Ok(interviews::table
+            .inner_join(jobs::table.on(interviews::job_id.eq(jobs::id)))
+            .filter(interviews::job_id.eq_any(job_ids))
+            .order((interviews::scheduled.asc(), interviews::id.asc()))
+            .select((
+                interviews::id,
+                jobs::company_name,
+                interviews::round,
+                interviews::interview_type,
+                interviews::scheduled,
+                interviews::interviewers,
+                interviews::outcome,
+                interviews::notes,
+            ))
+            .load::<TabledInterview>(self.connection)?)
+    }
+
+    /// Updates an existing interview with new changes.
+    pub fn update_interview(
+        &mut self,
+        interview_id: i32,
+        changes: InterviewUpdate,
+    ) -> Result<QueriedInterview, FettersError> {
+        Ok(update(interviews::table.find(interview_id))
+            .set(&changes)
+            .returning(QueriedInterview::as_returning())
+            .get_result(self.connection)?)
+    }
+
+    /// Deletes an existing interview.
+    pub fn delete_interview(
+        &mut self,
+        interview_id: i32,
+    ) -> Result<QueriedInterview, FettersError> {
+        Ok(delete(interviews::table.find(interview_id))
+            .returning(QueriedInterview::as_returning())
+            .get_result(self.connection)?)
+    }
+
+    /// Deletes all interviews for a job.
+    pub fn delete_interviews(&mut self, job_id: i32) -> Result<(), FettersError> {
+        delete(interviews::table.filter(interviews::job_id.eq(job_id))).execute(self.connection)?;
+
+        Ok(())
+    }
+}
@@ -1,19 +1,65 @@
 //! Contains the job repository abstraction class.
 
-use diesel::dsl::count;
+use std::collections::HashMap;
+
+use chrono::Duration;
+use diesel::dsl::{count, max};
 use diesel::prelude::*;
+use diesel::sql_types::{Nullable, Text};
 use diesel::sqlite::Sqlite;
 use diesel::{delete, insert_into, update};
 
 use crate::cli::QueryArgs;
 use crate::errors::FettersError;
-use crate::models::insight::CountAndPercentage;
+use crate::models::insight::{
+    CountAndPercentage, DurationOutlier, DurationStats, FunnelStage, SourceInsight,
+};
 use crate::models::{
-    job::{JobUpdate, NewJob, QueriedJob, TabledJob},
+    audit::{AuditAction, AuditTable},
+    job::{JobUpdate, NewJob, QueriedJob, TabledJob, TrashedJob, WorkMode},
     sprint::QueriedSprint,
+    status_change::NewStatusChange,
+};
+use crate::repositories::{
+    audit::AuditRepository, compensation::CompensationRepository, contact::ContactRepository,
+    interview::InterviewRepository, reminder::ReminderRepository, sprint::SprintRepository,
+    status_change::StatusChangeRepository, tag::TagRepository,
+};
+use crate::schema::{
+    audit_log, interviews, job_contacts, job_tags, jobs, reminders, sources, sprints,
+    status_changes, statuses, tags, titles,
+};
+use crate::utils::{
+    dates::{TIMESTAMP_FORMAT, now_timestamp, parse_timestamp},
+    tags::normalize_tags,
 };
-use crate::repositories::sprint::SprintRepository;
-use crate::schema::{jobs, sprints, statuses, titles};
+
+/// Statuses that do not count as a response from the company in insights.
+const NO_RESPONSE_STATUSES: [&str; 2] = ["PENDING", "GHOSTED"];
+
+/// Statuses that count as an offer in insights.
+const OFFER_STATUSES: [&str; 2] = ["OFFER RECEIVED", "HIRED"];
+
+/// The stages of the application funnel after the initial application, each with the statuses
+/// that show a job application reached the stage. Reaching a later stage implies reaching all
+/// earlier stages.
+const FUNNEL_STAGES: [(&str, &[&str]); 3] = [
+    ("IN PROGRESS", &["IN PROGRESS"]),
+    ("OFFER RECEIVED", &["OFFER RECEIVED"]),
+    ("HIRED", &["HIRED"]),
+];
+
+/// The stage name used for the time between applying and receiving a first response.
+const FIRST_RESPONSE_STAGE: &str = "FIRST RESPONSE";
+
+/// The stage name used for job applications that are still waiting for a first response.
+const AWAITING_RESPONSE_STAGE: &str = "AWAITING RESPONSE";
+
+/// A duration is an outlier if it is longer than the historical median multiplied by this factor.
+const OUTLIER_FACTOR: f64 = 2.0;
+
+/// The minimum number of historical durations required before flagging outliers for a stage.
+const MIN_OUTLIER_SAMPLES: usize = 3;
 
 /// Contains all methods pertaining to CRUD operations for the `jobs` table.
 pub struct JobRepository<'a> {
@@ -21,51 +67,466 @@ pub struct JobRepository<'a> {
 }
 
 impl<'a> JobRepository<'a> {
-    /// Adds a new job to the `jobs` table.
-    pub fn add_job(&mut self, new_job: NewJob) -> Result<QueriedJob, FettersError> {
+    /// Adds a new job to the `jobs` table with the given tags. The job is inserted, its sprint's
+    /// `num_jobs` count is incremented, and the insert is recorded in the audit log in a single
+    /// transaction.
+    pub fn add_job(
+        &mut self,
+        new_job: NewJob,
+        tag_names: &[String],
+    ) -> Result<QueriedJob, FettersError> {
         use crate::schema::jobs::dsl::*;
 
-        let queried_job = insert_into(jobs)
-            .values(&new_job)
-            .returning(QueriedJob::as_returning())
-            .get_result(self.connection)?;
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                let queried_job = insert_into(jobs)
+                    .values(&new_job)
+                    .returning(QueriedJob::as_returning())
+                    .get_result(connection)?;
 
-        let mut sprint_repo = SprintRepository {
-            connection: self.connection,
-        };
-        sprint_repo.increment_num_jobs(new_job.sprint_id)?;
+                let mut sprint_repo = SprintRepository { connection };
+                sprint_repo.increment_num_jobs(new_job.sprint_id)?;
+
+                // Record the initial status as the first entry in the job's status history.
+                let mut status_change_repo = StatusChangeRepository { connection };
+                status_change_repo.add_status_change(NewStatusChange {
+                    job_id: queried_job.id,
+                    old_status_id: None,
+                    new_status_id: queried_job.status_id,
+                    changed: &queried_job.created,
+                })?;
+
+                let mut tag_repo = TagRepository { connection };
+                tag_repo.add_job_tags(queried_job.id, tag_names)?;
 
-        Ok(queried_job)
+                let mut audit_repo = AuditRepository { connection };
+                let snapshot = audit_repo.snapshot_job(queried_job.id)?;
+                audit_repo.record_job(AuditAction::Insert, None, Some(&snapshot))?;
+
+                Ok(queried_job)
+            })
     }
 
-    /// Updates an existing job with new changes.
+    /// Updates an existing job with new changes and adds or removes the given tags. A status
+    /// change is recorded in the job's status history if the status was modified, and the
+    /// `num_jobs` counts of both sprints are adjusted if a job that is not in the trash was moved
+    /// to a different sprint. The update, including the tag changes, is recorded in the audit
+    /// log. All changes are made in a single transaction.
     pub fn update_job(
         &mut self,
         job_id: i32,
         changes: JobUpdate,
+        add_tags: &[String],
+        remove_tags: &[String],
     ) -> Result<QueriedJob, FettersError> {
         use crate::schema::jobs::dsl::*;
 
-        Ok(update(jobs.find(job_id))
-            .set(&changes)
-            .returning(QueriedJob::as_returning())
-            .get_result(self.connection)?)
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                let mut audit_repo = AuditRepository { connection };
+                let previous_snapshot = audit_repo.snapshot_job(job_id)?;
+                let previous_job = &previous_snapshot.job;
+
+                let queried_job = if changes.has_changes() {
+                    update(jobs.find(job_id))
+                        .set(&changes)
+                        .returning(QueriedJob::as_returning())
+                        .get_result(connection)?
+                } else {
+                    jobs.find(job_id)
+                        .select(QueriedJob::as_select())
+                        .first(connection)?
+                };
+
+                if previous_job.sprint_id != queried_job.sprint_id
+                    && queried_job.deleted_at.is_none()
+                {
+                    let mut sprint_repo = SprintRepository { connection };
+                    sprint_repo.decrement_num_jobs(previous_job.sprint_id)?;
+                    sprint_repo.increment_num_jobs(queried_job.sprint_id)?;
+                }
+
+                if previous_job.status_id != queried_job.status_id {
+                    let mut status_change_repo = StatusChangeRepository { connection };
+                    status_change_repo.add_status_change(NewStatusChange {
+                        job_id,
+                        old_status_id: Some(previous_job.status_id),
+                        new_status_id: queried_job.status_id,
+                        changed: &now_timestamp(),
+                    })?;
+                }
+
+                let mut tag_repo = TagRepository { connection };
+                tag_repo.add_job_tags(job_id, add_tags)?;
+                tag_repo.remove_job_tags(job_id, remove_tags)?;
+
+                let mut audit_repo = AuditRepository { connection };
+                let snapshot = audit_repo.snapshot_job(job_id)?;
+                audit_repo.record_job(
+                    AuditAction::Update,
+                    Some(&previous_snapshot),
+                    Some(&snapshot),
+                )?;
+
+                Ok(queried_job)
+            })
     }
 
-    /// Deletes an existing job.
+    /// Moves an existing job to the trash. The job's `deleted_at` timestamp is set, its sprint's
+    /// `num_jobs` count is decremented, and the deletion is recorded in the audit log in a single
+    /// transaction. Records referencing the job are kept so it can be restored.
     pub fn delete_job(&mut self, job_id: i32) -> Result<QueriedJob, FettersError> {
         use crate::schema::jobs::dsl::*;
 
-        let queried_job = delete(jobs.find(job_id))
-            .returning(QueriedJob::as_returning())
-            .get_result(self.connection)?;
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                let mut audit_repo = AuditRepository { connection };
+                let snapshot = audit_repo.snapshot_job(job_id)?;
 
-        let mut sprint_repo = SprintRepository {
-            connection: self.connection,
-        };
-        sprint_repo.decrement_num_jobs(queried_job.sprint_id)?;
+                let queried_job = update(jobs.find(job_id))
+                    .set(deleted_at.eq(Some(now_timestamp())))
+                    .returning(QueriedJob::as_returning())
+                    .get_result(connection)?;
 
-        Ok(queried_job)
+                let mut sprint_repo = SprintRepository { connection };
+                sprint_repo.decrement_num_jobs(queried_job.sprint_id)?;
+
+                let mut audit_repo = AuditRepository { connection };
+                audit_repo.record_job(AuditAction::Delete, Some(&snapshot), None)?;
+
+                Ok(queried_job)
+            })
+    }
+
+    /// Restores a job from the trash. The job's `deleted_at` timestamp is cleared, its sprint's
+    /// `num_jobs` count is incremented, and the update is recorded in the audit log in a single
+    /// transaction.
+    pub fn restore_job(&mut self, job_id: i32) -> Result<QueriedJob, FettersError> {
+        use crate::schema::jobs::dsl::*;
+
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                let mut audit_repo = AuditRepository { connection };
+                let previous_snapshot = audit_repo.snapshot_job(job_id)?;
+
+                let queried_job = update(jobs.find(job_id))
+                    .set(deleted_at.eq(None::<String>))
+                    .returning(QueriedJob::as_returning())
+                    .get_result(connection)?;
+
+                let mut sprint_repo = SprintRepository { connection };
+                sprint_repo.increment_num_jobs(queried_job.sprint_id)?;
+
+                let mut audit_repo = AuditRepository { connection };
+                let snapshot = audit_repo.snapshot_job(job_id)?;
+                audit_repo.record_job(
+                    AuditAction::Update,
+                    Some(&previous_snapshot),
+                    Some(&snapshot),
+                )?;
+
+                Ok(queried_job)
+            })
+    }
+
+    /// Permanently deletes a job in the trash and all records referencing it. A snapshot of the
+    /// job and its records is stored in the audit log in the same transaction. The sprint's
+    /// `num_jobs` count is left untouched because it was already decremented when the job was
+    /// moved to the trash.
+    pub fn purge_job(&mut self, job_id: i32) -> Result<QueriedJob, FettersError> {
+        use crate::schema::jobs::dsl::*;
+
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                let mut audit_repo = AuditRepository { connection };
+                let snapshot = audit_repo.snapshot_job(job_id)?;
+
+                let queried_job = delete(jobs.find(job_id))
+                    .returning(QueriedJob::as_returning())
+                    .get_result(connection)?;
+
+                let mut status_change_repo = StatusChangeRepository { connection };
+                status_change_repo.delete_status_changes(queried_job.id)?;
+
+                let mut interview_repo = InterviewRepository { connection };
+                interview_repo.delete_interviews(queried_job.id)?;
+
+                let mut contact_repo = ContactRepository { connection };
+                contact_repo.delete_job_contacts(queried_job.id)?;
+
+                let mut reminder_repo = ReminderRepository { connection };
+                reminder_repo.delete_reminders(queried_job.id)?;
+
+                let mut tag_repo = TagRepository { connection };
+                tag_repo.delete_job_tags(queried_job.id)?;
+
+                let mut compensation_repo = CompensationRepository { connection };
+                compensation_repo.delete_compensation(queried_job.id)?;
+
+                let mut audit_repo = AuditRepository { connection };
+                audit_repo.record_job(AuditAction::Delete, Some(&snapshot), None)?;
+
+                Ok(queried_job)
+            })
+    }
+
+    /// Apply the same changes to multiple jobs and add or remove the given tags for each job in a
+    /// single transaction. Status changes, sprint `num_jobs` counts, and the audit log are handled
+    /// the same way as when updating a single job.
+    pub fn update_jobs(
+        &mut self,
+        job_ids: &[i32],
+        changes: &JobUpdate,
+        add_tags: &[String],
+        remove_tags: &[String],
+    ) -> Result<(), FettersError> {
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                let mut job_repo = JobRepository { connection };
+                for job_id in job_ids {
+                    job_repo.update_job(*job_id, changes.clone(), add_tags, remove_tags)?;
+                }
+
+                Ok(())
+            })
+    }
+
+    /// Move every job and status history entry referencing a status to a replacement status in a
+    /// single transaction. Unlike a status update, no new status change is recorded, since the
+    /// history is rewritten instead. Each affected job is recorded in the audit log.
+    pub fn replace_status(
+        &mut self,
+        status_id: i32,
+        replacement_id: i32,
+    ) -> Result<(), FettersError> {
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                let mut job_ids: Vec<i32> = jobs::table
+                    .filter(jobs::status_id.eq(status_id))
+                    .select(jobs::id)
+                    .load(connection)?;
+                job_ids.extend(
+                    status_changes::table
+                        .filter(
+                            status_changes::new_status_id
+                                .eq(status_id)
+                                .or(status_changes::old_status_id.eq(status_id)),
+                        )
+                        .select(status_changes::job_id)
+                        .load::<i32>(connection)?,
+                );
+                job_ids.sort_unstable();
+                job_ids.dedup();
+
+                for job_id in job_ids {
+                    let mut audit_repo = AuditRepository { connection };
+                    let previous_snapshot = audit_repo.snapshot_job(job_id)?;
+
+                    update(
+                        jobs::table
+                            .find(job_id)
+                            .filter(jobs::status_id.eq(status_id)),
+                    )
+                    .set(jobs::status_id.eq(replacement_id))
+                    .execute(connection)?;
+                    update(
+                        status_changes::table
+                            .filter(status_changes::job_id.eq(job_id))
+                            .filter(status_changes::new_status_id.eq(status_id)),
+                    )
+                    .set(status_changes::new_status_id.eq(replacement_id))
+                    .execute(connection)?;
+                    update(
+                        status_changes::table
+                            .filter(status_changes::job_id.eq(job_id))
+                            .filter(status_changes::old_status_id.eq(status_id)),
+                    )
+                    .set(status_changes::old_status_id.eq(replacement_id))
+                    .execute(connection)?;
+
+                    let mut audit_repo = AuditRepository { connection };
+                    let snapshot = audit_repo.snapshot_job(job_id)?;
+                    audit_repo.record_job(
+                        AuditAction::Update,
+                        Some(&previous_snapshot),
+                        Some(&snapshot),
+                    )?;
+                }
+
+                Ok(())
+            })
+    }
+
+    /// Set the status of multiple jobs in a single transaction. A status change is recorded for
+    /// each job whose status was modified.
+    pub fn set_status_for_jobs(
+        &mut self,
+        job_ids: &[i32],
+        status_id: i32,
+    ) -> Result<(), FettersError> {
+        self.update_jobs(
+            job_ids,
+            &JobUpdate {
+                status_id: Some(status_id),
+                ..Default::default()
+            },
+            &[],
+            &[],
+        )
+    }
+
+    /// Move multiple jobs to the trash in a single transaction. The `num_jobs` count of each
+    /// affected sprint is decremented.
+    pub fn delete_jobs(&mut self, job_ids: &[i32]) -> Result<(), FettersError> {
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                let mut job_repo = JobRepository { connection };
+                for job_id in job_ids {
+                    job_repo.delete_job(*job_id)?;
+                }
+
+                Ok(())
+            })
+    }
+
+    /// Restore multiple jobs from the trash in a single transaction. The `num_jobs` count of each
+    /// affected sprint is incremented.
+    pub fn restore_jobs(&mut self, job_ids: &[i32]) -> Result<(), FettersError> {
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                let mut job_repo = JobRepository { connection };
+                for job_id in job_ids {
+                    job_repo.restore_job(*job_id)?;
+                }
+
+                Ok(())
+            })
+    }
+
+    /// Permanently delete multiple jobs in the trash and all records referencing them in a single
+    /// transaction.
+    pub fn purge_jobs(&mut self, job_ids: &[i32]) -> Result<(), FettersError> {
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                let mut job_repo = JobRepository { connection };
+                for job_id in job_ids {
+                    job_repo.purge_job(*job_id)?;
+                }
+
+                Ok(())
+            })
+    }
+
+    /// List all jobs in the trash, oldest deletion first. Only jobs moved to the trash before
+    /// `deleted_before` are listed if it is provided.
+    pub fn list_trashed_jobs(
+        &mut self,
+        deleted_before: Option<&str>,
+    ) -> Result<Vec<TrashedJob>, FettersError> {
+        let mut query = jobs::table
+            .left_join(titles::table.on(jobs::title_id.eq(titles::id)))
+            .left_join(statuses::table.on(jobs::status_id.eq(statuses::id)))
+            .left_join(sprints::table.on(jobs::sprint_id.eq(sprints::id)))
+            .filter(jobs::deleted_at.is_not_null())
+            .select((
+                jobs::id,
+                jobs::deleted_at.assume_not_null(),
+                jobs::company_name,
+                titles::name.nullable(),
+                statuses::name.nullable(),
+                sprints::name.nullable(),
+            ))
+            .order((jobs::deleted_at.asc(), jobs::id.asc()))
+            .into_boxed::<Sqlite>();
+
+        if let Some(deleted_before) = deleted_before {
+            query = query.filter(jobs::deleted_at.lt(deleted_before));
+        }
+
+        Ok(query.load::<TrashedJob>(self.connection)?)
+    }
+
+    /// Find the IDs of all jobs in a status whose last activity is more than `inactivity_days`
+    /// days old. The last activity of a job is the latest of its creation, its status changes,
+    /// the edits recorded in the audit log, its past interviews, the due dates of its manual
+    /// reminders, and the times contacts were linked to it.
+    pub fn find_inactive_jobs(
+        &mut self,
+        status_name: &str,
+        inactivity_days: i64,
+    ) -> Result<Vec<i32>, FettersError> {
+        let now = now_timestamp();
+        let cutoff = parse_timestamp(&now)
+            .map(|now| now - Duration::days(inactivity_days))
+            .map(|cutoff| cutoff.format(TIMESTAMP_FORMAT).to_string())
+            .unwrap_or(now.clone());
+
+        let mut last_activity: HashMap<i32, String> = jobs::table
+            .inner_join(statuses::table.on(jobs::status_id.eq(statuses::id)))
+            .filter(statuses::name.eq(status_name))
+            .filter(jobs::deleted_at.is_null())
+            .select((jobs::id, jobs::created))
+            .load::<(i32, String)>(self.connection)?
+            .into_iter()
+            .collect();
+        let job_ids: Vec<i32> = last_activity.keys().copied().collect();
+
+        let status_activity = status_changes::table
+            .filter(status_changes::job_id.eq_any(&job_ids))
+            .group_by(status_changes::job_id)
+            .select((status_changes::job_id, max(status_changes::changed)))
+            .load::<(i32, Option<String>)>(self.connection)?;
+        let interview_activity = interviews::table
+            .filter(interviews::job_id.eq_any(&job_ids))
+            .filter(interviews::scheduled.le(&now))
+            .group_by(interviews::job_id)
+            .select((interviews::job_id, max(interviews::scheduled)))
+            .load::<(i32, Option<String>)>(self.connection)?;
+        // Undone edits still count, so job applications whose sweep was undone are not swept
+        // again on the next run.
+        let edit_activity = audit_log::table
+            .filter(audit_log::table_name.eq(AuditTable::Jobs.to_string()))
+            .filter(audit_log::action.eq(AuditAction::Update.to_string()))
+            .filter(audit_log::record_id.eq_any(&job_ids))
+            .group_by(audit_log::record_id)
+            .select((audit_log::record_id, max(audit_log::created)))
+            .load::<(i32, Option<String>)>(self.connection)?;
+        // A manual reminder is a planned follow-up, so it counts as activity until it is due.
+        // Automatic reminders are created for every job and do not count.
+        let reminder_activity = reminders::table
+            .filter(reminders::job_id.eq_any(&job_ids))
+            .filter(reminders::automatic.eq(false))
+            .group_by(reminders::job_id)
+            .select((reminders::job_id, max(reminders::due)))
+            .load::<(i32, Option<String>)>(self.connection)?;
+        let contact_activity = job_contacts::table
+            .filter(job_contacts::job_id.eq_any(&job_ids))
+            .group_by(job_contacts::job_id)
+            .select((job_contacts::job_id, max(job_contacts::linked)))
+            .load::<(i32, Option<String>)>(self.connection)?;
+
+        for (job_id, activity) in status_activity
+            .into_iter()
+            .chain(interview_activity)
+            .chain(edit_activity)
+            .chain(reminder_activity)
+            .chain(contact_activity)
+        {
+            if let (Some(latest), Some(activity)) = (last_activity.get_mut(&job_id), activity)
+                && activity > *latest
+            {
+                *latest = activity;
+            }
+        }
+
+        let mut inactive_job_ids: Vec<i32> = last_activity
+            .into_iter()
+            .filter(|(_, latest)| *latest < cutoff)
+            .map(|(job_id, _)| job_id)
+            .collect();
+        inactive_job_ids.sort();
+
+        Ok(inactive_job_ids)
     }
 
     /// List all jobs matching the query.
@@ -73,11 +534,28 @@ impl<'a> JobRepository<'a> {
         &mut self,
         query_args: &QueryArgs,
         current_sprint: &QueriedSprint,
+    ) -> Result<Vec<TabledJob>, FettersError> {
+        self.query_jobs(query_args, Some(current_sprint), None)
+    }
+
+    /// List the jobs with the given IDs across all sprints.
+    pub fn list_jobs_by_ids(&mut self, job_ids: &[i32]) -> Result<Vec<TabledJob>, FettersError> {
+        self.query_jobs(&QueryArgs::default(), None, Some(job_ids))
+    }
+
+    /// Query jobs matching the `QueryArgs`. Jobs are limited to the current sprint unless a sprint
+    /// filter, a job ID, or a list of job IDs is provided. Jobs in the trash are never included.
+    fn query_jobs(
+        &mut self,
+        query_args: &QueryArgs,
+        current_sprint: Option<&QueriedSprint>,
+        job_ids: Option<&[i32]>,
     ) -> Result<Vec<TabledJob>, FettersError> {
         let mut query = jobs::table
             .left_join(titles::table.on(jobs::title_id.eq(titles::id)))
             .left_join(statuses::table.on(jobs::status_id.eq(statuses::id)))
             .left_join(sprints::table.on(jobs::sprint_id.eq(sprints::id)))
+            .left_join(sources::table.on(jobs::source_id.eq(sources::id.nullable())))
             .select((
                 jobs::id,
                 jobs::created,
@@ -86,15 +564,33 @@ impl<'a> JobRepository<'a> {
                 statuses::name.nullable(),
                 jobs::link,
                 jobs::notes,
+                jobs::work_mode,
+                jobs::location,
+                jobs::country,
+                sources::name.nullable(),
+                // Tags are filled in after the query.
+                None::<String>.into_sql::<Nullable<Text>>(),
+                statuses::color.nullable(),
             ))
+            .filter(jobs::deleted_at.is_null())
             .into_boxed::<Sqlite>();
 
         if let Some(sprint) = &query_args.sprint {
             query = query.filter(sprints::name.like(format!("%{}%", sprint)));
-        } else {
+        } else if query_args.id.is_none()
+            && let Some(current_sprint) = current_sprint
+        {
             query = query.filter(sprints::id.eq(current_sprint.id));
         }
 
+        if let Some(job_ids) = job_ids {
+            query = query.filter(jobs::id.eq_any(job_ids.to_vec()));
+        }
+
+        if let Some(job_id) = query_args.id {
+            query = query.filter(jobs::id.eq(job_id));
+        }
+
         if let Some(company) = &query_args.company {
             query = query.filter(jobs::company_name.like(format!("%{}%", company)));
         }
@@ -115,17 +611,72 @@ impl<'a> JobRepository<'a> {
             query = query.filter(titles::name.like(format!("%{}%", title)));
         }
 
-        Ok(query.load::<TabledJob>(self.connection)?)
+        if let Some(location) = &query_args.location {
+            query = query.filter(jobs::location.like(format!("%{}%", location)));
+        }
+
+        if let Some(country) = &query_args.country {
+            query = query.filter(jobs::country.like(format!("%{}%", country)));
+        }
+
+        if let Some(source) = &query_args.source {
+            query = query.filter(sources::name.like(format!("%{}%", source)));
+        }
+
+        if let Some(work_mode) = &query_args.work_mode {
+            query = query.filter(jobs::work_mode.eq(WorkMode::parse(work_mode)?.to_string()));
+        }
+
+        let tag_names = normalize_tags(&query_args.tag);
+        if query_args.any_tag {
+            query = query.filter(
+                jobs::id.eq_any(
+                    job_tags::table
+                        .inner_join(tags::table)
+                        .filter(tags::name.eq_any(tag_names))
+                        .select(job_tags::job_id),
+                ),
+            );
+        } else {
+            for tag_name in tag_names {
+                query = query.filter(
+                    jobs::id.eq_any(
+                        job_tags::table
+                            .inner_join(tags::table)
+                            .filter(tags::name.eq(tag_name))
+                            .select(job_tags::job_id),
+                    ),
+                );
+            }
+        }
+
+        let mut matched_jobs = query.load::<TabledJob>(self.connection)?;
+
+        let job_ids: Vec<i32> = matched_jobs.iter().map(|job| job.id).collect();
+        let mut tag_repo = TagRepository {
+            connection: self.connection,
+        };
+        let mut tags_by_job = tag_repo.get_tags_by_job(&job_ids)?;
+        for job in matched_jobs.iter_mut() {
+            job.tags = tags_by_job
+                .remove(&job.id)
+                .map(|job_tags| job_tags.join(", "));
+        }
+
+        Ok(matched_jobs)
     }
 
-    /// Get the total number of jobs in the database.
+    /// Get the total number of jobs in the database, excluding jobs in the trash.
     fn count_total_jobs(&mut self) -> Result<i64, FettersError> {
         use crate::schema::jobs::dsl::*;
 
-        Ok(jobs.select(count(id)).first(self.connection)?)
+        Ok(jobs
+            .filter(deleted_at.is_null())
+            .select(count(id))
+            .first(self.connection)?)
     }
 
-    /// Get the total number of jobs in the database by sprint.
+    /// Get the total number of jobs in the database by sprint, excluding jobs in the trash.
     fn count_total_jobs_by_sprint(
         &mut self,
         current_sprint: &QueriedSprint,
@@ -133,7 +684,8 @@ impl<'a> JobRepository<'a> {
         use crate::schema::jobs;
 
         Ok(jobs::table
-            .left_join(sprints::table.on(jobs::sprint_id.eq(current_sprint.id)))
+            .filter(jobs::sprint_id.eq(current_sprint.id))
+            .filter(jobs::deleted_at.is_null())
             .select(count(jobs::id))
             .first(self.connection)?)
     }
@@ -154,12 +706,14 @@ impl<'a> JobRepository<'a> {
             .group_by(statuses::name)
             .select((statuses::name.nullable(), count(jobs::id)))
             .filter(sprints::id.eq(current_sprint.id))
+            .filter(jobs::deleted_at.is_null())
             .load::<(Option<String>, i64)>(self.connection)?;
 
         let mut jobs_per_status: Vec<CountAndPercentage> = Vec::new();
         for (status_name, count) in job_counts {
             if let Some(status) = status_name {
                 jobs_per_status.push(CountAndPercentage {
+                    category: "status".to_string(),
                     label: status,
                     count,
                     sprint_percentage: format!(
@@ -189,6 +743,7 @@ impl<'a> JobRepository<'a> {
 
         let counts = jobs::table
             .left_join(sprints::table.on(jobs::sprint_id.eq(sprints::id)))
+            .filter(jobs::deleted_at.is_null())
             .group_by(sprints::name)
             .select((sprints::name.nullable(), count(jobs::id)))
             .load::<(Option<String>, i64)>(self.connection)?;
@@ -197,6 +752,7 @@ impl<'a> JobRepository<'a> {
         for (sprint_name, count) in counts {
             if let Some(sprint_name) = sprint_name {
                 jobs_per_sprint.push(CountAndPercentage {
+                    category: "sprint".to_string(),
                     label: sprint_name,
                     count,
                     sprint_percentage: format!(
@@ -213,4 +769,650 @@ impl<'a> JobRepository<'a> {
 
         Ok(jobs_per_sprint)
     }
+
+    /// Get the creation timestamps of all job applications across all sprints.
+    pub fn get_created_timestamps(&mut self) -> Result<Vec<String>, FettersError> {
+        Ok(jobs::table
+            .filter(jobs::deleted_at.is_null())
+            .select(jobs::created)
+            .order(jobs::created.asc())
+            .load::<String>(self.connection)?)
+    }
+
+    /// Get the number of job applications and percentages per work arrangement for a given
+    /// sprint.
+    pub fn count_jobs_per_work_mode(
+        &mut self,
+        current_sprint: &QueriedSprint,
+    ) -> Result<Vec<CountAndPercentage>, FettersError> {
+        let counts = jobs::table
+            .filter(jobs::sprint_id.eq(current_sprint.id))
+            .filter(jobs::deleted_at.is_null())
+            .group_by(jobs::work_mode)
+            .select((jobs::work_mode, count(jobs::id)))
+            .order(count(jobs::id).desc())
+            .load::<(Option<String>, i64)>(self.connection)?;
+
+        self.build_counts_and_percentages(
+            "work_mode",
+            counts.into_iter().map(|(work_mode, count)| {
+                (work_mode.filter(|work_mode| !work_mode.is_empty()), count)
+            }),
+            current_sprint,
+        )
+    }
+
+    /// Get the number of job applications and percentages per location for a given sprint. Each
+    /// location is labeled with its city or region and country.
+    pub fn count_jobs_per_location(
+        &mut self,
+        current_sprint: &QueriedSprint,
+    ) -> Result<Vec<CountAndPercentage>, FettersError> {
+        let counts = jobs::table
+            .filter(jobs::sprint_id.eq(current_sprint.id))
+            .filter(jobs::deleted_at.is_null())
+            .group_by((jobs::location, jobs::country))
+            .select((jobs::location, jobs::country, count(jobs::id)))
+            .order(count(jobs::id).desc())
+            .load::<(Option<String>, Option<String>, i64)>(self.connection)?;
+
+        self.build_counts_and_percentages(
+            "location",
+            counts.into_iter().map(|(location, country, count)| {
+                let label = [location, country]
+                    .into_iter()
+                    .flatten()
+                    .filter(|part| !part.trim().is_empty())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                ((!label.is_empty()).then_some(label), count)
+            }),
+            current_sprint,
+        )
+    }
+
+    /// Convert labeled job counts for a given sprint into counts and percentages. Jobs without a
+    /// label are grouped under `NOT SPECIFIED`.
+    fn build_counts_and_percentages(
+        &mut self,
+        category: &str,
+        counts: impl Iterator<Item = (Option<String>, i64)>,
+        current_sprint: &QueriedSprint,
+    ) -> Result<Vec<CountAndPercentage>, FettersError> {
+        let total_jobs = self.count_total_jobs()?;
+        let total_jobs_in_sprint: i64 = jobs::table
+            .filter(jobs::sprint_id.eq(current_sprint.id))
+            .filter(jobs::deleted_at.is_null())
+            .select(count(jobs::id))
+            .first(self.connection)?;
+
+        let mut merged_counts: Vec<(String, i64)> = Vec::new();
+        for (label, count) in counts {
+            let label = label.unwrap_or("NOT SPECIFIED".to_string());
+            match merged_counts
+                .iter_mut()
+                .find(|(existing_label, _)| *existing_label == label)
+            {
+                Some((_, existing_count)) => *existing_count += count,
+                None => merged_counts.push((label, count)),
+            }
+        }
+        merged_counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+
+        Ok(merged_counts
+            .into_iter()
+            .map(|(label, count)| CountAndPercentage {
+                category: category.to_string(),
+                label,
+                count,
+                sprint_percentage: format!(
+                    "{:.2}%",
+                    (count as f64 / total_jobs_in_sprint as f64) * 100.0
+                ),
+                overall_percentage: format!("{:.2}%", (count as f64 / total_jobs as f64) * 100.0),
+            })
+            .collect())
+    }
+
+    /// Get the number of job applications per source for a given sprint, along with the response
+    /// and offer rates of each source. A job application received a response if its status
+    /// history contains any status other than `PENDING` or `GHOSTED`, and received an offer if it
+    /// was ever `OFFER RECEIVED` or `HIRED`.
+    pub fn count_jobs_per_source(
+        &mut self,
+        current_sprint: &QueriedSprint,
+    ) -> Result<Vec<SourceInsight>, FettersError> {
+        let status_history = jobs::table
+            .left_join(sources::table.on(jobs::source_id.eq(sources::id.nullable())))
+            .inner_join(status_changes::table.on(status_changes::job_id.eq(jobs::id)))
+            .inner_join(statuses::table.on(status_changes::new_status_id.eq(statuses::id)))
+            .filter(jobs::sprint_id.eq(current_sprint.id))
+            .filter(jobs::deleted_at.is_null())
+            .select((jobs::id, sources::name.nullable(), statuses::name))
+            .load::<(i32, Option<String>, String)>(self.connection)?;
+
+        // Maps each job ID to its source and whether it received a response and an offer.
+        let mut job_outcomes: HashMap<i32, (String, bool, bool)> = HashMap::new();
+        for (job_id, source_name, status_name) in status_history {
+            let (_, responded, offered) = job_outcomes.entry(job_id).or_insert((
+                source_name.unwrap_or("NOT SPECIFIED".to_string()),
+                false,
+                false,
+            ));
+
+            *responded |= !NO_RESPONSE_STATUSES.contains(&status_name.as_str());
+            *offered |= OFFER_STATUSES.contains(&status_name.as_str());
+        }
+
+        let mut source_insights: Vec<SourceInsight> = Vec::new();
+        for (source_name, responded, offered) in job_outcomes.into_values() {
+            let source_insight = match source_insights
+                .iter_mut()
+                .find(|source_insight| source_insight.source == source_name)
+            {
+                Some(source_insight) => source_insight,
+                None => {
+                    source_insights.push(SourceInsight {
+                        source: source_name,
+                        count: 0,
+                        responses: 0,
+                        response_rate: String::new(),
+                        offers: 0,
+                        offer_rate: String::new(),
+                    });
+                    source_insights.last_mut().unwrap()
+                }
+            };
+
+            source_insight.count += 1;
+            source_insight.responses += responded as i64;
+            source_insight.offers += offered as i64;
+        }
+
+        for source_insight in source_insights.iter_mut() {
+            source_insight.response_rate = format!(
+                "{:.2}%",
+                (source_insight.responses as f64 / source_insight.count as f64) * 100.0
+            );
+            source_insight.offer_rate = format!(
+                "{:.2}%",
+                (source_insight.offers as f64 / source_insight.count as f64) * 100.0
+            );
+        }
+
+        source_insights.sort_by(|a, b| b.count.cmp(&a.count).then(a.source.cmp(&b.source)));
+
+        Ok(source_insights)
+    }
+
+    /// Get the application funnel for a given sprint, or for all job applications if no sprint
+    /// is provided. The funnel starts with every job application, followed by the job
+    /// applications that progressed past `PENDING` and the stages in `FUNNEL_STAGES`. Job
+    /// applications that are currently `GHOSTED` or `REJECTED` are counted as attrition at the
+    /// furthest stage they reached.
+    pub fn get_funnel(
+        &mut self,
+        sprint: Option<&QueriedSprint>,
+        scope: &str,
+    ) -> Result<Vec<FunnelStage>, FettersError> {
+        let mut history_query = jobs::table
+            .inner_join(status_changes::table.on(status_changes::job_id.eq(jobs::id)))
+            .inner_join(statuses::table.on(status_changes::new_status_id.eq(statuses::id)))
+            .filter(jobs::deleted_at.is_null())
+            .select((jobs::id, statuses::name))
+            .into_boxed::<Sqlite>();
+        let mut current_status_query = jobs::table
+            .inner_join(statuses::table.on(jobs::status_id.eq(statuses::id)))
+            .filter(jobs::deleted_at.is_null())
+            .select((jobs::id, statuses::name))
+            .into_boxed::<Sqlite>();
+
+        if let Some(sprint) = sprint {
+            history_query = history_query.filter(jobs::sprint_id.eq(sprint.id));
+            current_status_query = current_status_query.filter(jobs::sprint_id.eq(sprint.id));
+        }
+
+        let status_history = history_query.load::<(i32, String)>(self.connection)?;
+        let current_statuses = current_status_query.load::<(i32, String)>(self.connection)?;
+
+        // Maps each job ID to the index of the furthest stage it reached.
+        let mut furthest_stages: HashMap<i32, usize> = current_statuses
+            .iter()
+            .map(|(job_id, _)| (*job_id, 0))
+            .collect();
+        for (job_id, status_name) in status_history.iter() {
+            let stage = if let Some(index) = FUNNEL_STAGES
+                .iter()
+                .rposition(|(_, stage_statuses)| stage_statuses.contains(&status_name.as_str()))
+            {
+                index + 2
+            } else if !NO_RESPONSE_STATUSES.contains(&status_name.as_str()) {
+                1
+            } else {
+                0
+            };
+
+            if let Some(furthest_stage) = furthest_stages.get_mut(job_id) {
+                *furthest_stage = (*furthest_stage).max(stage);
+            }
+        }
+
+        let stage_names: Vec<&str> = ["APPLIED", "PAST PENDING"]
+            .into_iter()
+            .chain(FUNNEL_STAGES.iter().map(|(stage_name, _)| *stage_name))
+            .collect();
+        let mut counts = vec![0; stage_names.len()];
+        let mut ghosted = vec![0; stage_names.len()];
+        let mut rejected = vec![0; stage_names.len()];
+
+        for (job_id, status_name) in current_statuses.iter() {
+            let furthest_stage = furthest_stages[job_id];
+
+            for count in counts.iter_mut().take(furthest_stage + 1) {
+                *count += 1;
+            }
+
+            match status_name.as_str() {
+                "GHOSTED" => ghosted[furthest_stage] += 1,
+                "REJECTED" => rejected[furthest_stage] += 1,
+                _ => {}
+            }
+        }
+
+        Ok(stage_names
+            .into_iter()
+            .enumerate()
+            .map(|(index, stage_name)| FunnelStage {
+                scope: scope.to_string(),
+                stage: stage_name.to_string(),
+                count: counts[index],
+                applied_percentage: format_percentage(counts[index], counts[0]),
+                conversion_rate: if index == 0 {
+                    "-".to_string()
+                } else {
+                    format_percentage(counts[index], counts[index - 1])
+                },
+                ghosted: ghosted[index],
+                rejected: rejected[index],
+            })
+            .collect())
+    }
+
+    /// Get statistics about the number of days between applying and receiving a first response
+    /// and between each status transition across all sprints, grouped overall, per company, and
+    /// per title. Durations are measured with the status history of each job application. Job
+    /// applications that took much longer than the historical median of a stage, including
+    /// job applications that are still waiting for a response in a status that is not terminal,
+    /// are returned as outliers.
+    pub fn get_stage_durations(
+        &mut self,
+    ) -> Result<(Vec<DurationStats>, Vec<DurationOutlier>), FettersError> {
+        let status_history = jobs::table
+            .inner_join(titles::table)
+            .inner_join(status_changes::table.on(status_changes::job_id.eq(jobs::id)))
+            .inner_join(statuses::table.on(status_changes::new_status_id.eq(statuses::id)))
+            .filter(jobs::deleted_at.is_null())
+            .select((
+                jobs::id,
+                jobs::company_name,
+                titles::name,
+                jobs::created,
+                statuses::name,
+                status_changes::changed,
+            ))
+            .order((jobs::id, status_changes::changed, status_changes::id))
+            .load::<(i32, String, String, String, String, String)>(self.connection)?;
+        // Maps each job ID to whether its current status is terminal.
+        let terminal_jobs: HashMap<i32, bool> = jobs::table
+            .inner_join(statuses::table.on(jobs::status_id.eq(statuses::id)))
+            .filter(jobs::deleted_at.is_null())
+            .select((jobs::id, statuses::is_terminal))
+            .load::<(i32, bool)>(self.connection)?
+            .into_iter()
+            .collect();
+
+        let now = parse_timestamp(&now_timestamp());
+        let days_between =
+            |start: &str, end: &str| match (parse_timestamp(start), parse_timestamp(end)) {
+                (Some(start), Some(end)) => {
+                    Some((end - start).num_seconds().max(0) as f64 / 86_400.0)
+                }
+                _ => None,
+            };
+
+        // Each measured duration as (job ID, company name, title, stage, days).
+        let mut durations: Vec<(i32, String, String, String, f64)> = Vec::new();
+        // Each job application still waiting for a response as (job ID, company name, title, days).
+        let mut awaiting_response: Vec<(i32, String, String, f64)> = Vec::new();
+
+        let mut index = 0;
+        while index < status_history.len() {
+            let (job_id, company_name, title, created, _, _) = &status_history[index];
+            let job_history: Vec<&(i32, String, String, String, String, String)> = status_history
+                [index..]
+                .iter()
+                .take_while(|(other_job_id, ..)| other_job_id == job_id)
+                .collect();
+            index += job_history.len();
+
+            let mut responded = false;
+            let mut previous: Option<(&str, &str)> = None;
+            for (_, _, _, _, status_name, changed) in job_history {
+                if !responded && !NO_RESPONSE_STATUSES.contains(&status_name.as_str()) {
+                    responded = true;
+                    if let Some(days) = days_between(created, changed) {
+                        durations.push((
+                            *job_id,
+                            company_name.clone(),
+                            title.clone(),
+                            FIRST_RESPONSE_STAGE.to_string(),
+                            days,
+                        ));
+                    }
+                }
+
+                if let Some((previous_status, previous_changed)) = previous
+                    && previous_status != status_name
+                    && let Some(days) = days_between(previous_changed, changed)
+                {
+                    durations.push((
+                        *job_id,
+                        company_name.clone(),
+                        title.clone(),
+                        format!("{previous_status} -> {status_name}"),
+                        days,
+                    ));
+                }
+
+                previous = Some((status_name, changed));
+            }
+
+            if !responded
+                && terminal_jobs.get(job_id) == Some(&false)
+                && let Some(now) = now
+                && let Some(created) = parse_timestamp(created)
+            {
+                awaiting_response.push((
+                    *job_id,
+                    company_name.clone(),
+                    title.clone(),
+                    (now - created).num_seconds().max(0) as f64 / 86_400.0,
+                ));
+            }
+        }
+
+        // Maps each (category, name, stage) to its measured durations.
+        let mut grouped_durations: HashMap<(&str, String, String), Vec<f64>> = HashMap::new();
+        for (_, company_name, title, stage, days) in durations.iter() {
+            for (category, name) in [
+                ("overall", "ALL JOBS".to_string()),
+                ("company", company_name.clone()),
+                ("title", title.clone()),
+            ] {
+                grouped_durations
+                    .entry((category, name, stage.clone()))
+                    .or_default()
+                    .push(*days);
+            }
+        }
+
+        let mut duration_stats: Vec<DurationStats> = grouped_durations
+            .into_iter()
+            .map(|((category, name, stage), mut days)| {
+                days.sort_by(f64::total_cmp);
+
+                DurationStats {
+                    category: category.to_string(),
+                    name,
+                    stage,
+                    samples: days.len() as i64,
+                    min_days: round_days(days[0]),
+                    median_days: round_days(median(&days)),
+                    mean_days: round_days(days.iter().sum::<f64>() / days.len() as f64),
+                    p90_days: round_days(
+                        days[((days.len() as f64 * 0.9).ceil() as usize).max(1) - 1],
+                    ),
+                }
+            })
+            .collect();
+
+        let category_order = |category: &str| match category {
+            "overall" => 0,
+            "company" => 1,
+            _ => 2,
+        };
+        duration_stats.sort_by(|a, b| {
+            category_order(&a.category)
+                .cmp(&category_order(&b.category))
+                .then(a.name.cmp(&b.name))
+                .then((b.stage == FIRST_RESPONSE_STAGE).cmp(&(a.stage == FIRST_RESPONSE_STAGE)))
+                .then(b.samples.cmp(&a.samples))
+                .then(a.stage.cmp(&b.stage))
+        });
+
+        let historical_medians: HashMap<&str, (usize, f64)> = duration_stats
+            .iter()
+            .filter(|stats| stats.category == "overall")
+            .map(|stats| {
+                (
+                    stats.stage.as_str(),
+                    (stats.samples as usize, stats.median_days),
+                )
+            })
+            .collect();
+        let is_outlier = |stage: &str, days: f64| match historical_medians.get(stage) {
+            Some((samples, median_days)) if *samples >= MIN_OUTLIER_SAMPLES => {
+                days > (median_days * OUTLIER_FACTOR).max(median_days + 1.0)
+            }
+            _ => false,
+        };
+
+        let mut duration_outliers: Vec<DurationOutlier> = durations
+            .into_iter()
+            .filter(|(_, _, _, stage, days)| is_outlier(stage, *days))
+            .chain(
+                awaiting_response
+                    .into_iter()
+                    .filter(|(_, _, _, days)| is_outlier(FIRST_RESPONSE_STAGE, *days))
+                    .map(|(job_id, company_name, title, days)| {
+                        (
+                            job_id,
+                            company_name,
+                            title,
+                            AWAITING_RESPONSE_STAGE.to_string(),
+                            days,
+                        )
+                    }),
+            )
+            .map(
+                |(job_id, company_name, title, stage, days)| DurationOutlier {
+                    median_days: historical_medians
+                        .get(if stage == AWAITING_RESPONSE_STAGE {
+                            FIRST_RESPONSE_STAGE
+                        } else {
+                            stage.as_str()
+                        })
+                        .map_or(0.0, |(_, median_days)| *median_days),
+                    job_id,
+                    company_name,
+                    title,
+                    stage,
+                    days: round_days(days),
+                },
+            )
+            .collect();
+
+        duration_outliers.sort_by(|a, b| b.days.total_cmp(&a.days));
+
+        Ok((duration_stats, duration_outliers))
+    }
+}
+
+/// Format a count as a percentage of a total. A total of zero results in `0.00%`.
+fn format_percentage(count: i64, total: i64) -> String {
+    if total == 0 {
+        return "0.00%".to_string();
+    }
+
+    format!("{:.2}%", (count as f64 / total as f64) * 100.0)
+}
+
+/// Get the median of a sorted, non-empty slice of durations.
+fn median(sorted_days: &[f64]) -> f64 {
+    let middle = sorted_days.len() / 2;
+
+    if sorted_days.len().is_multiple_of(2) {
+        (sorted_days[middle - 1] + sorted_days[middle]) / 2.0
+    } else {
+        sorted_days[middle]
+    }
+}
+
+/// Round a number of days to one decimal place.
+fn round_days(days: f64) -> f64 {
+    (days * 10.0).round() / 10.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{contact::NewContact, interview::NewInterview, reminder::NewReminder};
+    use crate::repositories::statuses::StatusRepository;
+    use crate::utils::testing::TestDatabase;
+
+    /// A creation timestamp long before the inactivity cutoff.
+    const OLD_TIMESTAMP: &str = "2025-01-01 09:00:00";
+
+    /// Find the pending jobs without activity in the last 30 days.
+    fn find_inactive_pending_jobs(database: &mut TestDatabase) -> Result<Vec<i32>, FettersError> {
+        let mut job_repo = JobRepository {
+            connection: &mut database.connection,
+        };
+        job_repo.find_inactive_jobs("PENDING", 30)
+    }
+
+    #[test]
+    fn jobs_without_recent_activity_are_inactive() -> Result<(), FettersError> {
+        let mut database = TestDatabase::new()?;
+        let old_job = database.add_job("Acme", "PENDING", OLD_TIMESTAMP)?;
+        database.add_job("Globex", "PENDING", &now_timestamp())?;
+        database.add_job("Initech", "REJECTED", OLD_TIMESTAMP)?;
+        let trashed_job = database.add_job("Umbrella", "PENDING", OLD_TIMESTAMP)?;
+        JobRepository {
+            connection: &mut database.connection,
+        }
+        .delete_job(trashed_job.id)?;
+
+        assert_eq!(find_inactive_pending_jobs(&mut database)?, vec![old_job.id]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn past_interviews_and_linked_contacts_count_as_activity() -> Result<(), FettersError> {
+        let mut database = TestDatabase::new()?;
+        let interviewed_job = database.add_job("Acme", "PENDING", OLD_TIMESTAMP)?;
+        let scheduled_job = database.add_job("Globex", "PENDING", OLD_TIMESTAMP)?;
+        let linked_job = database.add_job("Initech", "PENDING", OLD_TIMESTAMP)?;
+
+        let mut interview_repo = InterviewRepository {
+            connection: &mut database.connection,
+        };
+        for (job_id, scheduled) in [
+            (interviewed_job.id, now_timestamp()),
+            (scheduled_job.id, "2999-01-01 09:00:00".to_string()),
+        ] {
+            interview_repo.add_interview(NewInterview {
+                job_id,
+                round: "Phone Screen",
+                interview_type: "PHONE",
+                scheduled: Some(&scheduled),
+                interviewers: None,
+                outcome: "PENDING",
+                notes: None,
+            })?;
+        }
+
+        let mut contact_repo = ContactRepository {
+            connection: &mut database.connection,
+        };
+        let contact = contact_repo.add_contact(NewContact {
+            name: "Jane Doe",
+            email: None,
+            phone: None,
+            role: None,
+            company: None,
+            linkedin_url: None,
+            notes: None,
+        })?;
+        contact_repo.link_contact(linked_job.id, contact.id)?;
+
+        // Interviews that have not happened yet do not count.
+        assert_eq!(
+            find_inactive_pending_jobs(&mut database)?,
+            vec![scheduled_job.id]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn only_manual_reminders_count_as_activity() -> Result<(), FettersError> {
+        let mut database = TestDatabase::new()?;
+        let manual_job = database.add_job("Acme", "PENDING", OLD_TIMESTAMP)?;
+        let automatic_job = database.add_job("Globex", "PENDING", OLD_TIMESTAMP)?;
+
+        let mut reminder_repo = ReminderRepository {
+            connection: &mut database.connection,
+        };
+        for (job_id, automatic) in [(manual_job.id, false), (automatic_job.id, true)] {
+            reminder_repo.add_reminder(NewReminder {
+                job_id,
+                due: "2999-01-01 09:00:00",
+                message: "Follow up",
+                done: false,
+                automatic,
+            })?;
+        }
+
+        assert_eq!(
+            find_inactive_pending_jobs(&mut database)?,
+            vec![automatic_job.id]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn undone_sweeps_count_as_activity() -> Result<(), FettersError> {
+        let mut database = TestDatabase::new()?;
+        let job = database.add_job("Acme", "PENDING", OLD_TIMESTAMP)?;
+        let ghosted = StatusRepository {
+            connection: &mut database.connection,
+        }
+        .get_status_by_name("GHOSTED")?
+        .expect("the default statuses are seeded");
+
+        AuditRepository::start_operation(Some("sweep"));
+        JobRepository {
+            connection: &mut database.connection,
+        }
+        .set_status_for_jobs(&[job.id], ghosted.id)?;
+
+        let mut audit_repo = AuditRepository {
+            connection: &mut database.connection,
+        };
+        let operation_id = audit_repo.list_undoable_operations(1, true)?[0].id;
+        audit_repo.undo_operations(&[operation_id])?;
+
+        let restored_status_id: i32 = jobs::table
+            .find(job.id)
+            .select(jobs::status_id)
+            .first(&mut database.connection)?;
+        assert_eq!(restored_status_id, job.status_id);
+        assert!(find_inactive_pending_jobs(&mut database)?.is_empty());
+
+        Ok(())
+    }
 }
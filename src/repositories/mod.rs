@@ -1,6 +1,17 @@
 //! Contains all repositories for `fetters`.
 
+pub mod archive;
+pub mod audit;
+pub mod compensation;
+pub mod contact;
+pub mod integrity;
+pub mod interview;
 pub mod job;
+pub mod reminder;
+pub mod search;
+pub mod source;
 pub mod sprint;
+pub mod status_change;
 pub mod statuses;
+pub mod tag;
 pub mod title;
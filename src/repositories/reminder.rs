@@ -0,0 +1,155 @@
+//! Contains the reminder repository abstraction class.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::Duration;
+use diesel::prelude::*;
+use diesel::{delete, insert_into, update};
+
+use crate::config::configuration::ReminderRule;
+use crate::errors::FettersError;
+use crate::models::reminder::{NewReminder, QueriedReminder};
+use crate::schema::{jobs, reminders, status_changes, statuses};
+use crate::utils::dates::{TIMESTAMP_FORMAT, parse_timestamp};
+
+/// Contains all methods pertaining to CRUD operations for the `reminders` table.
+pub struct ReminderRepository<'a> {
+    pub connection: &'a mut SqliteConnection,
+}
+
+impl<'a> ReminderRepository<'a> {
+    /// Adds a new reminder into the `reminders` table.
+    pub fn add_reminder(
+        &mut self,
+        new_reminder: NewReminder,
+    ) -> Result<QueriedReminder, FettersError> {
+        Ok(insert_into(reminders::table)
+            .values(&new_reminder)
+            .returning(QueriedReminder::as_returning())
+            .get_result(self.connection)?)
+    }
+
+    /// Retrieves all reminders that are not done and are due before the given timestamp, ordered
+    /// by their due timestamp. Reminders of job applications in the trash are excluded. Each
+    /// reminder is returned with the company name and the current status of its job application.
+    pub fn list_open_reminders(
+        &mut self,
+        due_before: &str,
+    ) -> Result<Vec<(QueriedReminder, String, String)>, FettersError> {
+        Ok(reminders::table
+            .inner_join(jobs::table.on(reminders::job_id.eq(jobs::id)))
+            .inner_join(statuses::table.on(jobs::status_id.eq(statuses::id)))
+            .filter(reminders::done.eq(false))
+            .filter(reminders::due.le(due_before))
+            .filter(jobs::deleted_at.is_null())
+            .order((reminders::due.asc(), reminders::id.asc()))
+            .select((
+                QueriedReminder::as_select(),
+                jobs::company_name,
+                statuses::name,
+            ))
+            .load(self.connection)?)
+    }
+
+    /// Marks a reminder as done. Returns `None` if the reminder does not exist.
+    pub fn complete_reminder(
+        &mut self,
+        reminder_id: i32,
+    ) -> Result<Option<QueriedReminder>, FettersError> {
+        Ok(update(reminders::table.find(reminder_id))
+            .set(reminders::done.eq(true))
+            .returning(QueriedReminder::as_returning())
+            .get_result(self.connection)
+            .optional()?)
+    }
+
+    /// Deletes all reminders for a job.
+    pub fn delete_reminders(&mut self, job_id: i32) -> Result<(), FettersError> {
+        delete(reminders::table.filter(reminders::job_id.eq(job_id))).execute(self.connection)?;
+
+        Ok(())
+    }
+
+    /// Brings automatic reminders in line with the reminder rules. A reminder is created for each
+    /// job application that is currently in a rule's status, due the configured number of days
+    /// after the job application last entered that status. Open automatic reminders that no
+    /// longer match a rule (ie. the job application has moved on to another status) are marked
+    /// as done. Reminders that already exist are never recreated, even if they are done. Job
+    /// applications in the trash are skipped, so their reminders are left as they are until the
+    /// job application is restored.
+    pub fn sync_automatic_reminders(&mut self, rules: &[ReminderRule]) -> Result<(), FettersError> {
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                let current_jobs: Vec<(i32, i32, String, String)> = jobs::table
+                    .inner_join(statuses::table.on(jobs::status_id.eq(statuses::id)))
+                    .filter(jobs::deleted_at.is_null())
+                    .select((jobs::id, jobs::status_id, statuses::name, jobs::created))
+                    .load(connection)?;
+
+                // Find when each job application last entered each status.
+                let mut entered: HashMap<(i32, i32), String> = HashMap::new();
+                for (job_id, status_id, changed) in status_changes::table
+                    .select((
+                        status_changes::job_id,
+                        status_changes::new_status_id,
+                        status_changes::changed,
+                    ))
+                    .load::<(i32, i32, String)>(connection)?
+                {
+                    let latest = entered.entry((job_id, status_id)).or_default();
+                    if changed > *latest {
+                        *latest = changed;
+                    }
+                }
+
+                let mut expected: HashSet<(i32, String, String)> = HashSet::new();
+                for rule in rules.iter() {
+                    for (job_id, status_id, status_name, created) in current_jobs.iter() {
+                        if !status_name.eq_ignore_ascii_case(&rule.status) {
+                            continue;
+                        }
+
+                        let entered_status = entered.get(&(*job_id, *status_id)).unwrap_or(created);
+                        if let Some(timestamp) = parse_timestamp(entered_status) {
+                            let due = (timestamp + Duration::days(rule.days))
+                                .format(TIMESTAMP_FORMAT)
+                                .to_string();
+                            expected.insert((*job_id, due, rule.message.clone()));
+                        }
+                    }
+                }
+
+                let existing: Vec<QueriedReminder> = reminders::table
+                    .inner_join(jobs::table.on(reminders::job_id.eq(jobs::id)))
+                    .filter(reminders::automatic.eq(true))
+                    .filter(jobs::deleted_at.is_null())
+                    .select(QueriedReminder::as_select())
+                    .load(connection)?;
+                let mut existing_keys: HashSet<(i32, String, String)> = HashSet::new();
+
+                for reminder in existing {
+                    let key = (reminder.job_id, reminder.due, reminder.message);
+                    if !reminder.done && !expected.contains(&key) {
+                        update(reminders::table.find(reminder.id))
+                            .set(reminders::done.eq(true))
+                            .execute(connection)?;
+                    }
+                    existing_keys.insert(key);
+                }
+
+                for (job_id, due, message) in expected.difference(&existing_keys) {
+                    insert_into(reminders::table)
+                        .values(&NewReminder {
+                            job_id: *job_id,
+                            due,
+                            message,
+                            done: false,
+                            automatic: true,
+                        })
+                        .execute(connection)?;
+                }
+
+                Ok(())
+            })
+    }
+}
@@ -0,0 +1,64 @@
+//! Contains the search repository abstraction class.
+
+use diesel::prelude::*;
+use diesel::result::Error as DieselError;
+use diesel::sql_query;
+use diesel::sql_types::{BigInt, Nullable, Text};
+
+use crate::errors::FettersError;
+use crate::models::search::SearchResult;
+
+/// Finds job applications in the `jobs_fts` full-text index, which is kept in sync with the `jobs`
+/// and `titles` tables by triggers. Matches in the company name and title are weighted higher than
+/// matches in the notes or link when ranking results. Job applications in the trash are excluded.
+///
+/// `jobs_fts` is an FTS5 virtual table, so it is queried with raw SQL instead of the Diesel DSL.
+const SEARCH_QUERY: &str = "
+    SELECT
+        jobs.id AS id,
+        highlight(jobs_fts, 0, char(2), char(3)) AS company_name,
+        highlight(jobs_fts, 1, char(2), char(3)) AS title,
+        statuses.name AS status,
+        sprints.name AS sprint,
+        snippet(jobs_fts, 2, char(2), char(3), '...', 12) AS snippet
+    FROM jobs_fts
+    INNER JOIN jobs ON jobs.id = jobs_fts.rowid
+    LEFT JOIN statuses ON statuses.id = jobs.status_id
+    LEFT JOIN sprints ON sprints.id = jobs.sprint_id
+    WHERE jobs_fts MATCH ?
+        AND jobs.deleted_at IS NULL
+        AND (? IS NULL OR sprints.name LIKE ?)
+    ORDER BY bm25(jobs_fts, 4.0, 3.0, 1.0, 1.0), jobs.id
+    LIMIT ?
+";
+
+/// Contains all methods pertaining to full-text searches across job applications.
+pub struct SearchRepository<'a> {
+    pub connection: &'a mut SqliteConnection,
+}
+
+impl<'a> SearchRepository<'a> {
+    /// Search job applications with an FTS5 query, most relevant first. Only job applications in
+    /// sprints matching `sprint` are searched if it is provided.
+    pub fn search_jobs(
+        &mut self,
+        query: &str,
+        sprint: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<SearchResult>, FettersError> {
+        let sprint_pattern = sprint.map(|sprint| format!("%{}%", sprint));
+
+        sql_query(SEARCH_QUERY)
+            .bind::<Text, _>(query)
+            .bind::<Nullable<Text>, _>(&sprint_pattern)
+            .bind::<Nullable<Text>, _>(&sprint_pattern)
+            .bind::<BigInt, _>(limit)
+            .load::<SearchResult>(self.connection)
+            .map_err(|error| match error {
+                DieselError::DatabaseError(_, info) => {
+                    FettersError::InvalidSearchQuery(query.to_string(), info.message().to_string())
+                }
+                error => error.into(),
+            })
+    }
+}
@@ -0,0 +1,83 @@
+//! Contains the sources repository abstraction class.
+
+use diesel::dsl::count;
+use diesel::insert_into;
+use diesel::prelude::*;
+use lazy_static::lazy_static;
+
+use crate::errors::FettersError;
+use crate::models::source::{NewSource, QueriedSource};
+use crate::schema::sources;
+use crate::sqlite::lower;
+
+lazy_static! {
+    /// Contains all default sources that will be stored into the `sources` SQLite table on the
+    /// initial run.
+    static ref DEFAULT_SOURCES: Vec<&'static str> = vec![
+        "JOB BOARD",
+        "REFERRAL",
+        "CAREER PAGE",
+        "RECRUITER OUTREACH",
+    ];
+}
+
+/// Contains all methods pertaining to CRUD operations for the `sources` table.
+pub struct SourceRepository<'a> {
+    pub connection: &'a mut SqliteConnection,
+}
+
+impl<'a> SourceRepository<'a> {
+    /// Adds a new source into the `sources` table. Source names are stored in uppercase. The
+    /// existing source is returned if a source with the same name already exists.
+    pub fn add_source(&mut self, source_name: &str) -> Result<QueriedSource, FettersError> {
+        if let Some(queried_source) = self.get_source_by_name(source_name)? {
+            return Ok(queried_source);
+        }
+
+        Ok(insert_into(sources::table)
+            .values(&NewSource {
+                name: &source_name.trim().to_uppercase(),
+            })
+            .returning(QueriedSource::as_returning())
+            .get_result(self.connection)?)
+    }
+
+    /// Retrieves all sources.
+    pub fn get_all_sources(&mut self) -> Result<Vec<QueriedSource>, FettersError> {
+        Ok(sources::table
+            .order(sources::id.asc())
+            .select(QueriedSource::as_select())
+            .load(self.connection)?)
+    }
+
+    /// Retrieves a source by name. The name is matched case-insensitively.
+    pub fn get_source_by_name(
+        &mut self,
+        source_name: &str,
+    ) -> Result<Option<QueriedSource>, FettersError> {
+        Ok(sources::table
+            .filter(lower(sources::name).eq(source_name.trim().to_lowercase()))
+            .select(QueriedSource::as_select())
+            .first(self.connection)
+            .optional()?)
+    }
+
+    /// Stores the default sources into the `sources` table if no sources exist yet.
+    pub fn seed_sources(&mut self) -> Result<(), FettersError> {
+        let source_count: i64 = sources::table
+            .select(count(sources::id))
+            .first(self.connection)?;
+
+        if source_count > 0 {
+            return Ok(());
+        }
+
+        for source_name in DEFAULT_SOURCES.iter() {
+            insert_into(sources::table)
+                .values(&NewSource { name: source_name })
+                .execute(self.connection)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,13 +1,18 @@
 //! Contains the job sprint repository abstraction class.
 
 use chrono::Local;
-use diesel::dsl::update;
-use diesel::insert_into;
+use diesel::dsl::{count, update};
 use diesel::prelude::*;
+use diesel::{delete, insert_into};
 
 use crate::errors::FettersError;
-use crate::models::sprint::{NewSprint, QueriedSprint, SprintUpdate};
-use crate::schema::sprints;
+use crate::models::{
+    audit::AuditAction,
+    job::JobUpdate,
+    sprint::{NewSprint, QueriedSprint, SprintUpdate},
+};
+use crate::repositories::{audit::AuditRepository, job::JobRepository};
+use crate::schema::{jobs, sprints};
 
 /// Contains all methods pertaining to CRUD operations for the `sprints` table.
 pub struct SprintRepository<'a> {
@@ -15,14 +20,22 @@ pub struct SprintRepository<'a> {
 }
 
 impl<'a> SprintRepository<'a> {
-    /// Adds a new job sprint into the `sprints` table.
+    /// Adds a new job sprint into the `sprints` table and records the insert in the audit log.
     pub fn add_job_sprint(&mut self, new_sprint: NewSprint) -> Result<QueriedSprint, FettersError> {
         use crate::schema::sprints::dsl::*;
 
-        Ok(insert_into(sprints)
-            .values(&new_sprint)
-            .returning(QueriedSprint::as_returning())
-            .get_result(self.connection)?)
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                let queried_sprint = insert_into(sprints)
+                    .values(&new_sprint)
+                    .returning(QueriedSprint::as_returning())
+                    .get_result(connection)?;
+
+                let mut audit_repo = AuditRepository { connection };
+                audit_repo.record_sprint(AuditAction::Insert, None, Some(&queried_sprint))?;
+
+                Ok(queried_sprint)
+            })
     }
 
     /// Retrieves the current sprint's ID.
@@ -41,6 +54,8 @@ impl<'a> SprintRepository<'a> {
                         start_date: &Local::now().date_naive().format("%Y-%m-%d").to_string(),
                         end_date: None,
                         num_jobs: &0,
+                        goal: None,
+                        goal_period: None,
                     };
                     self.add_job_sprint(new_sprint)
                 },
@@ -48,7 +63,21 @@ impl<'a> SprintRepository<'a> {
             )
     }
 
-    /// Update an existing sprint with new changes.
+    /// Retrieves an existing sprint by name.
+    pub fn get_sprint_by_name(
+        &mut self,
+        sprint_name: &str,
+    ) -> Result<Option<QueriedSprint>, FettersError> {
+        use crate::schema::sprints::dsl::*;
+
+        Ok(sprints
+            .filter(name.eq(sprint_name))
+            .select(QueriedSprint::as_select())
+            .first(self.connection)
+            .optional()?)
+    }
+
+    /// Update an existing sprint with new changes and record the update in the audit log.
     pub fn update_sprint(
         &mut self,
         sprint_id: i32,
@@ -56,10 +85,23 @@ impl<'a> SprintRepository<'a> {
     ) -> Result<QueriedSprint, FettersError> {
         use crate::schema::sprints::dsl::*;
 
-        Ok(update(sprints.find(sprint_id))
-            .set(&changes)
-            .returning(QueriedSprint::as_returning())
-            .get_result(self.connection)?)
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                let previous_sprint = get_sprint(connection, sprint_id)?;
+                let queried_sprint = update(sprints.find(sprint_id))
+                    .set(&changes)
+                    .returning(QueriedSprint::as_returning())
+                    .get_result(connection)?;
+
+                let mut audit_repo = AuditRepository { connection };
+                audit_repo.record_sprint(
+                    AuditAction::Update,
+                    Some(&previous_sprint),
+                    Some(&queried_sprint),
+                )?;
+
+                Ok(queried_sprint)
+            })
     }
 
     /// Retrieves all job sprints.
@@ -73,19 +115,214 @@ impl<'a> SprintRepository<'a> {
 
     /// Increment the `num_jobs` count for a particular sprint.
     pub fn increment_num_jobs(&mut self, sprint_id: i32) -> Result<(), FettersError> {
-        update(sprints::table.filter(sprints::id.eq(sprint_id)))
-            .set(sprints::num_jobs.eq(sprints::num_jobs + 1))
-            .execute(self.connection)?;
-
-        Ok(())
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                adjust_num_jobs(connection, sprint_id, 1)
+            })
     }
 
     /// Decrement the `num_jobs` count for a particular sprint.
     pub fn decrement_num_jobs(&mut self, sprint_id: i32) -> Result<(), FettersError> {
-        update(sprints::table.filter(sprints::id.eq(sprint_id)))
-            .set(sprints::num_jobs.eq(sprints::num_jobs - 1))
-            .execute(self.connection)?;
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                adjust_num_jobs(connection, sprint_id, -1)
+            })
+    }
+
+    /// Recalculate the `num_jobs` count for a particular sprint from the job applications it
+    /// contains, excluding job applications in the trash. The update is recorded in the audit log.
+    pub fn recount_num_jobs(&mut self, sprint_id: i32) -> Result<QueriedSprint, FettersError> {
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| recount_num_jobs(connection, sprint_id))
+    }
+
+    /// Count the job applications in a sprint. Returns the number of active job applications and
+    /// the number of job applications in the trash.
+    pub fn count_jobs_in_sprint(&mut self, sprint_id: i32) -> Result<(i64, i64), FettersError> {
+        let active_jobs = jobs::table
+            .filter(jobs::sprint_id.eq(sprint_id))
+            .filter(jobs::deleted_at.is_null())
+            .select(count(jobs::id))
+            .first(self.connection)?;
+        let trashed_jobs = jobs::table
+            .filter(jobs::sprint_id.eq(sprint_id))
+            .filter(jobs::deleted_at.is_not_null())
+            .select(count(jobs::id))
+            .first(self.connection)?;
+
+        Ok((active_jobs, trashed_jobs))
+    }
+
+    /// Delete a sprint. If a target sprint is provided, all job applications in the deleted sprint
+    /// are moved to the target sprint first and its `num_jobs` count is recalculated. Every change
+    /// is recorded in the audit log.
+    pub fn delete_sprint(
+        &mut self,
+        sprint_id: i32,
+        reassign_to: Option<i32>,
+    ) -> Result<QueriedSprint, FettersError> {
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                if let Some(target_id) = reassign_to {
+                    move_jobs(connection, sprint_id, target_id)?;
+                    recount_num_jobs(connection, target_id)?;
+                }
+
+                let deleted_sprint = delete(sprints::table.find(sprint_id))
+                    .returning(QueriedSprint::as_returning())
+                    .get_result(connection)?;
+
+                let mut audit_repo = AuditRepository { connection };
+                audit_repo.record_sprint(AuditAction::Delete, Some(&deleted_sprint), None)?;
 
-        Ok(())
+                Ok(deleted_sprint)
+            })
     }
+
+    /// Merge a sprint into another one. All job applications are moved to the target sprint, the
+    /// target sprint's dates are widened to cover both sprints, its `num_jobs` count is
+    /// recalculated, and the merged sprint is deleted. The target sprint stays open if either
+    /// sprint has no end date. Every change is recorded in the audit log.
+    pub fn merge_sprints(
+        &mut self,
+        from_sprint: &QueriedSprint,
+        into_sprint: &QueriedSprint,
+    ) -> Result<QueriedSprint, FettersError> {
+        let start_date = from_sprint
+            .start_date
+            .clone()
+            .min(into_sprint.start_date.clone());
+        let end_date = match (&from_sprint.end_date, &into_sprint.end_date) {
+            (Some(from_end_date), Some(into_end_date)) => {
+                Some(from_end_date.clone().max(into_end_date.clone()))
+            }
+            _ => None,
+        };
+
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                move_jobs(connection, from_sprint.id, into_sprint.id)?;
+
+                let deleted_sprint = delete(sprints::table.find(from_sprint.id))
+                    .returning(QueriedSprint::as_returning())
+                    .get_result(connection)?;
+                AuditRepository { connection }.record_sprint(
+                    AuditAction::Delete,
+                    Some(&deleted_sprint),
+                    None,
+                )?;
+
+                let previous_sprint = get_sprint(connection, into_sprint.id)?;
+                let widened_sprint = update(sprints::table.find(into_sprint.id))
+                    .set((
+                        sprints::start_date.eq(&start_date),
+                        sprints::end_date.eq(&end_date),
+                    ))
+                    .returning(QueriedSprint::as_returning())
+                    .get_result(connection)?;
+                AuditRepository { connection }.record_sprint(
+                    AuditAction::Update,
+                    Some(&previous_sprint),
+                    Some(&widened_sprint),
+                )?;
+
+                recount_num_jobs(connection, into_sprint.id)
+            })
+    }
+}
+
+/// Retrieve a sprint by ID.
+fn get_sprint(
+    connection: &mut SqliteConnection,
+    sprint_id: i32,
+) -> Result<QueriedSprint, FettersError> {
+    Ok(sprints::table
+        .find(sprint_id)
+        .select(QueriedSprint::as_select())
+        .first(connection)?)
+}
+
+/// Add `delta` to the `num_jobs` count of a sprint and record the update in the audit log. Nothing
+/// is changed if the sprint does not exist.
+fn adjust_num_jobs(
+    connection: &mut SqliteConnection,
+    sprint_id: i32,
+    delta: i32,
+) -> Result<(), FettersError> {
+    let Some(previous_sprint) = sprints::table
+        .find(sprint_id)
+        .select(QueriedSprint::as_select())
+        .first(connection)
+        .optional()?
+    else {
+        return Ok(());
+    };
+
+    let queried_sprint = update(sprints::table.find(sprint_id))
+        .set(sprints::num_jobs.eq(sprints::num_jobs + delta))
+        .returning(QueriedSprint::as_returning())
+        .get_result(connection)?;
+
+    let mut audit_repo = AuditRepository { connection };
+    audit_repo.record_sprint(
+        AuditAction::Update,
+        Some(&previous_sprint),
+        Some(&queried_sprint),
+    )
+}
+
+/// Move all job applications from one sprint to another. Each job application is updated through
+/// the `JobRepository` so the move is recorded in the audit log.
+fn move_jobs(
+    connection: &mut SqliteConnection,
+    from_sprint_id: i32,
+    to_sprint_id: i32,
+) -> Result<(), FettersError> {
+    let job_ids: Vec<i32> = jobs::table
+        .filter(jobs::sprint_id.eq(from_sprint_id))
+        .select(jobs::id)
+        .load(connection)?;
+
+    let mut job_repo = JobRepository { connection };
+    for job_id in job_ids {
+        job_repo.update_job(
+            job_id,
+            JobUpdate {
+                sprint_id: Some(to_sprint_id),
+                ..Default::default()
+            },
+            &[],
+            &[],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Recalculate the `num_jobs` count of a sprint from the job applications it contains, excluding
+/// job applications in the trash, and record the update in the audit log.
+fn recount_num_jobs(
+    connection: &mut SqliteConnection,
+    sprint_id: i32,
+) -> Result<QueriedSprint, FettersError> {
+    let num_jobs: i64 = jobs::table
+        .filter(jobs::sprint_id.eq(sprint_id))
+        .filter(jobs::deleted_at.is_null())
+        .select(count(jobs::id))
+        .first(connection)?;
+
+    let previous_sprint = get_sprint(connection, sprint_id)?;
+    let queried_sprint = update(sprints::table.find(sprint_id))
+        .set(sprints::num_jobs.eq(num_jobs as i32))
+        .returning(QueriedSprint::as_returning())
+        .get_result(connection)?;
+
+    let mut audit_repo = AuditRepository { connection };
+    audit_repo.record_sprint(
+        AuditAction::Update,
+        Some(&previous_sprint),
+        Some(&queried_sprint),
+    )?;
+
+    Ok(queried_sprint)
 }
@@ -0,0 +1,50 @@
+//! Contains the status change repository abstraction class.
+
+use diesel::prelude::*;
+use diesel::{delete, insert_into};
+
+use crate::errors::FettersError;
+use crate::models::status_change::{NewStatusChange, QueriedStatusChange};
+
+/// Contains all methods pertaining to CRUD operations for the `status_changes` table.
+pub struct StatusChangeRepository<'a> {
+    pub connection: &'a mut SqliteConnection,
+}
+
+impl<'a> StatusChangeRepository<'a> {
+    /// Adds a new status change into the `status_changes` table.
+    pub fn add_status_change(
+        &mut self,
+        new_status_change: NewStatusChange,
+    ) -> Result<QueriedStatusChange, FettersError> {
+        use crate::schema::status_changes::dsl::*;
+
+        Ok(insert_into(status_changes)
+            .values(&new_status_change)
+            .returning(QueriedStatusChange::as_returning())
+            .get_result(self.connection)?)
+    }
+
+    /// Retrieves all status changes for a job in chronological order.
+    pub fn get_status_changes(
+        &mut self,
+        target_job_id: i32,
+    ) -> Result<Vec<QueriedStatusChange>, FettersError> {
+        use crate::schema::status_changes::dsl::*;
+
+        Ok(status_changes
+            .filter(job_id.eq(target_job_id))
+            .order((changed.asc(), id.asc()))
+            .select(QueriedStatusChange::as_select())
+            .load(self.connection)?)
+    }
+
+    /// Deletes all status changes for a job.
+    pub fn delete_status_changes(&mut self, target_job_id: i32) -> Result<(), FettersError> {
+        use crate::schema::status_changes::dsl::*;
+
+        delete(status_changes.filter(job_id.eq(target_job_id))).execute(self.connection)?;
+
+        Ok(())
+    }
+}
@@ -1,23 +1,28 @@
 //! Contains the statuses repository abstraction class.
 
-use diesel::insert_into;
+use diesel::dsl::{count, max};
 use diesel::prelude::*;
+use diesel::{delete, insert_into, update};
 use lazy_static::lazy_static;
 
 use crate::errors::FettersError;
-use crate::models::status::{NewStatus, QueriedStatus};
+use crate::models::status::{NewStatus, QueriedStatus, StatusUpdate};
+use crate::repositories::job::JobRepository;
+use crate::schema::{jobs, status_changes, statuses};
+use crate::sqlite::lower;
 
 lazy_static! {
     /// Contains all default statuses that will be stored into the `statuses` SQLite table on the
-    /// initial run.
-    static ref DEFAULT_STATUSES: Vec<&'static str> = vec![
-        "GHOSTED",
-        "HIRED",
-        "IN PROGRESS",
-        "NOT HIRING ANYMORE",
-        "OFFER RECEIVED",
-        "PENDING",
-        "REJECTED",
+    /// initial run with their color and whether they are terminal. The statuses are sorted in
+    /// this order.
+    pub static ref DEFAULT_STATUSES: Vec<(&'static str, &'static str, bool)> = vec![
+        ("PENDING", "bright blue", false),
+        ("IN PROGRESS", "bright yellow", false),
+        ("OFFER RECEIVED", "bright magenta", false),
+        ("HIRED", "bright green", true),
+        ("REJECTED", "bright red", true),
+        ("GHOSTED", "#858585", true),
+        ("NOT HIRING ANYMORE", "#757575", true),
     ];
 }
 
@@ -27,32 +32,149 @@ pub struct StatusRepository<'a> {
 }
 
 impl<'a> StatusRepository<'a> {
-    /// Retrieves all statuses.
-    pub fn get_all_statuses(&mut self) -> Result<Vec<QueriedStatus>, FettersError> {
-        use crate::schema::statuses::dsl::*;
+    /// Adds a new status to the end of the status order.
+    pub fn add_status(
+        &mut self,
+        status_name: &str,
+        color: Option<&str>,
+        is_terminal: bool,
+    ) -> Result<QueriedStatus, FettersError> {
+        if self.get_status_by_name(status_name)?.is_some() {
+            return Err(FettersError::StatusNameConflict(status_name.to_string()));
+        }
 
-        Ok(statuses
+        let last_sort_order: Option<i32> = statuses::table
+            .select(max(statuses::sort_order))
+            .first(self.connection)?;
+
+        Ok(insert_into(statuses::table)
+            .values(&NewStatus {
+                name: status_name,
+                color,
+                sort_order: last_sort_order.unwrap_or(0) + 1,
+                is_terminal,
+            })
+            .returning(QueriedStatus::as_returning())
+            .get_result(self.connection)?)
+    }
+
+    /// Retrieves all statuses in their sort order.
+    pub fn get_all_statuses(&mut self) -> Result<Vec<QueriedStatus>, FettersError> {
+        Ok(statuses::table
+            .order((statuses::sort_order.asc(), statuses::id.asc()))
             .select(QueriedStatus::as_select())
             .load(self.connection)?)
     }
 
-    /// Stores the default statuses into the `statuses` table if it doesn't already exist.
+    /// Retrieves a status by name. The name is matched case-insensitively.
+    pub fn get_status_by_name(
+        &mut self,
+        status_name: &str,
+    ) -> Result<Option<QueriedStatus>, FettersError> {
+        Ok(statuses::table
+            .filter(lower(statuses::name).eq(status_name.trim().to_lowercase()))
+            .select(QueriedStatus::as_select())
+            .first(self.connection)
+            .optional()?)
+    }
+
+    /// Updates an existing status with new changes.
+    pub fn update_status(
+        &mut self,
+        status_id: i32,
+        changes: StatusUpdate,
+    ) -> Result<QueriedStatus, FettersError> {
+        Ok(update(statuses::table.find(status_id))
+            .set(&changes)
+            .returning(QueriedStatus::as_returning())
+            .get_result(self.connection)?)
+    }
+
+    /// Returns the number of job applications and status changes that reference a status.
+    pub fn count_status_references(&mut self, status_id: i32) -> Result<i64, FettersError> {
+        let job_count: i64 = jobs::table
+            .filter(jobs::status_id.eq(status_id))
+            .select(count(jobs::id))
+            .first(self.connection)?;
+        let status_change_count: i64 = status_changes::table
+            .filter(
+                status_changes::new_status_id
+                    .eq(status_id)
+                    .or(status_changes::old_status_id.eq(status_id)),
+            )
+            .select(count(status_changes::id))
+            .first(self.connection)?;
+
+        Ok(job_count + status_change_count)
+    }
+
+    /// Removes a status. If a replacement status is provided, all job applications and status
+    /// changes referencing the removed status are moved to the replacement first, and each moved
+    /// job application is recorded in the audit log.
+    pub fn remove_status(
+        &mut self,
+        status_id: i32,
+        replacement_id: Option<i32>,
+    ) -> Result<QueriedStatus, FettersError> {
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                if let Some(replacement_id) = replacement_id {
+                    let mut job_repo = JobRepository { connection };
+                    job_repo.replace_status(status_id, replacement_id)?;
+                }
+
+                Ok(delete(statuses::table.find(status_id))
+                    .returning(QueriedStatus::as_returning())
+                    .get_result(connection)?)
+            })
+    }
+
+    /// Moves a status to a new position (starting at 1) in the status order and renumbers all
+    /// statuses.
+    pub fn reorder_status(&mut self, status_id: i32, position: usize) -> Result<(), FettersError> {
+        let mut all_statuses = self.get_all_statuses()?;
+
+        if let Some(index) = all_statuses
+            .iter()
+            .position(|status| status.id == status_id)
+        {
+            let status = all_statuses.remove(index);
+            let position = position.clamp(1, all_statuses.len() + 1);
+            all_statuses.insert(position - 1, status);
+        }
+
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                for (index, status) in all_statuses.iter().enumerate() {
+                    update(statuses::table.find(status.id))
+                        .set(statuses::sort_order.eq(index as i32 + 1))
+                        .execute(connection)?;
+                }
+
+                Ok(())
+            })
+    }
+
+    /// Stores the default statuses into the `statuses` table if no statuses exist yet. Statuses
+    /// are not reseeded once they exist so that renamed or removed statuses stay that way.
     pub fn seed_statuses(&mut self) -> Result<(), FettersError> {
-        use crate::schema::statuses::dsl::*;
-
-        for status in DEFAULT_STATUSES.iter().copied() {
-            let exists = statuses
-                .filter(name.eq(status))
-                .select(QueriedStatus::as_select())
-                .first(self.connection)
-                .optional()?;
-
-            if exists.is_none() {
-                let new_status = NewStatus { name: status };
-                insert_into(statuses)
-                    .values(&new_status)
-                    .execute(self.connection)?;
-            }
+        let status_count: i64 = statuses::table
+            .select(count(statuses::id))
+            .first(self.connection)?;
+
+        if status_count > 0 {
+            return Ok(());
+        }
+
+        for (index, (status_name, color, is_terminal)) in DEFAULT_STATUSES.iter().enumerate() {
+            insert_into(statuses::table)
+                .values(&NewStatus {
+                    name: status_name,
+                    color: Some(color),
+                    sort_order: index as i32 + 1,
+                    is_terminal: *is_terminal,
+                })
+                .execute(self.connection)?;
         }
 
         Ok(())
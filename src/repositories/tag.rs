@@ -0,0 +1,95 @@
+//! Contains the tag repository abstraction class.
+
+use std::collections::HashMap;
+
+use diesel::prelude::*;
+use diesel::{delete, insert_or_ignore_into};
+
+use crate::errors::FettersError;
+use crate::models::tag::{JobTag, NewTag};
+use crate::schema::{job_tags, tags};
+
+/// Contains all methods pertaining to CRUD operations for the `tags` and `job_tags` tables.
+pub struct TagRepository<'a> {
+    pub connection: &'a mut SqliteConnection,
+}
+
+impl<'a> TagRepository<'a> {
+    /// Retrieves the tags of the given job applications, keyed by job ID. Tags are sorted by
+    /// name.
+    pub fn get_tags_by_job(
+        &mut self,
+        job_ids: &[i32],
+    ) -> Result<HashMap<i32, Vec<String>>, FettersError> {
+        let job_tag_names: Vec<(i32, String)> = job_tags::table
+            .inner_join(tags::table)
+            .filter(job_tags::job_id.eq_any(job_ids))
+            .order((job_tags::job_id.asc(), tags::name.asc()))
+            .select((job_tags::job_id, tags::name))
+            .load(self.connection)?;
+
+        let mut tags_by_job: HashMap<i32, Vec<String>> = HashMap::new();
+        for (job_id, tag_name) in job_tag_names {
+            tags_by_job.entry(job_id).or_default().push(tag_name);
+        }
+
+        Ok(tags_by_job)
+    }
+
+    /// Adds tags to a job application. Tags that do not exist yet are created and tags that are
+    /// already attached are ignored.
+    pub fn add_job_tags(&mut self, job_id: i32, tag_names: &[String]) -> Result<(), FettersError> {
+        if tag_names.is_empty() {
+            return Ok(());
+        }
+
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                for tag_name in tag_names {
+                    insert_or_ignore_into(tags::table)
+                        .values(&NewTag { name: tag_name })
+                        .execute(connection)?;
+                }
+
+                let tag_ids: Vec<i32> = tags::table
+                    .filter(tags::name.eq_any(tag_names))
+                    .select(tags::id)
+                    .load(connection)?;
+
+                for tag_id in tag_ids {
+                    insert_or_ignore_into(job_tags::table)
+                        .values(&JobTag { job_id, tag_id })
+                        .execute(connection)?;
+                }
+
+                Ok(())
+            })
+    }
+
+    /// Removes tags from a job application.
+    pub fn remove_job_tags(
+        &mut self,
+        job_id: i32,
+        tag_names: &[String],
+    ) -> Result<(), FettersError> {
+        let tag_ids = tags::table
+            .filter(tags::name.eq_any(tag_names))
+            .select(tags::id);
+
+        delete(
+            job_tags::table
+                .filter(job_tags::job_id.eq(job_id))
+                .filter(job_tags::tag_id.eq_any(tag_ids)),
+        )
+        .execute(self.connection)?;
+
+        Ok(())
+    }
+
+    /// Removes all tags from a job application.
+    pub fn delete_job_tags(&mut self, job_id: i32) -> Result<(), FettersError> {
+        delete(job_tags::table.filter(job_tags::job_id.eq(job_id))).execute(self.connection)?;
+
+        Ok(())
+    }
+}
@@ -1,10 +1,15 @@
 //! Contains the title repository abstraction class.
 
-use diesel::insert_into;
 use diesel::prelude::*;
+use diesel::{delete, insert_into};
 
 use crate::errors::FettersError;
-use crate::models::title::{NewTitle, QueriedTitle};
+use crate::models::{
+    audit::AuditAction,
+    title::{NewTitle, QueriedTitle},
+};
+use crate::repositories::audit::AuditRepository;
+use crate::sqlite::lower;
 
 /// Contains all methods pertaining to CRUD operations for the `titles` table.
 pub struct TitleRepository<'a> {
@@ -12,19 +17,47 @@ pub struct TitleRepository<'a> {
 }
 
 impl<'a> TitleRepository<'a> {
-    /// Adds a new job title into the `titles` table.
+    /// Adds a new job title into the `titles` table. The insert is recorded in the audit log
+    /// unless the title already exists.
     pub fn add_title(&mut self, new_title: NewTitle) -> Result<QueriedTitle, FettersError> {
         use crate::schema::titles::dsl::*;
 
-        insert_into(titles)
-            .values(&new_title)
-            .on_conflict(name)
-            .do_nothing()
-            .execute(self.connection)?;
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                let num_inserted = insert_into(titles)
+                    .values(&new_title)
+                    .on_conflict(name)
+                    .do_nothing()
+                    .execute(connection)?;
 
-        Ok(titles
-            .filter(name.eq(new_title.name))
-            .first(self.connection)?)
+                let queried_title: QueriedTitle =
+                    titles.filter(name.eq(new_title.name)).first(connection)?;
+
+                if num_inserted > 0 {
+                    let mut audit_repo = AuditRepository { connection };
+                    audit_repo.record_title(AuditAction::Insert, None, Some(&queried_title))?;
+                }
+
+                Ok(queried_title)
+            })
+    }
+
+    /// Deletes a job title from the `titles` table and records the deletion in the audit log in a
+    /// single transaction.
+    pub fn delete_title(&mut self, title_id: i32) -> Result<QueriedTitle, FettersError> {
+        use crate::schema::titles::dsl::*;
+
+        self.connection
+            .transaction::<_, FettersError, _>(|connection| {
+                let deleted_title = delete(titles.find(title_id))
+                    .returning(QueriedTitle::as_returning())
+                    .get_result(connection)?;
+
+                let mut audit_repo = AuditRepository { connection };
+                audit_repo.record_title(AuditAction::Delete, Some(&deleted_title), None)?;
+
+                Ok(deleted_title)
+            })
     }
 
     /// Retrieves an existing job title by ID.
@@ -37,6 +70,20 @@ impl<'a> TitleRepository<'a> {
             .first(self.connection)?)
     }
 
+    /// Retrieves an existing job title by name. The name is matched case-insensitively.
+    pub fn get_title_by_name(
+        &mut self,
+        title_name: &str,
+    ) -> Result<Option<QueriedTitle>, FettersError> {
+        use crate::schema::titles::dsl::*;
+
+        Ok(titles
+            .filter(lower(name).eq(title_name.trim().to_lowercase()))
+            .select(QueriedTitle::as_select())
+            .first(self.connection)
+            .optional()?)
+    }
+
     /// Retrieves all job titles.
     pub fn get_all_titles(&mut self) -> Result<Vec<QueriedTitle>, FettersError> {
         use crate::schema::titles::dsl::*;
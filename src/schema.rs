@@ -1,5 +1,77 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    audit_log (id) {
+        id -> Integer,
+        operation_id -> Integer,
+        created -> Text,
+        command -> Text,
+        action -> Text,
+        table_name -> Text,
+        record_id -> Integer,
+        label -> Text,
+        before -> Nullable<Text>,
+        after -> Nullable<Text>,
+        undone -> Bool,
+    }
+}
+
+diesel::table! {
+    compensation (job_id) {
+        job_id -> Integer,
+        posted_min -> Nullable<BigInt>,
+        posted_max -> Nullable<BigInt>,
+        expected -> Nullable<BigInt>,
+        offered_base -> Nullable<BigInt>,
+        offered_bonus -> Nullable<BigInt>,
+        offered_equity -> Nullable<BigInt>,
+        currency -> Nullable<Text>,
+        offer_deadline -> Nullable<Text>,
+        benefits -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    contacts (id) {
+        id -> Integer,
+        name -> Text,
+        email -> Nullable<Text>,
+        phone -> Nullable<Text>,
+        role -> Nullable<Text>,
+        company -> Nullable<Text>,
+        linkedin_url -> Nullable<Text>,
+        notes -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    interviews (id) {
+        id -> Integer,
+        job_id -> Integer,
+        round -> Text,
+        interview_type -> Text,
+        scheduled -> Nullable<Text>,
+        interviewers -> Nullable<Text>,
+        outcome -> Text,
+        notes -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    job_contacts (job_id, contact_id) {
+        job_id -> Integer,
+        contact_id -> Integer,
+        linked -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    job_tags (job_id, tag_id) {
+        job_id -> Integer,
+        tag_id -> Integer,
+    }
+}
+
 diesel::table! {
     jobs (id) {
         id -> Integer,
@@ -10,6 +82,29 @@ diesel::table! {
         link -> Nullable<Text>,
         notes -> Nullable<Text>,
         sprint_id -> Integer,
+        location -> Nullable<Text>,
+        country -> Nullable<Text>,
+        work_mode -> Nullable<Text>,
+        source_id -> Nullable<Integer>,
+        deleted_at -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    reminders (id) {
+        id -> Integer,
+        job_id -> Integer,
+        due -> Text,
+        message -> Text,
+        done -> Bool,
+        automatic -> Bool,
+    }
+}
+
+diesel::table! {
+    sources (id) {
+        id -> Integer,
+        name -> Text,
     }
 }
 
@@ -20,6 +115,18 @@ diesel::table! {
         start_date -> Text,
         end_date -> Nullable<Text>,
         num_jobs -> Integer,
+        goal -> Nullable<Integer>,
+        goal_period -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    status_changes (id) {
+        id -> Integer,
+        job_id -> Integer,
+        old_status_id -> Nullable<Integer>,
+        new_status_id -> Integer,
+        changed -> Text,
     }
 }
 
@@ -27,6 +134,16 @@ diesel::table! {
     statuses (id) {
         id -> Integer,
         name -> Text,
+        color -> Nullable<Text>,
+        sort_order -> Integer,
+        is_terminal -> Bool,
+    }
+}
+
+diesel::table! {
+    tags (id) {
+        id -> Integer,
+        name -> Text,
     }
 }
 
@@ -37,8 +154,32 @@ diesel::table! {
     }
 }
 
+diesel::joinable!(compensation -> jobs (job_id));
+diesel::joinable!(interviews -> jobs (job_id));
+diesel::joinable!(job_contacts -> contacts (contact_id));
+diesel::joinable!(job_contacts -> jobs (job_id));
+diesel::joinable!(job_tags -> jobs (job_id));
+diesel::joinable!(job_tags -> tags (tag_id));
+diesel::joinable!(jobs -> sources (source_id));
 diesel::joinable!(jobs -> sprints (sprint_id));
 diesel::joinable!(jobs -> statuses (status_id));
 diesel::joinable!(jobs -> titles (title_id));
+diesel::joinable!(reminders -> jobs (job_id));
+diesel::joinable!(status_changes -> jobs (job_id));
 
-diesel::allow_tables_to_appear_in_same_query!(jobs, sprints, statuses, titles,);
+diesel::allow_tables_to_appear_in_same_query!(
+    audit_log,
+    compensation,
+    contacts,
+    interviews,
+    job_contacts,
+    job_tags,
+    jobs,
+    reminders,
+    sources,
+    sprints,
+    status_changes,
+    statuses,
+    tags,
+    titles,
+);
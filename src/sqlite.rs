@@ -1,7 +1,8 @@
 //! Contains all functionality pertaining to interacting with SQLite.
 
-use diesel::Connection;
+use diesel::sql_types::Text;
 use diesel::sqlite::SqliteConnection;
+use diesel::{Connection, define_sql_function};
 
 use crate::errors::FettersError;
 
@@ -18,3 +19,8 @@ impl Database {
         Ok(Database { connection })
     }
 }
+
+define_sql_function! {
+    /// The SQLite `lower()` function, used for case-insensitive comparisons.
+    fn lower(x: Text) -> Text;
+}
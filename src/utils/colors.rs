@@ -0,0 +1,45 @@
+//! Contains utility functions for parsing and applying the colors stored for each status.
+
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use owo_colors::{DynColor, DynColors, OwoColorize};
+use tabled::settings::Color;
+
+use crate::errors::FettersError;
+
+/// Wraps a color so that its ANSI foreground escape sequence can be written with `Display`.
+struct AnsiForeground(DynColors);
+
+impl Display for AnsiForeground {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt_ansi_fg(f)
+    }
+}
+
+/// Normalize a user-provided color into the form stored in SQLite. Supports the ANSI color names
+/// (ie. `red` or `bright-green`) and hex colors (ie. `#ff8800`).
+pub fn normalize_color(input: &str) -> Result<String, FettersError> {
+    let color = input.trim().to_lowercase().replace(['-', '_'], " ");
+
+    match DynColors::from_str(&color) {
+        Ok(_) => Ok(color),
+        Err(_) => Err(FettersError::InvalidColor(input.to_string())),
+    }
+}
+
+/// Returns the `tabled` color for a color stored in SQLite, or `None` if the color is invalid.
+pub fn table_color(color: &str) -> Option<Color> {
+    DynColors::from_str(color)
+        .ok()
+        .map(|color| Color::new(AnsiForeground(color).to_string(), "\u{1b}[39m"))
+}
+
+/// Colorize text in bold with a color stored in SQLite. The text is returned without a color if
+/// no color is set or the color is invalid.
+pub fn paint(text: &str, color: Option<&str>) -> String {
+    match color.and_then(|color| DynColors::from_str(color).ok()) {
+        Some(color) => text.color(color).bold().to_string(),
+        None => text.to_string(),
+    }
+}
@@ -0,0 +1,70 @@
+//! Contains utility functions for parsing and formatting timestamps stored in SQLite.
+
+use chrono::{Duration, Local, NaiveDate, NaiveDateTime};
+
+use crate::errors::FettersError;
+
+/// The format of all timestamps stored in SQLite.
+pub const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// The format of all dates stored in SQLite.
+pub const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Returns the current local time formatted as a SQLite timestamp.
+pub fn now_timestamp() -> String {
+    Local::now().format(TIMESTAMP_FORMAT).to_string()
+}
+
+/// Parse a timestamp stored in SQLite (`YYYY-MM-DD HH:MM:SS`).
+pub fn parse_timestamp(timestamp: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT).ok()
+}
+
+/// Normalize a user-provided date (`YYYY-MM-DD`) or timestamp (`YYYY-MM-DD HH:MM[:SS]`) into a
+/// SQLite timestamp. Dates without a time are set to midnight.
+pub fn normalize_timestamp(input: &str) -> Result<String, FettersError> {
+    let input = input.trim();
+
+    if let Some(timestamp) = parse_timestamp(input)
+        .or_else(|| NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M").ok())
+    {
+        return Ok(timestamp.format(TIMESTAMP_FORMAT).to_string());
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(input, DATE_FORMAT)
+        && let Some(timestamp) = date.and_hms_opt(0, 0, 0)
+    {
+        return Ok(timestamp.format(TIMESTAMP_FORMAT).to_string());
+    }
+
+    Err(FettersError::InvalidDate(input.to_string()))
+}
+
+/// Normalize a user-provided date (`YYYY-MM-DD`) into a SQLite date.
+pub fn normalize_date(input: &str) -> Result<String, FettersError> {
+    let input = input.trim();
+
+    NaiveDate::parse_from_str(input, DATE_FORMAT)
+        .map(|date| date.format(DATE_FORMAT).to_string())
+        .map_err(|_| FettersError::InvalidDate(input.to_string()))
+}
+
+/// Parse a user-provided duration made of a number followed by a unit: `h` (hours), `d` (days),
+/// or `w` (weeks). For example, `7d` is parsed as seven days.
+pub fn parse_duration(input: &str) -> Result<Duration, FettersError> {
+    let input = input.trim();
+    let invalid_duration = || FettersError::InvalidDuration(input.to_string());
+
+    let split_index = input
+        .find(|character: char| !character.is_ascii_digit())
+        .ok_or_else(invalid_duration)?;
+    let (amount, unit) = input.split_at(split_index);
+    let amount: i64 = amount.parse().map_err(|_| invalid_duration())?;
+
+    match unit.to_lowercase().as_str() {
+        "h" => Ok(Duration::hours(amount)),
+        "d" => Ok(Duration::days(amount)),
+        "w" => Ok(Duration::weeks(amount)),
+        _ => Err(invalid_duration()),
+    }
+}
@@ -1,8 +1,11 @@
 //! Contains utilities for displaying job applications.
 
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{Datelike, Duration, NaiveDate};
 use owo_colors::OwoColorize;
 use tabled::{
-    Table, Tabled,
+    Table,
     settings::{
         Alignment, Color, Modify, Panel, Remove, Style, Width,
         location::Locator,
@@ -10,60 +13,176 @@ use tabled::{
     },
 };
 
-use crate::models::{insight::CountAndPercentage, job::TabledJob, sprint::QueriedSprint};
+use crate::models::{
+    audit::LoggedOperation,
+    compensation::OfferComparison,
+    contact::QueriedContact,
+    insight::{
+        CountAndPercentage, DurationOutlier, DurationStats, FunnelStage, SourceInsight,
+        TimelineCount,
+    },
+    integrity::IntegrityIssue,
+    interview::TabledInterview,
+    job::{TabledJob, TrashedJob},
+    reminder::TabledReminder,
+    search::SearchResult,
+    sprint::{QueriedSprint, SprintProgress},
+    status::QueriedStatus,
+    status_change::TabledStatusChange,
+};
+use crate::utils::{colors::table_color, format::strip_color_if_piped};
 
 /// Display jobs in a table.
 pub fn display_jobs(jobs: &Vec<TabledJob>, sprint_name: &str) {
+    display_jobs_with_header(
+        jobs,
+        &format!("{sprint_name} SPRINT [{} JOBS LISTED]", jobs.len()),
+    );
+}
+
+/// Display jobs in a table with a custom header.
+pub fn display_jobs_with_header(jobs: &Vec<TabledJob>, table_header: &str) {
     let mut table = Table::new(jobs);
 
     table
         .with(Style::blank())
-        .with(Panel::header(
-            format!("{sprint_name} SPRINT [{} JOBS LISTED]", jobs.len())
-                .green()
-                .bold()
-                .to_string(),
-        ))
+        .with(Panel::header(table_header.green().bold().to_string()))
         .with(Modify::list(Rows::first(), Alignment::center()))
         .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_CYAN))
         .modify(Rows::first(), Color::FG_BRIGHT_WHITE)
         .modify(Columns::one(5), Width::truncate(23).suffix("..."))
-        .modify(Columns::one(6), Width::wrap(40).keep_words(true))
-        .modify(Locator::content("GHOSTED"), Color::rgb_fg(133, 133, 133))
-        .modify(Locator::content("HIRED"), Color::FG_BRIGHT_GREEN)
-        .modify(Locator::content("IN PROGRESS"), Color::FG_BRIGHT_YELLOW)
-        .modify(
-            Locator::content("NOT HIRING ANYMORE"),
-            Color::rgb_fg(117, 117, 117),
-        )
-        .modify(Locator::content("OFFER RECEIVED"), Color::FG_BRIGHT_MAGENTA)
-        .modify(Locator::content("PENDING"), Color::FG_BRIGHT_BLUE)
-        .modify(Locator::content("REJECTED"), Color::FG_BRIGHT_RED);
-
-    println!("\n{table}\n");
-}
-
-/// Display a single job. This generic function works with any struct that implements `Tabled`.
-pub fn display_single_job<T: Tabled>(job: T) {
+        .modify(Columns::one(6), Width::wrap(40).keep_words(true));
+
+    color_statuses(&mut table, job_status_colors(jobs));
+
+    println!("\n{}\n", strip_color_if_piped(table.to_string()));
+}
+
+/// Display a single job and the contacts linked to it.
+pub fn display_single_job(job: &TabledJob, contacts: &[QueriedContact]) {
     let mut table = Table::new([job]);
     table
         .with(Style::blank())
         .with(Remove::column(Columns::first()))
         .with(Modify::list(Rows::first(), Color::FG_BRIGHT_CYAN))
         .modify(Columns::one(4), Width::truncate(23).suffix("..."))
-        .modify(Columns::one(5), Width::wrap(40).keep_words(true))
-        .modify(Locator::content("GHOSTED"), Color::rgb_fg(133, 133, 133))
-        .modify(Locator::content("HIRED"), Color::FG_BRIGHT_GREEN)
-        .modify(Locator::content("IN PROGRESS"), Color::FG_BRIGHT_YELLOW)
-        .modify(
-            Locator::content("NOT HIRING ANYMORE"),
-            Color::rgb_fg(117, 117, 117),
-        )
-        .modify(Locator::content("OFFER RECEIVED"), Color::FG_BRIGHT_MAGENTA)
-        .modify(Locator::content("PENDING"), Color::FG_BRIGHT_BLUE)
-        .modify(Locator::content("REJECTED"), Color::FG_BRIGHT_RED);
+        .modify(Columns::one(5), Width::wrap(40).keep_words(true));
+
+    color_statuses(&mut table, job_status_colors([job]));
+
+    println!("\n{}\n", strip_color_if_piped(table.to_string()));
+
+    if !contacts.is_empty() {
+        display_contacts(contacts, "LINKED CONTACTS");
+    }
+}
+
+/// Display the status history timeline of a job application.
+pub fn display_status_history(
+    status_changes: &Vec<TabledStatusChange>,
+    statuses: &[QueriedStatus],
+    table_header: &str,
+) {
+    let mut table = Table::new(status_changes);
+
+    table
+        .with(Style::blank())
+        .with(Panel::header(table_header.green().bold().to_string()))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_CYAN));
+
+    color_statuses(
+        &mut table,
+        statuses
+            .iter()
+            .map(|status| (status.name.clone(), status.color.clone())),
+    );
+
+    println!("\n{}\n", strip_color_if_piped(table.to_string()));
+}
+
+/// Display interviews in a table.
+pub fn display_interviews(interviews: &Vec<TabledInterview>, table_header: &str) {
+    let mut table = Table::new(interviews);
+
+    table
+        .with(Style::blank())
+        .with(Panel::header(table_header.green().bold().to_string()))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_CYAN))
+        .modify(Columns::one(7), Width::wrap(40).keep_words(true))
+        .modify(Locator::content("CANCELLED"), Color::rgb_fg(133, 133, 133))
+        .modify(Locator::content("FAILED"), Color::FG_BRIGHT_RED)
+        .modify(Locator::content("PASSED"), Color::FG_BRIGHT_GREEN)
+        .modify(Locator::content("PENDING"), Color::FG_BRIGHT_BLUE);
+
+    println!("\n{}\n", strip_color_if_piped(table.to_string()));
+}
+
+/// Display contacts in a table.
+pub fn display_contacts(contacts: &[QueriedContact], table_header: &str) {
+    let mut table = Table::new(contacts);
+
+    table
+        .with(Style::blank())
+        .with(Panel::header(table_header.green().bold().to_string()))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_CYAN))
+        .modify(Columns::one(7), Width::wrap(40).keep_words(true));
 
-    println!("\n{table}\n");
+    println!("\n{}\n", strip_color_if_piped(table.to_string()));
+}
+
+/// Display reminders in a table.
+pub fn display_reminders(reminders: &Vec<TabledReminder>, table_header: &str) {
+    let mut table = Table::new(reminders);
+
+    table
+        .with(Style::blank())
+        .with(Panel::header(table_header.green().bold().to_string()))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_CYAN))
+        .modify(Columns::one(6), Width::wrap(40).keep_words(true))
+        .modify(Locator::content("OVERDUE"), Color::FG_BRIGHT_RED)
+        .modify(Locator::content("DUE TODAY"), Color::FG_BRIGHT_YELLOW)
+        .modify(Locator::content("UPCOMING"), Color::FG_BRIGHT_BLUE);
+
+    println!("\n{}\n", strip_color_if_piped(table.to_string()));
+}
+
+/// Display statuses in their sort order. Each status name is displayed in its color.
+pub fn display_statuses(statuses: &Vec<QueriedStatus>, table_header: &str) {
+    let mut table = Table::new(statuses);
+
+    table
+        .with(Style::blank())
+        .with(Panel::header(table_header.green().bold().to_string()))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_CYAN));
+
+    color_statuses(
+        &mut table,
+        statuses
+            .iter()
+            .map(|status| (status.name.clone(), status.color.clone())),
+    );
+
+    println!("\n{}\n", strip_color_if_piped(table.to_string()));
+}
+
+/// Display offers side by side. Each offer is displayed in its own column.
+pub fn display_offers(offers: &[OfferComparison], table_header: &str) {
+    let mut table = Table::builder(offers).index().column(0).transpose().build();
+
+    table
+        .with(Style::blank())
+        .with(Panel::header(table_header.green().bold().to_string()))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_CYAN))
+        .with(Modify::list(Columns::first(), Color::FG_BRIGHT_CYAN))
+        .modify(Columns::new(1..), Width::wrap(30).keep_words(true));
+
+    println!("\n{}\n", strip_color_if_piped(table.to_string()));
 }
 
 /// Display sprint metadata.
@@ -76,7 +195,90 @@ pub fn display_sprint(queried_sprints: &Vec<QueriedSprint>, table_header: &str)
         .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_BLUE))
         .with(Style::blank());
 
-    println!("\n{table}\n");
+    println!("\n{}\n", strip_color_if_piped(table.to_string()));
+}
+
+/// The width of a sprint progress bar.
+const PROGRESS_BAR_WIDTH: usize = 40;
+
+/// Display the progress of a sprint towards its goal as a progress bar followed by a projection
+/// of the current pace.
+pub fn display_sprint_progress(progress: &SprintProgress) {
+    let ratio = if progress.target == 0 {
+        0.0
+    } else {
+        (progress.applied as f64 / progress.target as f64).min(1.0)
+    };
+    let filled = (ratio * PROGRESS_BAR_WIDTH as f64).round() as usize;
+
+    let summary = format!(
+        "{}\n\nGoal: {} (averaging {:.1} per week)\n[{}{}] {}/{} ({:.2}%)\n{}",
+        "SPRINT GOAL".green().bold(),
+        progress.goal,
+        progress.weekly_average,
+        "█".repeat(filled).bright_green(),
+        "░".repeat(PROGRESS_BAR_WIDTH - filled),
+        progress.applied,
+        progress.target,
+        (progress.applied as f64 / progress.target.max(1) as f64) * 100.0,
+        progress.projection.bold()
+    );
+
+    println!("\n{}\n", strip_color_if_piped(summary));
+}
+
+/// Display all integrity problems found by `fetters doctor`.
+pub fn display_integrity_issues(issues: Vec<IntegrityIssue>) {
+    let header = format!("DOCTOR [{} PROBLEM(S) FOUND]", issues.len());
+    let mut table = Table::new(issues);
+
+    table
+        .with(Panel::header(header.yellow().bold().to_string()))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_BLUE))
+        .with(Style::blank());
+
+    println!("\n{}\n", strip_color_if_piped(table.to_string()));
+}
+
+/// Display operations recorded in the audit log.
+pub fn display_audit_log(operations: &[LoggedOperation], table_header: &str) {
+    let mut table = Table::new(operations);
+
+    table
+        .with(Panel::header(table_header.green().bold().to_string()))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_BLUE))
+        .with(Style::blank());
+
+    println!("\n{}\n", strip_color_if_piped(table.to_string()));
+}
+
+/// Display the job applications matching a search query.
+pub fn display_search_results(search_results: &[SearchResult], table_header: &str) {
+    let mut table = Table::new(search_results);
+
+    table
+        .with(Panel::header(table_header.green().bold().to_string()))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_CYAN))
+        .with(Style::blank())
+        .modify(Columns::one(5), Width::wrap(60).keep_words(true));
+
+    println!("\n{}\n", strip_color_if_piped(table.to_string()));
+}
+
+/// Display the job applications in the trash.
+pub fn display_trash(trashed_jobs: &[TrashedJob], table_header: &str) {
+    let mut table = Table::new(trashed_jobs);
+
+    table
+        .with(Panel::header(table_header.green().bold().to_string()))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_RED))
+        .with(Style::blank());
+
+    println!("\n{}\n", strip_color_if_piped(table.to_string()));
 }
 
 /// Display insights information.
@@ -88,8 +290,10 @@ pub fn display_insights(
     let mut table = Table::new(count_and_percentages);
 
     if remove_current_sprint {
-        table.with(Remove::column(Columns::one(2)));
+        table.with(Remove::column(Columns::one(3)));
     }
+    // Each table only contains a single category, which is already described by the header.
+    table.with(Remove::column(Columns::first()));
 
     table
         .with(Panel::header(table_header.green().bold().to_string()))
@@ -97,5 +301,251 @@ pub fn display_insights(
         .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_BLUE))
         .with(Style::blank());
 
-    println!("\n{table}\n");
+    println!("\n{}\n", strip_color_if_piped(table.to_string()));
+}
+
+/// Display the number of job applications, response rate, and offer rate per application
+/// source.
+pub fn display_source_insights(source_insights: Vec<SourceInsight>, table_header: &str) {
+    let mut table = Table::new(source_insights);
+
+    table
+        .with(Panel::header(table_header.green().bold().to_string()))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_BLUE))
+        .with(Style::blank());
+
+    println!("\n{}\n", strip_color_if_piped(table.to_string()));
+}
+
+/// Display statistics about the number of days spent in each stage, followed by the job
+/// applications that took much longer than usual.
+pub fn display_stage_durations(
+    duration_stats: Vec<DurationStats>,
+    duration_outliers: Vec<DurationOutlier>,
+) {
+    let mut table = Table::new(duration_stats);
+
+    table
+        .with(Panel::header(
+            "STAGE DURATIONS [DAYS]".green().bold().to_string(),
+        ))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_BLUE))
+        .with(Style::blank());
+
+    println!("\n{}\n", strip_color_if_piped(table.to_string()));
+
+    if duration_outliers.is_empty() {
+        return;
+    }
+
+    let header = format!("SLOW APPLICATIONS [{} FLAGGED]", duration_outliers.len());
+    let mut table = Table::new(duration_outliers);
+
+    table
+        .with(Panel::header(header.yellow().bold().to_string()))
+        .with(Modify::list(Rows::first(), Alignment::center()))
+        .with(Modify::list(Rows::one(1), Color::FG_BRIGHT_BLUE))
+        .with(Style::blank());
+
+    println!("\n{}\n", strip_color_if_piped(table.to_string()));
+}
+
+/// The width of the widest bar in an application funnel.
+const FUNNEL_WIDTH: usize = 50;
+
+/// Display an application funnel as centered bars followed by a table containing the conversion
+/// and attrition at each stage.
+pub fn display_funnel(funnel_stages: &[FunnelStage], table_header: &str) {
+    let applied = funnel_stages.first().map_or(0, |stage| stage.count);
+    let label_width = funnel_stages
+        .iter()
+        .map(|stage| stage.stage.len())
+        .max()
+        .unwrap_or_default();
+
+    let mut funnel = format!("{}\n\n", table_header.green().bold());
+    for stage in funnel_stages {
+        let bar_width = if applied == 0 {
+            0
+        } else {
+            ((stage.count as f64 / applied as f64) * FUNNEL_WIDTH as f64).round() as usize
+        };
+        let padding = (FUNNEL_WIDTH - bar_width) / 2;
+
+        funnel.push_str(&format!(
+            "{:>label_width$}  {}{}{}  {}\n",
+            stage.stage,
+            " ".repeat(padding),
+            "█".repeat(bar_width).bright_blue(),
+            " ".repeat(FUNNEL_WIDTH - bar_width - padding),
+            stage.count,
+        ));
+    }
+
+    let mut table = Table::new(funnel_stages);
+    table
+        .with(Modify::list(Rows::first(), Color::FG_BRIGHT_BLUE))
+        .with(Style::blank());
+
+    println!(
+        "\n{}\n{}\n",
+        strip_color_if_piped(funnel),
+        strip_color_if_piped(table.to_string())
+    );
+}
+
+/// The characters used to draw a sparkline, from the lowest to the highest value.
+const SPARKLINE_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// The characters used to draw a calendar heatmap, from no activity to the most activity.
+const HEATMAP_LEVELS: [char; 5] = ['·', '░', '▒', '▓', '█'];
+
+/// The width of the widest bar in a timeline.
+const TIMELINE_WIDTH: usize = 50;
+
+/// Display the number of job applications created per period as a sparkline followed by a
+/// horizontal bar chart.
+pub fn display_timeline(timeline: &[TimelineCount], header: &str) {
+    let max_count = timeline
+        .iter()
+        .map(|timeline_count| timeline_count.count)
+        .max()
+        .unwrap_or_default()
+        .max(1);
+    let label_width = timeline
+        .iter()
+        .map(|timeline_count| timeline_count.period.len())
+        .max()
+        .unwrap_or_default();
+
+    let sparkline: String = timeline
+        .iter()
+        .map(|timeline_count| {
+            let level = (timeline_count.count * (SPARKLINE_LEVELS.len() as i64 - 1)) / max_count;
+            SPARKLINE_LEVELS[level as usize]
+        })
+        .collect();
+
+    let mut chart = format!(
+        "{}\n\n{}\n\n",
+        header.green().bold(),
+        sparkline.bright_blue()
+    );
+    for timeline_count in timeline {
+        let bar_width = ((timeline_count.count as f64 / max_count as f64) * TIMELINE_WIDTH as f64)
+            .round() as usize;
+
+        chart.push_str(&format!(
+            "{:>label_width$} │ {} {}\n",
+            timeline_count.period,
+            "█".repeat(bar_width).bright_blue(),
+            timeline_count.count,
+        ));
+    }
+
+    println!("\n{}", strip_color_if_piped(chart));
+}
+
+/// Display a calendar heatmap of the number of job applications created per day over the last
+/// year. Each column is a week starting on Monday and each row is a day of the week.
+pub fn display_activity_heatmap(jobs_per_day: &BTreeMap<NaiveDate, i64>, today: NaiveDate) {
+    let first_day =
+        today - Duration::weeks(52) - Duration::days(today.weekday().num_days_from_monday() as i64);
+    let num_weeks = ((today - first_day).num_days() / 7 + 1) as usize;
+    let max_count = jobs_per_day
+        .range(first_day..=today)
+        .map(|(_, count)| *count)
+        .max()
+        .unwrap_or_default()
+        .max(1);
+    let total: i64 = jobs_per_day
+        .range(first_day..=today)
+        .map(|(_, count)| *count)
+        .sum();
+
+    // Label each week in which a new month starts, as long as the label fits.
+    let mut month_labels = " ".repeat(4 + num_weeks);
+    let mut next_free_column = 0;
+    for week in 0..num_weeks {
+        let week_start = first_day + Duration::weeks(week as i64);
+        let label = week_start.format("%b").to_string();
+
+        if (week == 0 || week_start.day() <= 7)
+            && week >= next_free_column
+            && week + label.len() <= num_weeks
+        {
+            month_labels.replace_range(4 + week..4 + week + label.len(), &label);
+            next_free_column = week + label.len() + 1;
+        }
+    }
+
+    let mut heatmap = format!(
+        "{}\n\n{}\n",
+        format!("APPLICATION ACTIVITY [{total} JOBS IN THE LAST YEAR]")
+            .green()
+            .bold(),
+        month_labels.trim_end()
+    );
+    for (weekday, weekday_label) in ["Mon", "", "Wed", "", "Fri", "", "Sun"]
+        .into_iter()
+        .enumerate()
+    {
+        let row: String = (0..num_weeks)
+            .map(|week| {
+                let day = first_day + Duration::days((week * 7 + weekday) as i64);
+                if day > today {
+                    return ' ';
+                }
+
+                match jobs_per_day.get(&day).copied().unwrap_or_default() {
+                    0 => HEATMAP_LEVELS[0],
+                    count => {
+                        let level = ((count * (HEATMAP_LEVELS.len() as i64 - 1)) + max_count - 1)
+                            / max_count;
+                        HEATMAP_LEVELS[level as usize]
+                    }
+                }
+            })
+            .collect();
+
+        heatmap.push_str(&format!("{weekday_label:<3} {}\n", row.bright_green()));
+    }
+    heatmap.push_str(&format!(
+        "\n    Less {} More\n",
+        HEATMAP_LEVELS
+            .iter()
+            .map(|level| level.to_string())
+            .collect::<Vec<String>>()
+            .join(" ")
+            .bright_green()
+    ));
+
+    println!("\n{}", strip_color_if_piped(heatmap));
+}
+
+/// Collect the status name and color of each job application.
+fn job_status_colors<'a>(
+    jobs: impl IntoIterator<Item = &'a TabledJob>,
+) -> HashMap<String, Option<String>> {
+    jobs.into_iter()
+        .filter_map(|job| {
+            job.status
+                .clone()
+                .map(|status| (status, job.status_color.clone()))
+        })
+        .collect()
+}
+
+/// Color every cell in a table that matches a status name with the color of that status.
+fn color_statuses(
+    table: &mut Table,
+    status_colors: impl IntoIterator<Item = (String, Option<String>)>,
+) {
+    for (status_name, color) in status_colors {
+        if let Some(color) = color.as_deref().and_then(table_color) {
+            table.modify(Locator::content(status_name.as_str()), color);
+        }
+    }
 }
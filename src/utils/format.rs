@@ -0,0 +1,61 @@
+//! Contains utilities for printing records in machine-readable output formats.
+
+use std::io::{IsTerminal, stdout};
+
+use csv::WriterBuilder;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Serialize;
+use tabled::{Table, Tabled, settings::Style};
+
+use crate::{cli::OutputFormat, errors::FettersError};
+
+lazy_static! {
+    /// Matches ANSI escape sequences used to color terminal output.
+    static ref ANSI_ESCAPE_PATTERN: Regex = Regex::new(r"\x1b\[[0-9;]*m").unwrap();
+}
+
+/// Print records in the given output format. Colored tables are not handled here and should be
+/// printed with the functions in `utils::display` instead.
+pub fn print_records<T: Serialize + Tabled>(
+    records: &[T],
+    output_format: OutputFormat,
+) -> Result<(), FettersError> {
+    match output_format {
+        OutputFormat::Csv => write_delimited(records, b',')?,
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(records)?),
+        OutputFormat::Markdown => println!("{}", Table::new(records).with(Style::markdown())),
+        OutputFormat::Table => {
+            return Err(FettersError::UnknownError(
+                "Tables should be printed with the display utilities!".to_string(),
+            ));
+        }
+        OutputFormat::Tsv => write_delimited(records, b'\t')?,
+    }
+
+    Ok(())
+}
+
+/// Write records to stdout as delimited values with a header row.
+fn write_delimited<T: Serialize>(records: &[T], delimiter: u8) -> Result<(), FettersError> {
+    let mut writer = WriterBuilder::new()
+        .delimiter(delimiter)
+        .from_writer(stdout());
+
+    for record in records {
+        writer.serialize(record)?;
+    }
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Remove ANSI color codes from the output if stdout is not a terminal (ie. when piping output into
+/// another program or a file).
+pub fn strip_color_if_piped(output: String) -> String {
+    if stdout().is_terminal() {
+        output
+    } else {
+        ANSI_ESCAPE_PATTERN.replace_all(&output, "").into_owned()
+    }
+}
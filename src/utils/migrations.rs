@@ -24,3 +24,44 @@ pub fn run_migrations(connection: &mut SqliteConnection) -> Result<(), FettersEr
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use diesel::{Connection, SqliteConnection};
+    use diesel_migrations::MigrationHarness;
+
+    use super::*;
+    use crate::repositories::statuses::StatusRepository;
+
+    #[test]
+    fn migrations_can_be_reverted_and_run_again() -> Result<(), FettersError> {
+        let mut connection = SqliteConnection::establish(":memory:")?;
+        run_migrations(&mut connection)?;
+
+        assert!(
+            !connection
+                .has_pending_migration(MIGRATIONS)
+                .map_err(|_| FettersError::MigrationFailure)?
+        );
+
+        connection
+            .revert_all_migrations(MIGRATIONS)
+            .map_err(|_| FettersError::MigrationFailure)?;
+        assert!(
+            connection
+                .applied_migrations()
+                .map_err(|_| FettersError::MigrationFailure)?
+                .is_empty()
+        );
+
+        run_migrations(&mut connection)?;
+
+        let mut status_repo = StatusRepository {
+            connection: &mut connection,
+        };
+        status_repo.seed_statuses()?;
+        assert!(!status_repo.get_all_statuses()?.is_empty());
+
+        Ok(())
+    }
+}
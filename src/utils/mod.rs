@@ -1,6 +1,13 @@
 //! Contains all utils for `fetters`.
 
+pub mod colors;
+pub mod dates;
 pub mod display;
+pub mod format;
 pub mod migrations;
 pub mod prompt;
+pub mod selection;
+pub mod tags;
+#[cfg(test)]
+pub mod testing;
 pub mod titles;
@@ -1,6 +1,13 @@
-//! Contains a utility function for getting the `RenderConfig` for `inquire` prompts.
+//! Contains utility functions for `inquire` prompts.
 
-use inquire::ui::{Attributes, Color, RenderConfig, StyleSheet, Styled};
+use inquire::{
+    Confirm, Text,
+    ui::{Attributes, Color, RenderConfig, StyleSheet, Styled},
+};
+
+use owo_colors::OwoColorize;
+
+use crate::errors::FettersError;
 
 /// Returns the `RenderConfig` object to use with `inquire` prompts.
 pub fn get_inquire_config() -> RenderConfig<'static> {
@@ -20,3 +27,40 @@ pub fn get_inquire_config() -> RenderConfig<'static> {
 
     render_config
 }
+
+/// Input an optional text field. Empty input is returned as `None`.
+pub fn input_optional_text(
+    message: &str,
+    previous_value: &str,
+) -> Result<Option<String>, FettersError> {
+    Ok(Text::new(message)
+        .with_initial_value(previous_value)
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?
+        .map(|input| input.trim().to_string())
+        .filter(|input| !input.is_empty()))
+}
+
+/// Confirm a destructive action, unless `yes` is set.
+pub fn confirm(message: &str, yes: bool) -> Result<bool, FettersError> {
+    let confirmed = if yes {
+        Some(true)
+    } else {
+        Confirm::new(message)
+            .with_default(true)
+            .with_render_config(get_inquire_config())
+            .prompt_skippable()?
+    };
+
+    match confirmed {
+        Some(true) => Ok(true),
+        Some(false) => {
+            println!("{}", "Cancelled.".red().bold());
+            Ok(false)
+        }
+        None => {
+            println!("{}", "Invalid input, try again".red().bold());
+            Ok(false)
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Contains a utility function for querying and selecting a single job application.
+
+use diesel::SqliteConnection;
+use inquire::Select;
+
+use crate::{
+    cli::QueryArgs,
+    errors::FettersError,
+    models::{job::TabledJob, sprint::QueriedSprint},
+    repositories::job::JobRepository,
+    utils::{display::display_jobs, prompt::get_inquire_config},
+};
+
+/// Query job applications with the `QueryArgs` and select one of them in a `Select` menu. The
+/// menu is skipped if the job application was targeted by its ID. Returns `None` if the selection
+/// was skipped.
+pub fn select_job(
+    connection: &mut SqliteConnection,
+    query_args: &mut QueryArgs,
+    current_sprint: &QueriedSprint,
+    message: &str,
+) -> Result<Option<TabledJob>, FettersError> {
+    let matched_jobs = query_jobs(connection, query_args, current_sprint)?;
+
+    // Skip the `Select` menu if the job was targeted by its ID.
+    if query_args.id.is_some() {
+        return Ok(matched_jobs.into_iter().next());
+    }
+
+    display_jobs(
+        &matched_jobs,
+        query_args.sprint.as_ref().unwrap_or(&current_sprint.name),
+    );
+
+    Ok(Select::new(message, matched_jobs)
+        .with_render_config(get_inquire_config())
+        .prompt_skippable()?)
+}
+
+/// Query job applications with the `QueryArgs`, searching the current sprint if no sprint filter
+/// or job ID was specified. Raises an error if no job applications matched the query.
+pub fn query_jobs(
+    connection: &mut SqliteConnection,
+    query_args: &mut QueryArgs,
+    current_sprint: &QueriedSprint,
+) -> Result<Vec<TabledJob>, FettersError> {
+    let default_sprint = Some(current_sprint.name.clone());
+
+    // Search the default sprint if no sprint filter or job ID was specified.
+    if query_args.sprint.is_none() && query_args.id.is_none() {
+        query_args.sprint = default_sprint;
+    }
+
+    let mut job_repo = JobRepository { connection };
+    let matched_jobs = job_repo.list_jobs(query_args, current_sprint)?;
+
+    if matched_jobs.is_empty() {
+        if let Some(job_id) = query_args.id {
+            return Err(FettersError::JobNotFound(job_id));
+        }
+
+        return Err(FettersError::NoJobsAvailable(
+            query_args
+                .sprint
+                .clone()
+                .as_ref()
+                .unwrap_or(&current_sprint.name.clone())
+                .to_string(),
+        ));
+    }
+
+    Ok(matched_jobs)
+}
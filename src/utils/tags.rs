@@ -0,0 +1,16 @@
+//! Contains utilities for working with tags.
+
+/// Normalize tag names so they can be matched consistently. Tags are trimmed and lowercased,
+/// empty tags are dropped, and duplicates are removed while preserving order.
+pub fn normalize_tags(tag_names: &[String]) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::new();
+
+    for tag_name in tag_names {
+        let tag_name = tag_name.trim().to_lowercase();
+        if !tag_name.is_empty() && !normalized.contains(&tag_name) {
+            normalized.push(tag_name);
+        }
+    }
+
+    normalized
+}
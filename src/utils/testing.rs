@@ -0,0 +1,112 @@
+//! Contains helpers for tests that need a database.
+
+use std::sync::{Mutex, MutexGuard};
+
+use diesel::{Connection, SqliteConnection};
+
+use crate::errors::FettersError;
+use crate::models::{
+    job::{NewJob, QueriedJob},
+    sprint::QueriedSprint,
+    title::NewTitle,
+};
+use crate::repositories::{
+    audit::AuditRepository, job::JobRepository, source::SourceRepository, sprint::SprintRepository,
+    statuses::StatusRepository, title::TitleRepository,
+};
+use crate::utils::migrations::run_migrations;
+
+/// Tests that use a database run one at a time because the operation recorded in the audit log is
+/// shared by the whole process.
+static DATABASE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Contains an in-memory SQLite database set up the same way `fetters` sets up its database on
+/// startup.
+pub struct TestDatabase {
+    /// The SQLite connection.
+    pub connection: SqliteConnection,
+    /// The current sprint.
+    pub sprint: QueriedSprint,
+    /// Keeps other tests from using a database until this one is dropped.
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl TestDatabase {
+    /// Create a new in-memory database, run all migrations, seed the default statuses and sources,
+    /// and create the current sprint.
+    pub fn new() -> Result<TestDatabase, FettersError> {
+        let lock = DATABASE_LOCK
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+
+        let mut connection = SqliteConnection::establish(":memory:")?;
+        run_migrations(&mut connection)?;
+
+        AuditRepository::start_operation(Some("test"));
+
+        StatusRepository {
+            connection: &mut connection,
+        }
+        .seed_statuses()?;
+        SourceRepository {
+            connection: &mut connection,
+        }
+        .seed_sources()?;
+        let sprint = SprintRepository {
+            connection: &mut connection,
+        }
+        .get_current_sprint("test-sprint")?;
+
+        Ok(TestDatabase {
+            connection,
+            sprint,
+            _lock: lock,
+        })
+    }
+
+    /// Add a job application in the current sprint with the given status that was created at the
+    /// given timestamp.
+    pub fn add_job(
+        &mut self,
+        company_name: &str,
+        status_name: &str,
+        created: &str,
+    ) -> Result<QueriedJob, FettersError> {
+        let status = StatusRepository {
+            connection: &mut self.connection,
+        }
+        .get_status_by_name(status_name)?
+        .ok_or(FettersError::StatusNotFound(status_name.to_string()))?;
+
+        let mut title_repo = TitleRepository {
+            connection: &mut self.connection,
+        };
+        let title = match title_repo.get_title_by_name("Software Engineer")? {
+            Some(title) => title,
+            None => title_repo.add_title(NewTitle {
+                name: "Software Engineer",
+            })?,
+        };
+
+        JobRepository {
+            connection: &mut self.connection,
+        }
+        .add_job(
+            NewJob {
+                company_name,
+                created: created.to_string(),
+                title_id: title.id,
+                status_id: status.id,
+                link: None,
+                notes: None,
+                sprint_id: self.sprint.id,
+                location: None,
+                country: None,
+                work_mode: None,
+                source_id: None,
+                deleted_at: None,
+            },
+            &[],
+        )
+    }
+}
@@ -35,6 +35,19 @@ pub fn create_or_use_title(connection: &mut SqliteConnection) -> Result<TitleTyp
     Ok(queried_title)
 }
 
+/// Use an existing job title matching the given name or create a new one if it does not exist.
+pub fn use_title_by_name(
+    connection: &mut SqliteConnection,
+    title_name: &str,
+) -> Result<TitleType, FettersError> {
+    let mut title_repo = TitleRepository { connection };
+
+    Ok(match title_repo.get_title_by_name(title_name)? {
+        Some(queried_title) => TitleType::QueriedTitle(queried_title),
+        None => TitleType::NewTitle(title_name.trim().to_string()),
+    })
+}
+
 /// Create a new job title.
 fn create_new_title() -> Result<TitleType, FettersError> {
     loop {